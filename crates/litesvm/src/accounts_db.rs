@@ -8,7 +8,7 @@ use {
     serde::de::DeserializeOwned,
     solana_account::{state_traits::StateMut, AccountSharedData, ReadableAccount, WritableAccount},
     solana_address_lookup_table_interface::{error::AddressLookupError, state::AddressLookupTable},
-    solana_clock::Clock,
+    solana_clock::{Clock, Slot},
     solana_instruction::error::InstructionError,
     solana_loader_v3_interface::state::UpgradeableLoaderState,
     solana_loader_v4_interface::state::LoaderV4State,
@@ -69,6 +69,8 @@ where
 #[derive(Clone, Default)]
 pub struct AccountsDb {
     pub inner: HashMap<Pubkey, AccountSharedData>,
+    /// The slot at which each account was last written.
+    pub modified_slots: HashMap<Pubkey, Slot>,
     pub programs_cache: ProgramCacheForTxBatch,
     pub sysvar_cache: SysvarCache,
     pub environments: ProgramRuntimeEnvironments,
@@ -86,9 +88,19 @@ impl AccountsDb {
     /// We should only use this when we know we're not touching any executable or sysvar accounts,
     /// or have already handled such cases.
     pub(crate) fn add_account_no_checks(&mut self, pubkey: Pubkey, account: AccountSharedData) {
+        self.mark_modified(pubkey);
         self.inner.insert(pubkey, account);
     }
 
+    fn mark_modified(&mut self, pubkey: Pubkey) {
+        let slot = self
+            .sysvar_cache
+            .get_clock()
+            .map(|clock| clock.slot)
+            .unwrap_or_default();
+        self.modified_slots.insert(pubkey, slot);
+    }
+
     pub(crate) fn add_account(
         &mut self,
         pubkey: Pubkey,
@@ -106,6 +118,7 @@ impl AccountsDb {
         }
         if account.lamports() == 0 {
             self.inner.remove(&pubkey);
+            self.modified_slots.remove(&pubkey);
         } else {
             self.add_account_no_checks(pubkey, account);
         }
@@ -208,14 +221,46 @@ impl AccountsDb {
         Ok(())
     }
 
+    /// Writes several sysvar accounts and rebuilds the sysvar cache once,
+    /// so no intermediate combination of them is ever observed.
+    pub(crate) fn add_sysvar_accounts(
+        &mut self,
+        accounts: Vec<(Pubkey, AccountSharedData)>,
+    ) -> Result<(), InvalidSysvarDataError> {
+        let mut pubkeys = Vec::with_capacity(accounts.len());
+        for (pubkey, account) in accounts {
+            if pubkey == CLOCK_ID {
+                let parsed: Clock = bincode::deserialize(account.data())
+                    .map_err(|_| InvalidSysvarDataError::Clock)?;
+                self.programs_cache.set_slot_for_tests(parsed.slot);
+            }
+            self.inner.insert(pubkey, account);
+            pubkeys.push(pubkey);
+        }
+        let accounts = &self.inner;
+        self.sysvar_cache.reset();
+        self.sysvar_cache
+            .fill_missing_entries(|pubkey, set_sysvar| {
+                if let Some(acc) = accounts.get(pubkey) {
+                    set_sysvar(acc.data())
+                }
+            });
+        for pubkey in pubkeys {
+            self.mark_modified(pubkey);
+        }
+        Ok(())
+    }
+
     /// Skip the executable() checks for builtin accounts
     pub(crate) fn add_builtin_account(&mut self, pubkey: Pubkey, data: AccountSharedData) {
+        self.mark_modified(pubkey);
         self.inner.insert(pubkey, data);
     }
 
     pub(crate) fn sync_accounts(
         &mut self,
         mut accounts: Vec<(Pubkey, AccountSharedData)>,
+        purge_zero_lamport: bool,
     ) -> Result<(), LiteSVMError> {
         // need to add programdata accounts first if there are any
         itertools::partition(&mut accounts, |x| {
@@ -223,12 +268,16 @@ impl AccountsDb {
                 && x.1.data().first().is_some_and(|byte| *byte == 3)
         });
         for (pubkey, acc) in accounts {
-            self.add_account(pubkey, acc)?;
+            if !purge_zero_lamport && acc.lamports() == 0 {
+                self.add_account_no_checks(pubkey, acc);
+            } else {
+                self.add_account(pubkey, acc)?;
+            }
         }
         Ok(())
     }
 
-    fn load_program(
+    pub(crate) fn load_program(
         &self,
         program_account: &AccountSharedData,
     ) -> Result<ProgramCacheEntry, InstructionError> {
@@ -371,6 +420,7 @@ impl AccountsDb {
                 account
                     .checked_sub_lamports(lamports)
                     .map_err(|_| TransactionError::InsufficientFundsForFee)?;
+                self.mark_modified(*pubkey);
 
                 Ok(())
             }
@@ -23,6 +23,9 @@ impl InvokeContextCallback for LiteSVM {
         data: &[u8],
         instruction_datas: Vec<&[u8]>,
     ) -> Result<(), solana_precompile_error::PrecompileError> {
+        if !self.sigverify_precompiles {
+            return Ok(());
+        }
         if let Some(precompile) = get_precompile(program_id, |feature_id: &Pubkey| {
             self.feature_set.is_active(feature_id)
         }) {
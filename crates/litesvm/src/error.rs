@@ -1,4 +1,4 @@
-use {solana_instruction::error::InstructionError, thiserror::Error};
+use {solana_instruction::error::InstructionError, solana_pubkey::Pubkey, thiserror::Error};
 
 #[derive(Error, Debug)]
 pub enum InvalidSysvarDataError {
@@ -30,4 +30,17 @@ pub enum LiteSVMError {
     Instruction(#[from] InstructionError),
     #[error("{0}")]
     InvalidPath(#[from] std::io::Error),
+    #[error("Account {0} already exists")]
+    AccountAlreadyExists(Pubkey),
+    #[error("Program {0} is not a builtin")]
+    NotABuiltin(Pubkey),
+    #[error("Failed to set account {index} ({pubkey}): {source}")]
+    SetAccounts {
+        index: usize,
+        pubkey: Pubkey,
+        source: Box<LiteSVMError>,
+    },
+    #[cfg(feature = "fetch")]
+    #[error("RPC request failed: {0}")]
+    Rpc(String),
 }
@@ -1,4 +1,10 @@
-use {ansi_term::Colour, std::fmt::Write};
+use {
+    crate::types::ExecutionTrace,
+    ansi_term::Colour,
+    base64::{prelude::BASE64_STANDARD, Engine},
+    solana_pubkey::Pubkey,
+    std::fmt::Write,
+};
 
 const PROGRAM_LOG: &str = "Program log:";
 
@@ -80,6 +86,117 @@ pub(crate) fn format_logs(logs: &[String]) -> String {
     out
 }
 
+/// Keeps the lines logged while `program_id` was the innermost running program,
+/// dropping the runtime's invoke, consumed, success and failed framing lines.
+pub(crate) fn logs_for_program(logs: &[String], program_id: &Pubkey) -> Vec<String> {
+    let program_id = program_id.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut out = Vec::new();
+    for line in logs {
+        let mut words = line.split(' ');
+        if let (Some("Program"), Some(id), Some(event)) = (words.next(), words.next(), words.next())
+        {
+            if id.parse::<Pubkey>().is_ok() {
+                match event {
+                    "invoke" => {
+                        stack.push(id);
+                        continue;
+                    }
+                    "success" | "failed:" => {
+                        stack.pop();
+                        continue;
+                    }
+                    "consumed" => continue,
+                    _ => (),
+                }
+            }
+        }
+        if stack.last() == Some(&program_id.as_str()) {
+            out.push(line.clone());
+        }
+    }
+    out
+}
+
+/// Fills in the logs of each instruction in `trace`, along with the compute units
+/// and return data of inner instructions, which only the logs report.
+///
+/// Each invoke line is matched to the next instruction in call order with the same
+/// program, so instructions that log nothing, such as precompiles, are skipped, and
+/// instructions past the point where the logs were truncated are left without logs.
+pub(crate) fn attach_logs(trace: &mut [ExecutionTrace], logs: &[String]) {
+    fn collect_paths(nodes: &[ExecutionTrace], prefix: &[usize], paths: &mut Vec<Vec<usize>>) {
+        for (index, node) in nodes.iter().enumerate() {
+            let mut path = prefix.to_vec();
+            path.push(index);
+            paths.push(path.clone());
+            collect_paths(&node.inner_instructions, &path, paths);
+        }
+    }
+    fn node_at<'a>(trace: &'a mut [ExecutionTrace], path: &[usize]) -> &'a mut ExecutionTrace {
+        let (first, rest) = path.split_first().unwrap();
+        rest.iter().fold(&mut trace[*first], |node, index| {
+            &mut node.inner_instructions[*index]
+        })
+    }
+
+    // every instruction in call order, as the path of indices leading to it
+    let mut paths = Vec::new();
+    collect_paths(trace, &[], &mut paths);
+    let mut next = 0;
+    // the running instructions, or `None` for an invoke line with no matching instruction
+    let mut stack: Vec<Option<&[usize]>> = Vec::new();
+    for line in logs {
+        let mut words = line.split(' ');
+        match (words.next(), words.next(), words.next()) {
+            (Some("Program"), Some("return:"), Some(_)) => {
+                if let (Some(Some(path)), Some(data)) = (stack.last(), words.next()) {
+                    // top-level return data comes from the runtime
+                    if path.len() > 1 {
+                        node_at(trace, path).return_data = BASE64_STANDARD.decode(data).ok();
+                    }
+                }
+                continue;
+            }
+            (Some("Program"), Some(id), Some(event)) => {
+                if let Ok(program_id) = id.parse::<Pubkey>() {
+                    match event {
+                        "invoke" => {
+                            let found = paths[next..]
+                                .iter()
+                                .position(|path| node_at(trace, path).program_id == program_id);
+                            stack.push(found.map(|offset| {
+                                next += offset + 1;
+                                paths[next - 1].as_slice()
+                            }));
+                            continue;
+                        }
+                        "consumed" => {
+                            if let (Some(Some(path)), Some(units)) = (stack.last(), words.next()) {
+                                // top-level compute units come from the runtime
+                                if path.len() > 1 {
+                                    node_at(trace, path).compute_units_consumed =
+                                        units.parse().unwrap_or_default();
+                                }
+                            }
+                            continue;
+                        }
+                        "success" | "failed:" => {
+                            stack.pop();
+                            continue;
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            _ => (),
+        }
+        if let Some(Some(path)) = stack.last() {
+            node_at(trace, path).logs.push(line.clone());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,6 +223,85 @@ mod tests {
         assert_eq!(formatted, "\u{1b}[32mstatic string\u{1b}[0m");
     }
 
+    #[test]
+    fn test_logs_for_program() {
+        let logs = ["Program ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL invoke [1]", "Program log: Create", "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]", "Program log: Instruction: GetAccountDataSize", "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 1622 of 194671 compute units", "Program return: TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA pQAAAAAAAAA=", "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success", "Program log: Initialize the associated token account", "Program ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL consumed 21990 of 200000 compute units", "Program ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL success"].map(ToString::to_string);
+        let token = Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+        let ata = Pubkey::from_str_const("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+        assert_eq!(
+            logs_for_program(&logs, &token),
+            [
+                "Program log: Instruction: GetAccountDataSize",
+                "Program return: TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA pQAAAAAAAAA="
+            ]
+        );
+        assert_eq!(
+            logs_for_program(&logs, &ata),
+            [
+                "Program log: Create",
+                "Program log: Initialize the associated token account"
+            ]
+        );
+        assert!(logs_for_program(&logs, &Pubkey::new_unique()).is_empty());
+    }
+
+    #[test]
+    fn test_attach_logs() {
+        let logs = ["Program ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL invoke [1]", "Program log: Create", "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]", "Program log: Instruction: GetAccountDataSize", "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 1622 of 194671 compute units", "Program return: TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA pQAAAAAAAAA=", "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success", "Program 11111111111111111111111111111111 invoke [2]", "Program 11111111111111111111111111111111 success", "Program ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL consumed 21990 of 200000 compute units", "Program ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL success", "Program 11111111111111111111111111111111 invoke [1]", "Log truncated"].map(ToString::to_string);
+        let token = Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+        let ata = Pubkey::from_str_const("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+        let system = Pubkey::from_str_const("11111111111111111111111111111111");
+        let ed25519 = Pubkey::from_str_const("Ed25519SigVerify111111111111111111111111111");
+        let node = |program_id, instruction_index, inner_instructions| ExecutionTrace {
+            program_id,
+            instruction_index,
+            inner_instructions,
+            ..Default::default()
+        };
+        // a precompile logs nothing, and the last system call ran past the log limit
+        let mut trace = [
+            node(ed25519, 0, vec![]),
+            ExecutionTrace {
+                compute_units_consumed: 21990,
+                ..node(
+                    ata,
+                    1,
+                    vec![node(token, 0, vec![]), node(system, 1, vec![])],
+                )
+            },
+            node(system, 2, vec![node(system, 0, vec![])]),
+        ];
+        attach_logs(&mut trace, &logs);
+        assert_eq!(
+            trace,
+            [
+                node(ed25519, 0, vec![]),
+                ExecutionTrace {
+                    program_id: ata,
+                    instruction_index: 1,
+                    inner_instructions: vec![
+                        ExecutionTrace {
+                            program_id: token,
+                            instruction_index: 0,
+                            inner_instructions: vec![],
+                            logs: vec!["Program log: Instruction: GetAccountDataSize".to_string()],
+                            compute_units_consumed: 1622,
+                            return_data: Some(vec![165, 0, 0, 0, 0, 0, 0, 0]),
+                        },
+                        node(system, 1, vec![]),
+                    ],
+                    logs: vec!["Program log: Create".to_string()],
+                    compute_units_consumed: 21990,
+                    return_data: None,
+                },
+                ExecutionTrace {
+                    logs: vec!["Log truncated".to_string()],
+                    ..node(system, 2, vec![node(system, 0, vec![])])
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_format_logs() {
         let logs = ["Program 1111111QLbz7JHiBTspS962RLKV8GndWFwiEaqKM invoke [1]", "Program log: panicked at clock-example/src/lib.rs:17:5:\nassertion failed: got_clock.unix_timestamp < 100", "Program 1111111QLbz7JHiBTspS962RLKV8GndWFwiEaqKM consumed 1751 of 200000 compute units", "Program 1111111QLbz7JHiBTspS962RLKV8GndWFwiEaqKM failed: SBF program panicked"].map(ToString::to_string);
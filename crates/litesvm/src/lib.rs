@@ -284,7 +284,7 @@ much easier.
 #[cfg(feature = "register-tracing")]
 use crate::register_tracing::DefaultRegisterTracingCallback;
 #[cfg(feature = "precompiles")]
-use precompiles::load_precompiles;
+use precompiles::{installed_precompiles, load_precompiles};
 #[cfg(feature = "nodejs-internal")]
 use qualifier_attr::qualifiers;
 #[allow(deprecated)]
@@ -297,17 +297,26 @@ use {
         error::LiteSVMError,
         history::TransactionHistory,
         message_processor::process_message,
-        programs::load_default_programs,
+        programs::{
+            load_config_program, load_default_programs, load_spl_associated_token_account_program,
+            SPL_TOKEN_2022_ID,
+        },
         types::{
-            ExecutionResult, FailedTransactionMetadata, TransactionMetadata, TransactionResult,
+            AccountLocks, AccountSummary, AccountsView, ExecutionResult, ExecutionTrace,
+            FailedTransactionMetadata, FeeBreakdown, InstructionReturnData, LatestBlockhashInfo,
+            MintInfo, ProgramKind, StakeActivation, StakeActivationState, SvmMetrics, SyscallCosts,
+            TokenBalance, TransactionMetadata, TransactionResult,
         },
         utils::{
             create_blockhash,
-            rent::{check_rent_state_with_account, get_account_rent_state, RentState},
+            rent::{
+                check_rent_state_with_account, get_account_rent_state, RentCollector, RentState,
+            },
         },
     },
     agave_feature_set::{
-        increase_cpi_account_info_limit, raise_cpi_nesting_limit_to_8, FeatureSet,
+        increase_cpi_account_info_limit, raise_cpi_nesting_limit_to_8,
+        stake_raise_minimum_delegation_to_1_sol, FeatureSet,
     },
     agave_reserved_account_keys::ReservedAccountKeys,
     agave_syscalls::{
@@ -315,38 +324,58 @@ use {
     },
     log::error,
     serde::de::DeserializeOwned,
-    solana_account::{Account, AccountSharedData, ReadableAccount, WritableAccount},
+    solana_account::{
+        state_traits::StateMut, Account, AccountSharedData, ReadableAccount, WritableAccount,
+    },
+    solana_address_lookup_table_interface::state::{AddressLookupTable, LookupTableMeta},
     solana_builtins::BUILTINS,
-    solana_clock::Clock,
+    solana_clock::{Clock, DEFAULT_MS_PER_SLOT, MAX_PROCESSING_AGE},
     solana_compute_budget::{
-        compute_budget::{ComputeBudget, SVMTransactionExecutionCost},
-        compute_budget_limits::ComputeBudgetLimits,
+        compute_budget::ComputeBudget, compute_budget_limits::ComputeBudgetLimits,
     },
     solana_compute_budget_instruction::instructions_processor::process_compute_budget_instructions,
     solana_epoch_rewards::EpochRewards,
     solana_epoch_schedule::EpochSchedule,
-    solana_fee::FeeFeatures,
+    solana_fee::{FeeFeatures, SignatureCounts},
+    solana_fee_calculator::FeeRateGovernor,
     solana_fee_structure::FeeStructure,
     solana_hash::Hash,
+    solana_instruction::{error::InstructionError, Instruction},
     solana_keypair::Keypair,
     solana_last_restart_slot::LastRestartSlot,
+    solana_loader_v3_interface::state::UpgradeableLoaderState,
     solana_message::{
-        inner_instruction::InnerInstructionsList, Message, SanitizedMessage, VersionedMessage,
+        inner_instruction::InnerInstructionsList, v0, AddressLookupTableAccount, Message,
+        SanitizedMessage, VersionedMessage,
     },
     solana_native_token::LAMPORTS_PER_SOL,
-    solana_nonce::{state::DurableNonce, NONCED_TX_MARKER_IX_INDEX},
+    solana_nonce::{
+        state::{Data as NonceData, DurableNonce, State as NonceState},
+        versions::Versions as NonceVersions,
+        NONCED_TX_MARKER_IX_INDEX,
+    },
+    solana_packet::PACKET_DATA_SIZE,
+    solana_program_pack::Pack,
     solana_program_runtime::{
         invoke_context::{BuiltinFunctionWithContext, EnvironmentConfig, InvokeContext},
-        loaded_programs::{LoadProgramMetrics, ProgramCacheEntry},
+        loaded_programs::{
+            LoadProgramMetrics, ProgramCacheEntry, ProgramCacheEntryOwner, ProgramCacheEntryType,
+        },
     },
     solana_pubkey::Pubkey,
     solana_rent::Rent,
-    solana_sdk_ids::{bpf_loader, native_loader, system_program},
+    solana_sdk_ids::{
+        address_lookup_table, bpf_loader, bpf_loader_deprecated, bpf_loader_upgradeable, loader_v4,
+        native_loader, system_program,
+    },
     solana_signature::Signature,
     solana_signer::Signer,
-    solana_slot_hashes::SlotHashes,
+    solana_slot_hashes::{self as slot_hashes, SlotHashes},
     solana_slot_history::SlotHistory,
-    solana_stake_interface::stake_history::StakeHistory,
+    solana_stake_interface::{
+        stake_history::StakeHistory,
+        state::{StakeActivationStatus, StakeStateV2},
+    },
     solana_svm_log_collector::LogCollector,
     solana_svm_timings::ExecuteTimings,
     solana_svm_transaction::svm_message::SVMMessage,
@@ -354,16 +383,28 @@ use {
     solana_sysvar::{Sysvar, SysvarSerialize},
     solana_sysvar_id::SysvarId,
     solana_transaction::{
-        sanitized::{MessageHash, SanitizedTransaction},
+        sanitized::{MessageHash, SanitizedTransaction, MAX_TX_ACCOUNT_LOCKS},
         versioned::VersionedTransaction,
+        Transaction,
     },
     solana_transaction_context::{ExecutionRecord, IndexOfAccount, TransactionContext},
     solana_transaction_error::TransactionError,
-    std::{cell::RefCell, path::Path, rc::Rc, sync::Arc},
+    spl_token_interface::state::{Account as TokenAccount, Mint},
+    std::{
+        borrow::Cow,
+        cell::RefCell,
+        collections::{HashMap, VecDeque},
+        path::Path,
+        rc::Rc,
+        sync::{Arc, Mutex},
+        time::Instant,
+    },
     types::SimulatedTransactionInfo,
     utils::{
         construct_instructions_account,
-        inner_instructions::inner_instructions_list_from_instruction_trace,
+        inner_instructions::{
+            inner_instructions_list_from_instruction_trace, truncate_inner_instructions,
+        },
     },
 };
 
@@ -380,8 +421,21 @@ mod precompiles;
 mod programs;
 #[cfg(feature = "register-tracing")]
 mod register_tracing;
+#[cfg(feature = "fetch")]
+mod rpc;
 mod utils;
 
+/// Admission check run against every transaction before it is executed.
+pub type TransactionFilter =
+    dyn Fn(&VersionedTransaction) -> Result<(), TransactionError> + Send + Sync;
+
+/// How many slots of prioritization fees are kept, matching the
+/// `getRecentPrioritizationFees` RPC method.
+const PRIORITIZATION_FEE_SLOTS: u64 = 150;
+
+/// Callback run against the account store before every transaction is executed.
+pub type PreTransactionHook = dyn FnMut(&mut AccountsView) + Send;
+
 #[derive(Clone)]
 pub struct LiteSVM {
     accounts: AccountsDb,
@@ -389,12 +443,52 @@ pub struct LiteSVM {
     feature_set: FeatureSet,
     reserved_account_keys: ReservedAccountKeys,
     latest_blockhash: Hash,
+    genesis_hash: Hash,
+    /// Mixed into every derived hash and key when set, see [`LiteSVM::with_rng_seed`].
+    rng_seed: Option<u64>,
+    unique_pubkeys_issued: u64,
+    /// Blockhashes that were expired but are still young enough to be used,
+    /// newest first.
+    recent_blockhashes: VecDeque<Hash>,
+    /// `(slot, compute unit price)` of recent prioritized transactions, oldest first.
+    prioritization_fees: VecDeque<(u64, u64)>,
+    blockhash_expiry_slots: u64,
+    /// Blockhashes expired more times than this are rejected even while still queued.
+    max_blockhash_age: Option<u64>,
+    /// The number of blocks produced with [`finalize_block`](Self::finalize_block).
+    block_height: u64,
     history: TransactionHistory,
+    transaction_dedup: bool,
     compute_budget: Option<ComputeBudget>,
+    syscall_costs: SyscallCosts,
     sigverify: bool,
     blockhash_check: bool,
     fee_structure: FeeStructure,
     log_bytes_limit: Option<usize>,
+    max_trace_entries: Option<usize>,
+    max_cpi_depth: Option<usize>,
+    slot_duration_ms: u64,
+    /// Time moved by [`warp_forward_seconds`](Self::warp_forward_seconds)
+    /// that didn't add up to a whole slot yet.
+    sub_slot_ms: u64,
+    /// The clock restored by [`reset_clock`](Self::reset_clock).
+    initial_clock: Clock,
+    rent_collection: bool,
+    require_rent_exempt: bool,
+    capture_instruction_return_data: bool,
+    capture_compute_units_remaining: bool,
+    balance_recording: bool,
+    token_balance_recording: bool,
+    upgradeable_loader_enabled: bool,
+    purge_zero_lamport_accounts: bool,
+    execution_trace: bool,
+    transaction_filter: Option<Arc<TransactionFilter>>,
+    pre_transaction_hook: Option<Arc<Mutex<Box<PreTransactionHook>>>>,
+    metrics: SvmMetrics,
+    program_invocations: HashMap<Pubkey, u64>,
+    /// Whether precompile instructions verify their signatures.
+    #[cfg(feature = "precompiles")]
+    sigverify_precompiles: bool,
     /// The callback which can be used to inspect invoke_context
     /// and extract low-level information such as bpf traces, transaction
     /// context, detailed timings, etc.
@@ -431,12 +525,42 @@ impl LiteSVM {
             reserved_account_keys: Self::reserved_account_keys_for_feature_set(&feature_set),
             feature_set,
             latest_blockhash: create_blockhash(b"genesis"),
+            genesis_hash: create_blockhash(b"genesis"),
+            rng_seed: None,
+            unique_pubkeys_issued: 0,
+            recent_blockhashes: VecDeque::new(),
+            prioritization_fees: VecDeque::new(),
+            blockhash_expiry_slots: MAX_PROCESSING_AGE as u64,
+            max_blockhash_age: None,
+            block_height: 0,
             history: TransactionHistory::new(),
+            transaction_dedup: true,
             compute_budget: None,
+            syscall_costs: SyscallCosts::default(),
             sigverify: false,
             blockhash_check: false,
             fee_structure: FeeStructure::default(),
             log_bytes_limit: Some(10_000),
+            max_trace_entries: None,
+            max_cpi_depth: None,
+            slot_duration_ms: DEFAULT_MS_PER_SLOT,
+            sub_slot_ms: 0,
+            initial_clock: Clock::default(),
+            rent_collection: false,
+            require_rent_exempt: true,
+            capture_instruction_return_data: false,
+            capture_compute_units_remaining: false,
+            balance_recording: false,
+            token_balance_recording: false,
+            upgradeable_loader_enabled: true,
+            purge_zero_lamport_accounts: true,
+            execution_trace: false,
+            transaction_filter: None,
+            pre_transaction_hook: None,
+            metrics: SvmMetrics::default(),
+            program_invocations: HashMap::new(),
+            #[cfg(feature = "precompiles")]
+            sigverify_precompiles: true,
             #[cfg(feature = "invocation-inspect-callback")]
             enable_register_tracing: _enable_register_tracing,
             #[cfg(feature = "invocation-inspect-callback")]
@@ -499,6 +623,19 @@ impl LiteSVM {
         self
     }
 
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_syscall_costs(&mut self, costs: SyscallCosts) {
+        self.syscall_costs = costs;
+    }
+
+    /// Overrides the compute unit cost of individual syscalls, e.g. to match
+    /// the cost table of a specific runtime version. Defaults to the current
+    /// mainnet costs.
+    pub fn with_syscall_costs(mut self, costs: SyscallCosts) -> Self {
+        self.set_syscall_costs(costs);
+        self
+    }
+
     #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
     fn set_sigverify(&mut self, sigverify: bool) {
         self.sigverify = sigverify;
@@ -521,9 +658,45 @@ impl LiteSVM {
         self
     }
 
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_blockhash_expiry_slots(&mut self, slots: u64) {
+        self.blockhash_expiry_slots = slots;
+        self.recent_blockhashes
+            .truncate(slots.saturating_sub(1) as usize);
+    }
+
+    /// Changes for how many blocks, produced with
+    /// [`finalize_block`](LiteSVM::finalize_block) or
+    /// [`warp_to_slot_with_blockhash_expiry`](LiteSVM::warp_to_slot_with_blockhash_expiry),
+    /// a blockhash is accepted. Defaults to 150, as on mainnet.
+    ///
+    /// The latest blockhash is always accepted, so with 1 a blockhash is
+    /// rejected as soon as the next block is produced.
+    /// [`expire_blockhash`](LiteSVM::expire_blockhash) ignores the window and
+    /// rejects earlier blockhashes right away.
+    pub fn with_blockhash_expiry_slots(mut self, slots: u64) -> Self {
+        self.set_blockhash_expiry_slots(slots);
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_max_blockhash_age(&mut self, slots: u64) {
+        self.max_blockhash_age = Some(slots);
+    }
+
+    /// Rejects transactions whose blockhash is more than `slots` blocks old
+    /// with [`TransactionError::BlockhashNotFound`], even if it is still within the
+    /// [expiry window](LiteSVM::with_blockhash_expiry_slots). This models a client
+    /// with a stricter deadline than the cluster. With 0, only the latest
+    /// blockhash is accepted.
+    pub fn with_max_blockhash_age(mut self, slots: u64) -> Self {
+        self.set_max_blockhash_age(slots);
+        self
+    }
+
     #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
     fn set_sysvars(&mut self) {
-        self.set_sysvar(&Clock::default());
+        self.set_sysvar(&self.initial_clock.clone());
         self.set_sysvar(&EpochRewards::default());
         self.set_sysvar(&EpochSchedule::default());
         #[allow(deprecated)]
@@ -576,6 +749,8 @@ impl LiteSVM {
             if builtint
                 .enable_feature_id
                 .is_none_or(|x| self.feature_set.is_active(&x))
+                && (self.upgradeable_loader_enabled
+                    || builtint.program_id != bpf_loader_upgradeable::id())
             {
                 let loaded_program =
                     ProgramCacheEntry::new_builtin(0, builtint.name.len(), builtint.entrypoint);
@@ -593,14 +768,7 @@ impl LiteSVM {
         #[cfg(feature = "register-tracing")]
         let _enable_register_tracing = self.enable_register_tracing;
 
-        let compute_budget = self
-            .compute_budget
-            .unwrap_or(ComputeBudget::new_with_defaults(
-                self.feature_set
-                    .is_active(&raise_cpi_nesting_limit_to_8::ID),
-                self.feature_set
-                    .is_active(&increase_cpi_account_info_limit::ID),
-            ));
+        let compute_budget = self.current_compute_budget();
         let program_runtime_v1 = create_program_runtime_environment_v1(
             &self.feature_set.runtime_features(),
             &compute_budget.to_budget(),
@@ -624,6 +792,22 @@ impl LiteSVM {
         self
     }
 
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_upgradeable_loader_enabled(&mut self, enabled: bool) {
+        self.upgradeable_loader_enabled = enabled;
+    }
+
+    /// Enables or disables the upgradeable BPF loader, to mimic environments
+    /// with only the legacy loaders. Enabled by default.
+    ///
+    /// Without it, programs can't be deployed or upgraded through the loader.
+    /// This takes effect at [`with_builtins`](LiteSVM::with_builtins), so call it
+    /// beforehand, starting from [`LiteSVM::default`].
+    pub fn with_upgradeable_loader_enabled(mut self, enabled: bool) -> Self {
+        self.set_upgradeable_loader_enabled(enabled);
+        self
+    }
+
     #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
     fn set_lamports(&mut self, lamports: u64) {
         self.accounts.add_account_no_checks(
@@ -640,6 +824,110 @@ impl LiteSVM {
         self
     }
 
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_faucet_seed(&mut self, seed: [u8; 32]) {
+        let old_pubkey = self.airdrop_pubkey();
+        self.airdrop_kp = Keypair::new_from_array(seed).to_bytes();
+        let new_pubkey = self.airdrop_pubkey();
+        if old_pubkey == new_pubkey {
+            return;
+        }
+        if let Some(account) = self.accounts.get_account(&old_pubkey) {
+            self.accounts
+                .add_account(old_pubkey, AccountSharedData::default())
+                .unwrap();
+            self.accounts.add_account_no_checks(new_pubkey, account);
+        }
+    }
+
+    /// Derives the faucet keypair that funds airdrops from `seed`, so its
+    /// [`airdrop_pubkey`](LiteSVM::airdrop_pubkey) is the same in every
+    /// instance built with that seed. Without this, the faucet keypair is random.
+    ///
+    /// Any lamports already in the faucet move over to the new faucet account.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the old faucet account can't be removed, which only happens
+    /// if its pubkey is a sysvar address.
+    pub fn with_faucet_seed(mut self, seed: [u8; 32]) -> Self {
+        self.set_faucet_seed(seed);
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_rng_seed(&mut self, seed: u64) {
+        self.rng_seed = Some(seed);
+        let genesis = create_blockhash(&[b"genesis".as_slice(), &seed.to_le_bytes()].concat());
+        self.genesis_hash = genesis;
+        self.set_latest_blockhash(genesis);
+        self.set_faucet_seed(
+            create_blockhash(&[b"faucet".as_slice(), &seed.to_le_bytes()].concat()).to_bytes(),
+        );
+    }
+
+    /// Derives everything LiteSVM would otherwise pick on its own from `seed`:
+    /// the genesis hash and blockhash chain, the faucet keypair, the
+    /// `SlotHashes` entries filled in by warps and the addresses handed out by
+    /// [`next_unique_pubkey`](LiteSVM::next_unique_pubkey).
+    ///
+    /// Two instances with the same seed that perform the same operations end up
+    /// in identical states. Call this before sending any transactions, as
+    /// blockhashes from before it are no longer the latest.
+    pub fn with_rng_seed(mut self, seed: u64) -> Self {
+        self.set_rng_seed(seed);
+        self
+    }
+
+    /// Returns a fresh address, like [`Pubkey::new_unique`]. With
+    /// [`with_rng_seed`](LiteSVM::with_rng_seed) the sequence is derived from
+    /// the seed, so it repeats across runs and instances.
+    pub fn next_unique_pubkey(&mut self) -> Pubkey {
+        let Some(seed) = self.rng_seed else {
+            return Pubkey::new_unique();
+        };
+        self.unique_pubkeys_issued += 1;
+        Pubkey::new_from_array(
+            create_blockhash(
+                &[
+                    b"unique".as_slice(),
+                    &seed.to_le_bytes(),
+                    &self.unique_pubkeys_issued.to_le_bytes(),
+                ]
+                .concat(),
+            )
+            .to_bytes(),
+        )
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_lamports_per_signature(&mut self, lamports_per_signature: u64) {
+        self.fee_structure.lamports_per_signature = lamports_per_signature;
+    }
+
+    /// Changes the base fee charged per signature. Defaults to 5000 lamports.
+    pub fn with_lamports_per_signature(mut self, lamports_per_signature: u64) -> Self {
+        self.set_lamports_per_signature(lamports_per_signature);
+        self
+    }
+
+    /// Returns the fee rate governor in effect.
+    ///
+    /// LiteSVM never adjusts fees to load, so the signature fee is fixed at the
+    /// configured lamports per signature and `target_signatures_per_slot` is 0.
+    /// The burn percent is the mainnet default.
+    pub fn fee_rate_governor(&self) -> FeeRateGovernor {
+        let lamports_per_signature = self.fee_structure.lamports_per_signature;
+        FeeRateGovernor {
+            lamports_per_signature,
+            target_lamports_per_signature: lamports_per_signature,
+            target_signatures_per_slot: 0,
+            min_lamports_per_signature: lamports_per_signature,
+            max_lamports_per_signature: lamports_per_signature,
+            ..FeeRateGovernor::default()
+        }
+    }
+
     #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
     fn set_default_programs(&mut self) {
         load_default_programs(self);
@@ -651,6 +939,36 @@ impl LiteSVM {
         self
     }
 
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_spl_associated_token_account_program(&mut self) {
+        load_spl_associated_token_account_program(self);
+    }
+
+    /// Includes the SPL Associated Token Account program.
+    ///
+    /// This is already part of [`with_default_programs`](LiteSVM::with_default_programs),
+    /// but can be added on its own to a VM built without the default programs.
+    pub fn with_spl_associated_token_account_program(mut self) -> Self {
+        self.set_spl_associated_token_account_program();
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_config_program(&mut self) {
+        load_config_program(self);
+    }
+
+    /// Includes the Config program.
+    ///
+    /// The Config program is no longer a builtin, so
+    /// [`with_builtins`](LiteSVM::with_builtins) does not install it. It is
+    /// already part of [`with_default_programs`](LiteSVM::with_default_programs),
+    /// but can be added on its own to a VM built without the default programs.
+    pub fn with_config_program(mut self) -> Self {
+        self.set_config_program();
+        self
+    }
+
     #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
     fn set_transaction_history(&mut self, capacity: usize) {
         self.history.set_capacity(capacity);
@@ -663,6 +981,22 @@ impl LiteSVM {
         self
     }
 
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_transaction_dedup(&mut self, enabled: bool) {
+        self.transaction_dedup = enabled;
+    }
+
+    /// Enables or disables rejecting transactions whose signature is already
+    /// in the transaction history with `TransactionError::AlreadyProcessed`.
+    /// Enabled by default. Only applies when sigverify is on.
+    ///
+    /// Unlike [`with_transaction_history(0)`](LiteSVM::with_transaction_history),
+    /// disabling this keeps recording transactions in the history.
+    pub fn with_transaction_dedup(mut self, enabled: bool) -> Self {
+        self.set_transaction_dedup(enabled);
+        self
+    }
+
     #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
     fn set_log_bytes_limit(&mut self, limit: Option<usize>) {
         self.log_bytes_limit = limit;
@@ -673,6 +1007,181 @@ impl LiteSVM {
         self
     }
 
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_max_trace_entries(&mut self, max_entries: Option<usize>) {
+        self.max_trace_entries = max_entries;
+    }
+
+    /// Caps the number of inner instructions kept in transaction metadata.
+    ///
+    /// When a transaction produces more, the oldest ones are dropped and
+    /// `inner_instructions_truncated` is set on the metadata.
+    pub fn with_max_trace_entries(mut self, max_entries: usize) -> Self {
+        self.set_max_trace_entries(Some(max_entries));
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_max_cpi_depth(&mut self, depth: usize) {
+        self.max_cpi_depth = Some(depth);
+    }
+
+    /// Caps how deeply instructions may nest cross-program invocations.
+    ///
+    /// A depth of `n` allows `n` nested CPIs below the top-level instruction;
+    /// invoking one more fails with [`InstructionError::CallDepth`]. Defaults
+    /// to the runtime limit for the current feature set (8 once
+    /// `raise_cpi_nesting_limit_to_8` is active, 4 before).
+    pub fn with_max_cpi_depth(mut self, depth: usize) -> Self {
+        self.set_max_cpi_depth(depth);
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_slot_duration_ms(&mut self, slot_duration_ms: u64) {
+        self.slot_duration_ms = slot_duration_ms;
+    }
+
+    /// Changes how long a slot lasts when converting between time and slots,
+    /// e.g. when [`warp_to_slot`](LiteSVM::warp_to_slot) derives the new timestamp.
+    /// Defaults to 400ms.
+    pub fn with_slot_duration_ms(mut self, slot_duration_ms: u64) -> Self {
+        self.set_slot_duration_ms(slot_duration_ms);
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_initial_clock(&mut self, clock: Clock) {
+        self.set_sysvar(&clock);
+        self.initial_clock = clock;
+    }
+
+    /// Sets the clock and makes it the one [`reset_clock`](LiteSVM::reset_clock)
+    /// restores. Defaults to [`Clock::default`].
+    pub fn with_initial_clock(mut self, clock: Clock) -> Self {
+        self.set_initial_clock(clock);
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_rent_collection(&mut self, enabled: bool) {
+        self.rent_collection = enabled;
+    }
+
+    /// Enables or disables rent collection. Disabled by default, as on mainnet.
+    ///
+    /// When enabled, accounts below the rent-exempt minimum are charged the rent
+    /// they owe whenever the clock is warped forward and whenever a transaction
+    /// writes to them.
+    pub fn with_rent_collection(mut self, enabled: bool) -> Self {
+        self.set_rent_collection(enabled);
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_require_rent_exempt(&mut self, enabled: bool) {
+        self.require_rent_exempt = enabled;
+    }
+
+    /// Enables or disables rejecting transactions that leave a newly created or
+    /// resized account below the rent-exempt minimum with
+    /// `TransactionError::InsufficientFundsForRent`. Enabled by default, as on mainnet.
+    ///
+    /// The fee payer must stay rent-exempt either way.
+    pub fn with_require_rent_exempt(mut self, enabled: bool) -> Self {
+        self.set_require_rent_exempt(enabled);
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_capture_instruction_return_data(&mut self, enabled: bool) {
+        self.capture_instruction_return_data = enabled;
+    }
+
+    /// Makes [`simulate_transaction`](LiteSVM::simulate_transaction) record the
+    /// return data left by each top-level instruction in
+    /// [`SimulatedTransactionInfo::instruction_return_data`].
+    ///
+    /// Without this only the return data of the last instruction is observable.
+    pub fn with_capture_instruction_return_data(mut self, enabled: bool) -> Self {
+        self.set_capture_instruction_return_data(enabled);
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_capture_compute_units_remaining(&mut self, enabled: bool) {
+        self.capture_compute_units_remaining = enabled;
+    }
+
+    /// Records how many compute units were left after each top-level
+    /// instruction in [`TransactionMetadata::compute_units_remaining`], to see
+    /// how much headroom a program had.
+    pub fn with_capture_compute_units_remaining(mut self, enabled: bool) -> Self {
+        self.set_capture_compute_units_remaining(enabled);
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_balance_recording(&mut self, enabled: bool) {
+        self.balance_recording = enabled;
+    }
+
+    /// Makes [`send_transaction`](LiteSVM::send_transaction) record the lamports
+    /// of every account before and after the transaction in
+    /// [`TransactionMetadata::pre_balances`] and [`TransactionMetadata::post_balances`],
+    /// aligned to the transaction's account keys as in RPC transaction responses.
+    pub fn with_balance_recording(mut self, enabled: bool) -> Self {
+        self.set_balance_recording(enabled);
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_token_balance_recording(&mut self, enabled: bool) {
+        self.token_balance_recording = enabled;
+    }
+
+    /// Makes [`send_transaction`](LiteSVM::send_transaction) record the balances
+    /// of the SPL Token and Token-2022 accounts the transaction references, before
+    /// and after it runs, in [`TransactionMetadata::pre_token_balances`] and
+    /// [`TransactionMetadata::post_token_balances`], as in RPC transaction responses.
+    ///
+    /// Accounts whose mint does not exist are left out.
+    pub fn with_token_balance_recording(mut self, enabled: bool) -> Self {
+        self.set_token_balance_recording(enabled);
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_execution_trace(&mut self, enabled: bool) {
+        self.execution_trace = enabled;
+    }
+
+    /// Makes sent and simulated transactions record their call graph in
+    /// [`TransactionMetadata::execution_trace`], with the program, logs,
+    /// compute units and return data of every instruction, including CPIs.
+    ///
+    /// The call graph comes from the runtime, so it is complete even when the
+    /// logs are truncated (see [`with_log_bytes_limit`](LiteSVM::with_log_bytes_limit)),
+    /// but instructions past that point have no logs, and inner instructions
+    /// have no compute units or return data, since only the logs report those.
+    pub fn with_execution_trace(mut self, enabled: bool) -> Self {
+        self.set_execution_trace(enabled);
+        self
+    }
+
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    fn set_purge_zero_lamport_accounts(&mut self, enabled: bool) {
+        self.purge_zero_lamport_accounts = enabled;
+    }
+
+    /// Sets whether writable accounts a successful transaction leaves with zero
+    /// lamports are removed from the store, as the runtime garbage-collects them.
+    /// Enabled by default; when disabled, such accounts persist with zero lamports.
+    pub fn with_purge_zero_lamport_accounts(mut self, enabled: bool) -> Self {
+        self.set_purge_zero_lamport_accounts(enabled);
+        self
+    }
+
     #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
     #[cfg(feature = "precompiles")]
     fn set_precompiles(&mut self) {
@@ -688,6 +1197,31 @@ impl LiteSVM {
         self
     }
 
+    #[cfg_attr(feature = "nodejs-internal", qualifiers(pub))]
+    #[cfg(feature = "precompiles")]
+    fn set_sigverify_precompiles(&mut self, enabled: bool) {
+        self.sigverify_precompiles = enabled;
+    }
+
+    /// Enables or disables signature verification in precompile instructions,
+    /// such as ed25519 and secp256k1. Enabled by default.
+    ///
+    /// When disabled, precompile instructions always succeed, while the rest
+    /// of the transaction still runs. Like [`with_sigverify`](LiteSVM::with_sigverify),
+    /// this only makes sense for tests that don't care about the signatures.
+    #[cfg(feature = "precompiles")]
+    pub fn with_sigverify_precompiles(mut self, enabled: bool) -> Self {
+        self.set_sigverify_precompiles(enabled);
+        self
+    }
+
+    /// Returns the ids of the precompiles transactions can currently use: those
+    /// whose account is installed and whose feature is active.
+    #[cfg(feature = "precompiles")]
+    pub fn installed_precompiles(&self) -> Vec<Pubkey> {
+        installed_precompiles(self)
+    }
+
     /// Returns minimum balance required to make an account with specified data length rent exempt.
     pub fn minimum_balance_for_rent_exemption(&self, data_len: usize) -> u64 {
         1.max(
@@ -699,16 +1233,444 @@ impl LiteSVM {
         )
     }
 
+    /// Returns the minimum stake delegation for the configured feature set.
+    ///
+    /// This is 1 SOL when `stake_raise_minimum_delegation_to_1_sol` is active
+    /// and 1 lamport otherwise.
+    pub fn get_minimum_delegation(&self) -> u64 {
+        if self
+            .feature_set
+            .is_active(&stake_raise_minimum_delegation_to_1_sol::id())
+        {
+            LAMPORTS_PER_SOL
+        } else {
+            1
+        }
+    }
+
     /// Returns all information associated with the account of the provided pubkey.
     pub fn get_account(&self, pubkey: &Pubkey) -> Option<Account> {
         self.accounts.get_account(pubkey).map(Into::into)
     }
 
+    /// Like [`get_account`](LiteSVM::get_account), but returns an empty,
+    /// zero-lamport, system-owned account when there is none.
+    ///
+    /// A missing account is indistinguishable from a real empty one, so check
+    /// the lamports and owner, or use `get_account`, where the difference matters.
+    pub fn get_account_or_default(&self, pubkey: &Pubkey) -> AccountSharedData {
+        self.accounts.get_account(pubkey).unwrap_or_default()
+    }
+
+    /// Returns the lamports, data length, owner and executable flag of an account
+    /// without copying its data.
+    pub fn get_account_summary(&self, pubkey: &Pubkey) -> Option<AccountSummary> {
+        self.accounts
+            .get_account_ref(pubkey)
+            .map(|account| AccountSummary {
+                lamports: account.lamports(),
+                data_len: account.data().len(),
+                owner: *account.owner(),
+                executable: account.executable(),
+            })
+    }
+
+    /// Returns the length of an account's data without copying it.
+    pub fn get_account_data_len(&self, pubkey: &Pubkey) -> Option<usize> {
+        self.accounts
+            .get_account_ref(pubkey)
+            .map(|account| account.data().len())
+    }
+
+    /// Returns whether an account is executable without copying it.
+    pub fn is_executable(&self, pubkey: &Pubkey) -> Option<bool> {
+        self.accounts
+            .get_account_ref(pubkey)
+            .map(|account| account.executable())
+    }
+
+    /// Returns the decimals, supply and authorities of an SPL Token or Token-2022 mint,
+    /// or `None` if there is no initialized mint at that address.
+    ///
+    /// Only the base mint layout is read, so Token-2022 extensions are ignored.
+    pub fn get_mint_info(&self, mint: &Pubkey) -> Option<MintInfo> {
+        // Token-2022 pads mints with extensions to the size of a token account
+        // and tags them with an account type byte right after.
+        const ACCOUNT_TYPE_OFFSET: usize = spl_token_interface::state::Account::LEN;
+        const ACCOUNT_TYPE_MINT: u8 = 1;
+        let account = self.accounts.get_account_ref(mint)?;
+        let data = account.data();
+        let is_mint = if account.owner() == &spl_token_interface::ID {
+            data.len() == Mint::LEN
+        } else if account.owner() == &SPL_TOKEN_2022_ID {
+            data.len() == Mint::LEN || data.get(ACCOUNT_TYPE_OFFSET) == Some(&ACCOUNT_TYPE_MINT)
+        } else {
+            false
+        };
+        if !is_mint {
+            return None;
+        }
+        let mint = Mint::unpack(&data[..Mint::LEN]).ok()?;
+        Some(MintInfo {
+            decimals: mint.decimals,
+            supply: mint.supply,
+            mint_authority: mint.mint_authority.into(),
+            freeze_authority: mint.freeze_authority.into(),
+        })
+    }
+
+    /// Returns the authority, deactivation slot and addresses of an address
+    /// lookup table, or `None` if there is no lookup table at that address.
+    ///
+    /// The result converts into a [`solana_message::AddressLookupTableAccount`]
+    /// for [`send_v0_transaction`](LiteSVM::send_v0_transaction).
+    pub fn get_address_lookup_table(
+        &self,
+        address: &Pubkey,
+    ) -> Option<types::AddressLookupTableAccount> {
+        let account = self.accounts.get_account_ref(address)?;
+        if account.owner() != &solana_sdk_ids::address_lookup_table::ID {
+            return None;
+        }
+        let table = AddressLookupTable::deserialize(account.data()).ok()?;
+        Some(types::AddressLookupTableAccount {
+            key: *address,
+            authority: table.meta.authority,
+            deactivation_slot: table.meta.deactivation_slot,
+            addresses: table.addresses.to_vec(),
+        })
+    }
+
+    /// Returns the total supply of an SPL Token or Token-2022 mint.
+    pub fn get_token_supply(&self, mint: &Pubkey) -> Option<u64> {
+        self.get_mint_info(mint).map(|info| info.supply)
+    }
+
+    /// Returns the activation state of a stake account at the current epoch,
+    /// or `None` if there is no initialized stake account at that address.
+    ///
+    /// This is computed from the delegation and the `StakeHistory` sysvar,
+    /// the same way as the `getStakeActivation` RPC method.
+    pub fn get_stake_activation(&self, stake_account: &Pubkey) -> Option<StakeActivation> {
+        let account = self.accounts.get_account_ref(stake_account)?;
+        if account.owner() != &solana_stake_interface::program::ID {
+            return None;
+        }
+        let stake_state: StakeStateV2 = bincode::deserialize(account.data()).ok()?;
+        let rent_exempt_reserve = stake_state.meta()?.rent_exempt_reserve;
+        let inactive_lamports = account.lamports().saturating_sub(rent_exempt_reserve);
+        let Some(delegation) = stake_state.delegation() else {
+            return Some(StakeActivation {
+                state: StakeActivationState::Inactive,
+                active: 0,
+                inactive: inactive_lamports,
+            });
+        };
+
+        let clock = self.get_sysvar::<Clock>();
+        let new_rate_activation_epoch = self
+            .feature_set
+            .new_warmup_cooldown_rate_epoch(&self.get_sysvar::<EpochSchedule>());
+        let StakeActivationStatus {
+            effective,
+            activating,
+            deactivating,
+        } = delegation.stake_activating_and_deactivating(
+            clock.epoch,
+            &self.get_sysvar::<StakeHistory>(),
+            new_rate_activation_epoch,
+        );
+        let state = if deactivating > 0 {
+            StakeActivationState::Deactivating
+        } else if activating > 0 {
+            StakeActivationState::Activating
+        } else if effective > 0 {
+            StakeActivationState::Active
+        } else {
+            StakeActivationState::Inactive
+        };
+        Some(StakeActivation {
+            state,
+            active: effective,
+            inactive: inactive_lamports.saturating_sub(effective),
+        })
+    }
+
+    /// Returns how the program at `program_id` is executed, or `None` if there is
+    /// no executable account at that address.
+    pub fn program_kind(&self, program_id: &Pubkey) -> Option<ProgramKind> {
+        let account = self.accounts.get_account_ref(program_id)?;
+        if !account.executable() {
+            return None;
+        }
+        let owner = account.owner();
+        if native_loader::check_id(owner) {
+            // builtins live in the program cache, precompiles are only accounts
+            if self.accounts.programs_cache.find(program_id).is_some() {
+                Some(ProgramKind::Builtin)
+            } else {
+                Some(ProgramKind::Precompile)
+            }
+        } else if bpf_loader_deprecated::check_id(owner) {
+            Some(ProgramKind::BpfLoaderDeprecated)
+        } else if bpf_loader::check_id(owner) {
+            Some(ProgramKind::BpfLoaderV2)
+        } else if bpf_loader_upgradeable::check_id(owner) {
+            Some(ProgramKind::BpfLoaderUpgradeable)
+        } else if loader_v4::check_id(owner) {
+            Some(ProgramKind::LoaderV4)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the slot at which the account was last written, either by a
+    /// transaction or through this API, or `None` if the account does not exist.
+    pub fn get_account_modified_slot(&self, pubkey: &Pubkey) -> Option<u64> {
+        self.accounts.get_account_ref(pubkey)?;
+        self.accounts.modified_slots.get(pubkey).copied()
+    }
+
     /// Sets all information associated with the account of the provided pubkey.
     pub fn set_account(&mut self, pubkey: Pubkey, data: Account) -> Result<(), LiteSVMError> {
         self.accounts.add_account(pubkey, data.into())
     }
 
+    /// Sets many accounts in one call, in order, as if by [`set_account`](LiteSVM::set_account).
+    ///
+    /// This is all-or-nothing: every entry is validated before any is applied,
+    /// so on error no account has changed. The error is a
+    /// [`LiteSVMError::SetAccounts`] naming the entry that failed. Programs are
+    /// loaded as their entry is reached, so put program data accounts first.
+    pub fn set_accounts(
+        &mut self,
+        entries: &[(Pubkey, AccountSharedData)],
+    ) -> Result<(), LiteSVMError> {
+        let mut staged = self.accounts.clone();
+        for (index, (pubkey, account)) in entries.iter().enumerate() {
+            staged
+                .add_account(*pubkey, account.clone())
+                .map_err(|source| LiteSVMError::SetAccounts {
+                    index,
+                    pubkey: *pubkey,
+                    source: Box::new(source),
+                })?;
+        }
+        self.accounts = staged;
+        Ok(())
+    }
+
+    /// Overwrites a slice of an existing account's data, starting at `offset`.
+    ///
+    /// Fails if the account does not exist or if the write would go past the end
+    /// of its data. The account length is never changed.
+    pub fn set_account_data(
+        &mut self,
+        pubkey: &Pubkey,
+        offset: usize,
+        bytes: &[u8],
+    ) -> Result<(), LiteSVMError> {
+        let mut account = self
+            .accounts
+            .get_account(pubkey)
+            .ok_or(InstructionError::MissingAccount)?;
+        let end = offset
+            .checked_add(bytes.len())
+            .filter(|end| *end <= account.data().len())
+            .ok_or(InstructionError::AccountDataTooSmall)?;
+        account.data_as_mut_slice()[offset..end].copy_from_slice(bytes);
+        self.accounts.add_account(*pubkey, account)
+    }
+
+    /// Creates an initialized SPL Token mint at `mint`, funded to be rent exempt.
+    ///
+    /// Returns [`LiteSVMError::AccountAlreadyExists`] if an account is already stored at `mint`.
+    pub fn create_token_mint(
+        &mut self,
+        mint: &Pubkey,
+        mint_authority: &Pubkey,
+        decimals: u8,
+    ) -> Result<(), LiteSVMError> {
+        let mut data = vec![0; Mint::LEN];
+        Mint {
+            mint_authority: Some(*mint_authority).into(),
+            decimals,
+            is_initialized: true,
+            ..Mint::default()
+        }
+        .pack_into_slice(&mut data);
+        self.create_account_if_absent(mint, &spl_token_interface::ID, data)
+    }
+
+    /// Creates an initialized durable nonce account at `nonce_account`, funded to be rent exempt.
+    ///
+    /// The stored nonce is derived from the current latest blockhash, so the blockhash must
+    /// be expired before the nonce can be advanced.
+    /// Returns [`LiteSVMError::AccountAlreadyExists`] if an account is already stored at `nonce_account`.
+    pub fn create_nonce_account(
+        &mut self,
+        nonce_account: &Pubkey,
+        authority: &Pubkey,
+    ) -> Result<(), LiteSVMError> {
+        let state = NonceVersions::new(NonceState::Initialized(NonceData::new(
+            *authority,
+            DurableNonce::from_blockhash(&self.latest_blockhash),
+            self.fee_structure.lamports_per_signature,
+        )));
+        let data = bincode::serialize(&state).expect("nonce state serializes");
+        self.create_account_if_absent(nonce_account, &system_program::ID, data)
+    }
+
+    /// Creates an active address lookup table at `address` holding `addresses`,
+    /// funded to be rent exempt. All addresses are usable immediately.
+    ///
+    /// Returns [`LiteSVMError::AccountAlreadyExists`] if an account is already stored at `address`.
+    pub fn create_address_lookup_table(
+        &mut self,
+        address: &Pubkey,
+        authority: &Pubkey,
+        addresses: &[Pubkey],
+    ) -> Result<(), LiteSVMError> {
+        let table = AddressLookupTable {
+            meta: LookupTableMeta {
+                authority: Some(*authority),
+                last_extended_slot_start_index: addresses.len() as u8,
+                ..LookupTableMeta::default()
+            },
+            addresses: Cow::Borrowed(addresses),
+        };
+        let data = table
+            .serialize_for_tests()
+            .map_err(|_| InstructionError::InvalidAccountData)?;
+        self.create_account_if_absent(address, &address_lookup_table::ID, data)
+    }
+
+    fn create_account_if_absent(
+        &mut self,
+        address: &Pubkey,
+        owner: &Pubkey,
+        data: Vec<u8>,
+    ) -> Result<(), LiteSVMError> {
+        if self.accounts.get_account_ref(address).is_some() {
+            return Err(LiteSVMError::AccountAlreadyExists(*address));
+        }
+        let lamports = self.minimum_balance_for_rent_exemption(data.len());
+        self.accounts.add_account(
+            *address,
+            Account {
+                lamports,
+                data,
+                owner: *owner,
+                executable: false,
+                rent_epoch: 0,
+            }
+            .into(),
+        )?;
+        Ok(())
+    }
+
+    /// Sets the lamports of an existing account, leaving its data, owner and
+    /// other fields untouched.
+    ///
+    /// Fails if the account does not exist. Setting the lamports to zero removes
+    /// the account, as it would after a transaction.
+    pub fn set_account_lamports(
+        &mut self,
+        pubkey: &Pubkey,
+        lamports: u64,
+    ) -> Result<(), LiteSVMError> {
+        let mut account = self
+            .accounts
+            .get_account(pubkey)
+            .ok_or(InstructionError::MissingAccount)?;
+        account.set_lamports(lamports);
+        if lamports == 0 {
+            return self.accounts.add_account(*pubkey, account);
+        }
+        // the data and owner are unchanged, so there is no program or sysvar to reload
+        self.accounts.add_account_no_checks(*pubkey, account);
+        Ok(())
+    }
+
+    /// Creates an uninitialized account at the program derived address of
+    /// `program_id` and `seeds`, for testing a program's initialize path.
+    ///
+    /// The account gets `space` zeroed bytes, is owned by the program and is
+    /// funded rent-exempt by `payer`. No transaction is sent, so no fee is
+    /// charged. Returns the derived address.
+    ///
+    /// Fails with [`LiteSVMError::AccountAlreadyExists`] if an account already
+    /// exists at that address. Fails with [`InstructionError::InsufficientFunds`]
+    /// if `payer` can't cover the rent-exempt balance, or if paying it would
+    /// leave `payer` with a non-zero balance below its own rent-exempt minimum,
+    /// which no transaction could do.
+    pub fn create_pda_account(
+        &mut self,
+        program_id: &Pubkey,
+        seeds: &[&[u8]],
+        space: usize,
+        payer: &Keypair,
+    ) -> Result<Pubkey, LiteSVMError> {
+        let (pda, _bump) = Pubkey::find_program_address(seeds, program_id);
+        if self.accounts.get_account_ref(&pda).is_some() {
+            return Err(LiteSVMError::AccountAlreadyExists(pda));
+        }
+        let lamports = self.minimum_balance_for_rent_exemption(space);
+        let mut payer_account = self
+            .accounts
+            .get_account(&payer.pubkey())
+            .ok_or(InstructionError::InsufficientFunds)?;
+        let remaining = payer_account
+            .lamports()
+            .checked_sub(lamports)
+            .ok_or(InstructionError::InsufficientFunds)?;
+        if remaining > 0
+            && remaining < self.minimum_balance_for_rent_exemption(payer_account.data().len())
+        {
+            return Err(InstructionError::InsufficientFunds.into());
+        }
+        payer_account.set_lamports(remaining);
+        self.accounts.add_account(payer.pubkey(), payer_account)?;
+        self.accounts
+            .add_account(pda, AccountSharedData::new(lamports, space, program_id))?;
+        Ok(pda)
+    }
+
+    /// Checks that the data stored at `pubkey` equals `expected`.
+    ///
+    /// On mismatch the error names the first differing offset and shows the bytes
+    /// around it, which is easier to read than `assert_eq!` on two large byte vectors.
+    pub fn assert_account_data_eq(&self, pubkey: &Pubkey, expected: &[u8]) -> Result<(), String> {
+        const CONTEXT: usize = 8;
+
+        let actual = self
+            .accounts
+            .get_account_ref(pubkey)
+            .ok_or_else(|| format!("Account {pubkey} not found"))?
+            .data();
+        let Some(offset) = actual
+            .iter()
+            .zip(expected)
+            .position(|(a, e)| a != e)
+            .or_else(|| {
+                (actual.len() != expected.len()).then_some(actual.len().min(expected.len()))
+            })
+        else {
+            return Ok(());
+        };
+        let start = offset.saturating_sub(CONTEXT);
+        let end = offset.saturating_add(CONTEXT + 1);
+        let window = |data: &[u8]| data[start.min(data.len())..end.min(data.len())].to_vec();
+        Err(format!(
+            "Account {pubkey} data differs at offset {offset} (actual len {}, expected len {})\n  \
+             actual[{start}..]:   {:?}\n  expected[{start}..]: {:?}",
+            actual.len(),
+            expected.len(),
+            window(actual),
+            window(expected),
+        ))
+    }
+
     /// **⚠️ ADVANCED USE ONLY ⚠️**
     ///
     /// Returns a reference to the internal accounts database.
@@ -731,6 +1693,22 @@ impl LiteSVM {
         &self.accounts
     }
 
+    /// Returns every installed sysvar account, sorted by address.
+    ///
+    /// The instructions sysvar is built per transaction and never stored,
+    /// so it is not included.
+    pub fn sysvar_accounts(&self) -> Vec<(Pubkey, AccountSharedData)> {
+        let mut accounts: Vec<_> = self
+            .accounts
+            .inner
+            .iter()
+            .filter(|(_, account)| account.owner() == &solana_sdk_ids::sysvar::ID)
+            .map(|(address, account)| (*address, account.clone()))
+            .collect();
+        accounts.sort_unstable_by_key(|(address, _)| *address);
+        accounts
+    }
+
     /// Gets the balance of the provided account pubkey.
     pub fn get_balance(&self, pubkey: &Pubkey) -> Option<u64> {
         self.accounts.get_account_ref(pubkey).map(|x| x.lamports())
@@ -741,14 +1719,137 @@ impl LiteSVM {
         self.latest_blockhash
     }
 
+    /// Gets the latest blockhash along with the last block height at which it
+    /// is still accepted, like the RPC `getLatestBlockhash`.
+    ///
+    /// Every [`finalize_block`](LiteSVM::finalize_block) uses up one slot of the
+    /// [expiry window](LiteSVM::with_blockhash_expiry_slots), and the latest
+    /// blockhash takes the first one, so this is the current block height plus
+    /// the window minus one. [`expire_blockhash`](LiteSVM::expire_blockhash)
+    /// rejects it right away.
+    pub fn latest_blockhash_info(&self) -> LatestBlockhashInfo {
+        LatestBlockhashInfo {
+            blockhash: self.latest_blockhash,
+            last_valid_block_height: self
+                .block_height
+                .saturating_add(self.blockhash_expiry_slots.saturating_sub(1)),
+        }
+    }
+
+    /// Gets the genesis hash, which identifies the cluster.
+    ///
+    /// Defaults to the SHA-256 hash of `b"genesis"`, which is also the initial blockhash.
+    pub fn genesis_hash(&self) -> Hash {
+        self.genesis_hash
+    }
+
+    /// Sets the genesis hash, e.g. to pin the identity of a specific cluster.
+    pub fn set_genesis_hash(&mut self, hash: Hash) {
+        self.genesis_hash = hash;
+    }
+
+    /// Sets the latest blockhash, e.g. to restore a saved state. Earlier
+    /// blockhashes are rejected, as after [`expire_blockhash`](LiteSVM::expire_blockhash).
+    pub fn set_latest_blockhash(&mut self, hash: Hash) {
+        self.recent_blockhashes.clear();
+        self.replace_latest_blockhash(hash);
+    }
+
+    /// Returns the fee in lamports that would be charged for the given message,
+    /// including the prioritization fee requested by any compute budget instructions.
+    ///
+    /// This mirrors the `getFeeForMessage` RPC method.
+    pub fn get_fee_for_message(&self, message: VersionedMessage) -> Result<u64, TransactionError> {
+        self.get_fee_breakdown_for_message(message)
+            .map(|breakdown| breakdown.total_fee)
+    }
+
+    /// Like [`get_fee_for_message`](LiteSVM::get_fee_for_message), but splits
+    /// the fee into its signature and prioritization parts.
+    pub fn get_fee_breakdown_for_message(
+        &self,
+        message: VersionedMessage,
+    ) -> Result<FeeBreakdown, TransactionError> {
+        let num_signatures = usize::from(message.header().num_required_signatures);
+        let tx = VersionedTransaction {
+            signatures: vec![Signature::default(); num_signatures],
+            message,
+        };
+        let sanitized_tx = self.sanitize_transaction_no_verify_inner(tx)?;
+        let compute_budget_limits = process_compute_budget_instructions(
+            SVMMessage::program_instructions_iter(&sanitized_tx),
+            &self.feature_set,
+        )?;
+        let fee_features = FeeFeatures::from(&self.feature_set);
+        let fee_details = solana_fee::calculate_fee_details(
+            sanitized_tx.message(),
+            false,
+            self.fee_structure.lamports_per_signature,
+            compute_budget_limits.get_prioritization_fee(),
+            fee_features,
+        );
+        // at one lamport per signature, the signature fee is the number of signatures charged for
+        let signature_count = solana_fee::calculate_signature_fee(
+            SignatureCounts::from(sanitized_tx.message()),
+            1,
+            fee_features.enable_secp256r1_precompile,
+        );
+        Ok(FeeBreakdown {
+            signature_count,
+            signature_fee: fee_details.transaction_fee(),
+            prioritization_fee: fee_details.prioritization_fee(),
+            total_fee: fee_details.total_fee(),
+        })
+    }
+
+    /// Returns the accounts `tx` would lock for writing and for reading,
+    /// without executing it or checking its signatures.
+    ///
+    /// Addresses from lookup tables are resolved against the current state,
+    /// and accounts the runtime demotes to readonly, such as programs and
+    /// sysvars, are reported as readonly. Fails if the transaction cannot be
+    /// sanitized or locks more than the runtime allows.
+    pub fn get_account_locks(
+        &self,
+        tx: impl Into<VersionedTransaction>,
+    ) -> Result<AccountLocks, TransactionError> {
+        let sanitized_tx = self.sanitize_transaction_no_verify_inner(tx.into())?;
+        let locks = sanitized_tx.get_account_locks(MAX_TX_ACCOUNT_LOCKS)?;
+        Ok(AccountLocks {
+            writable: locks.writable.into_iter().copied().collect(),
+            readonly: locks.readonly.into_iter().copied().collect(),
+        })
+    }
+
     /// Sets the sysvar to the test environment.
     pub fn set_sysvar<T>(&mut self, sysvar: &T)
     where
         T: Sysvar + SysvarId + SysvarSerialize,
     {
-        let mut account = AccountSharedData::new(1, T::size_of(), &solana_sdk_ids::sysvar::id());
-        account.serialize_data(sysvar).unwrap();
-        self.accounts.add_account(T::id(), account).unwrap();
+        self.accounts
+            .add_account(T::id(), sysvar_account(sysvar))
+            .unwrap();
+    }
+
+    /// Sets any of the clock, rent and epoch schedule sysvars in one step.
+    ///
+    /// The sysvar cache is only refreshed once all of them are written, so
+    /// unlike calling [`set_sysvar`](LiteSVM::set_sysvar) for each, there is
+    /// no intermediate state where only some of them have changed.
+    pub fn set_sysvars_bulk(
+        &mut self,
+        clock: Option<Clock>,
+        rent: Option<Rent>,
+        epoch_schedule: Option<EpochSchedule>,
+    ) {
+        let accounts = [
+            clock.map(|clock| (Clock::id(), sysvar_account(&clock))),
+            rent.map(|rent| (Rent::id(), sysvar_account(&rent))),
+            epoch_schedule.map(|schedule| (EpochSchedule::id(), sysvar_account(&schedule))),
+        ];
+        self.accounts
+            .add_sysvar_accounts(accounts.into_iter().flatten().collect())
+            .unwrap();
     }
 
     /// Gets a sysvar from the test environment.
@@ -759,6 +1860,60 @@ impl LiteSVM {
         bincode::deserialize(self.accounts.get_account_ref(&T::id()).unwrap().data()).unwrap()
     }
 
+    /// Gets the [`Clock`] sysvar.
+    pub fn get_clock(&self) -> Clock {
+        self.accounts
+            .sysvar_cache
+            .get_clock()
+            .map(|clock| clock.as_ref().clone())
+            .unwrap_or_else(|_| self.get_sysvar())
+    }
+
+    /// Sets the [`Clock`] sysvar, like [`set_sysvar`](LiteSVM::set_sysvar).
+    pub fn set_clock(&mut self, clock: &Clock) {
+        self.set_sysvar(clock);
+    }
+
+    /// Sets only the clock's `unix_timestamp`, leaving the other fields as they are.
+    pub fn set_unix_timestamp(&mut self, unix_timestamp: i64) {
+        let mut clock = self.get_clock();
+        clock.unix_timestamp = unix_timestamp;
+        self.set_clock(&clock);
+    }
+
+    /// Sets only the clock's `slot`, leaving the other fields as they are.
+    ///
+    /// Unlike [`warp_to_slot`](LiteSVM::warp_to_slot), this does not move the
+    /// timestamp or touch the slot hashes.
+    pub fn set_slot(&mut self, slot: u64) {
+        let mut clock = self.get_clock();
+        clock.slot = slot;
+        self.set_clock(&clock);
+    }
+
+    /// Returns how many transactions have been processed, successful or not.
+    ///
+    /// Unlike the transaction history, this is not bounded by a capacity.
+    pub fn transaction_count(&self) -> u64 {
+        self.metrics.transactions
+    }
+
+    /// Returns cumulative execution metrics since this instance was created.
+    pub fn metrics(&self) -> SvmMetrics {
+        self.metrics
+    }
+
+    /// Returns how many times `program_id` was entered by sent transactions
+    /// since this instance was created, counting both top-level instructions
+    /// and CPIs. Instructions after the one a transaction failed on never run
+    /// and are not counted.
+    pub fn program_invocation_count(&self, program_id: &Pubkey) -> u64 {
+        self.program_invocations
+            .get(program_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
     /// Gets a transaction from the transaction history.
     pub fn get_transaction(&self, signature: &Signature) -> Option<&TransactionResult> {
         self.history.get_transaction(signature)
@@ -772,9 +1927,17 @@ impl LiteSVM {
     }
 
     /// Airdrops the account with the lamports specified.
+    ///
+    /// This is a system transfer, so an existing account only gains lamports:
+    /// its owner, data and executable flag are left as they are.
     pub fn airdrop(&mut self, pubkey: &Pubkey, lamports: u64) -> TransactionResult {
+        let tx = self.airdrop_transaction(pubkey, lamports);
+        self.send_transaction(tx)
+    }
+
+    fn airdrop_transaction(&self, pubkey: &Pubkey, lamports: u64) -> VersionedTransaction {
         let payer = Keypair::try_from(self.airdrop_kp.as_slice()).unwrap();
-        let tx = VersionedTransaction::try_new(
+        VersionedTransaction::try_new(
             VersionedMessage::Legacy(Message::new_with_blockhash(
                 &[solana_system_interface::instruction::transfer(
                     &payer.pubkey(),
@@ -786,11 +1949,176 @@ impl LiteSVM {
             )),
             &[payer],
         )
+        .unwrap()
+    }
+
+    /// Returns the balance `pubkey` would have after
+    /// [`airdrop`](LiteSVM::airdrop)ping it `lamports`, without sending anything.
+    ///
+    /// An account that does not exist yet would be created with `lamports`.
+    /// When the airdrop would fail, the current balance is returned: when the
+    /// faucet can't cover `lamports` plus the fee, when the balance would
+    /// overflow, when the same airdrop was already sent against the latest
+    /// blockhash, or when an account holding data would stay below its
+    /// rent-exempt minimum while [`with_require_rent_exempt`](LiteSVM::with_require_rent_exempt)
+    /// is on.
+    pub fn preview_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> u64 {
+        let balance = self.get_balance(pubkey).unwrap_or_default();
+        let tx = self.airdrop_transaction(pubkey, lamports);
+        let Ok(fee) = self.get_fee_for_message(tx.message.clone()) else {
+            return balance;
+        };
+        let faucet_balance = self.get_balance(&self.airdrop_pubkey()).unwrap_or_default();
+        if fee.saturating_add(lamports) > faucet_balance {
+            return balance;
+        }
+        if self.transaction_dedup
+            && self.sigverify
+            && self.history.check_transaction(&tx.signatures[0])
+        {
+            return balance;
+        }
+        let Some(new_balance) = balance.checked_add(lamports) else {
+            return balance;
+        };
+        let data_len = self.get_account_data_len(pubkey).unwrap_or_default();
+        if self.require_rent_exempt
+            && lamports > 0
+            && data_len > 0
+            && new_balance < self.minimum_balance_for_rent_exemption(data_len)
+        {
+            return balance;
+        }
+        new_balance
+    }
+
+    /// Sends lamports from `from` to every recipient with a single transaction
+    /// made of one system transfer per recipient, paid for by `from`.
+    ///
+    /// Fails with [`TransactionError::SanitizeFailure`] without executing anything
+    /// if the transaction would not fit in a packet.
+    pub fn transfer_many(
+        &mut self,
+        from: &Keypair,
+        recipients: &[(Pubkey, u64)],
+    ) -> TransactionResult {
+        let instructions: Vec<_> = recipients
+            .iter()
+            .map(|(to, lamports)| {
+                solana_system_interface::instruction::transfer(&from.pubkey(), to, *lamports)
+            })
+            .collect();
+        let tx = VersionedTransaction::try_new(
+            VersionedMessage::Legacy(Message::new_with_blockhash(
+                &instructions,
+                Some(&from.pubkey()),
+                &self.latest_blockhash,
+            )),
+            &[from],
+        )
+        .unwrap();
+        let tx_len = bincode::serialized_size(&tx).unwrap_or(u64::MAX);
+        if tx_len > PACKET_DATA_SIZE as u64 {
+            return TransactionResult::Err(FailedTransactionMetadata {
+                err: TransactionError::SanitizeFailure,
+                meta: TransactionMetadata {
+                    signature: tx.signatures[0],
+                    ..Default::default()
+                },
+            });
+        }
+
+        self.send_transaction(tx)
+    }
+
+    /// Sends a transaction containing only `instruction`, signed by `signers`
+    /// against the latest blockhash. The first signer pays the fee.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `signers` is empty or does not cover every required signature.
+    pub fn process_instruction(
+        &mut self,
+        instruction: Instruction,
+        signers: &[&Keypair],
+    ) -> TransactionResult {
+        let payer = signers
+            .first()
+            .expect("process_instruction needs at least one signer")
+            .pubkey();
+        let tx = VersionedTransaction::try_new(
+            VersionedMessage::Legacy(Message::new_with_blockhash(
+                &[instruction],
+                Some(&payer),
+                &self.latest_blockhash,
+            )),
+            signers,
+        )
         .unwrap();
 
         self.send_transaction(tx)
     }
 
+    /// Sends a transaction containing `instructions` with `fee_payer` placed
+    /// first in the message, so it pays the fee whatever order the instruction
+    /// accounts are in. The transaction is signed against the latest blockhash
+    /// by `fee_payer` and `other_signers`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fee_payer` and `other_signers` don't cover every required signature.
+    pub fn send_with_fee_payer(
+        &mut self,
+        instructions: &[Instruction],
+        fee_payer: &Keypair,
+        other_signers: &[&Keypair],
+    ) -> TransactionResult {
+        let signers: Vec<&Keypair> = std::iter::once(fee_payer)
+            .chain(other_signers.iter().copied())
+            .collect();
+        let tx = VersionedTransaction::try_new(
+            VersionedMessage::Legacy(Message::new_with_blockhash(
+                instructions,
+                Some(&fee_payer.pubkey()),
+                &self.latest_blockhash,
+            )),
+            &signers,
+        )
+        .unwrap();
+
+        self.send_transaction(tx)
+    }
+
+    /// Compiles `instructions` into a v0 message that loads accounts through
+    /// `lookup_tables`, signs it against the latest blockhash and sends it.
+    /// The first signer pays the fee.
+    ///
+    /// The lookup tables only tell the compiler which addresses they hold;
+    /// the table accounts themselves must exist in the SVM and be usable at
+    /// the current slot for the transaction to load.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `signers` is empty or does not cover every required signature,
+    /// or if the message cannot be compiled.
+    pub fn send_v0_transaction(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+        lookup_tables: &[AddressLookupTableAccount],
+    ) -> TransactionResult {
+        let payer = signers
+            .first()
+            .expect("send_v0_transaction needs at least one signer")
+            .pubkey();
+        let message =
+            v0::Message::try_compile(&payer, instructions, lookup_tables, self.latest_blockhash)
+                .unwrap();
+        let tx = VersionedTransaction::try_new(VersionedMessage::V0(message), signers).unwrap();
+
+        self.send_transaction(tx)
+    }
+
     /// Adds a builtin program to the test environment.
     pub fn add_builtin(&mut self, program_id: Pubkey, entrypoint: BuiltinFunctionWithContext) {
         let builtin = ProgramCacheEntry::new_builtin(
@@ -807,7 +2135,7 @@ impl LiteSVM {
             .programs_cache
             .replenish(program_id, Arc::new(builtin));
 
-        let mut account = AccountSharedData::new(1, 1, &bpf_loader::id());
+        let mut account = AccountSharedData::new(1, 1, &native_loader::id());
         account.set_executable(true);
         self.accounts.add_account_no_checks(program_id, account);
     }
@@ -844,19 +2172,218 @@ impl LiteSVM {
         let mut loaded_program = solana_bpf_loader_program::load_program_from_bytes(
             None,
             &mut LoadProgramMetrics::default(),
-            account.data(),
-            account.owner(),
-            account.data().len(),
+            account.data(),
+            account.owner(),
+            account.data().len(),
+            current_slot,
+            self.accounts.environments.program_runtime_v1.clone(),
+            false,
+        )
+        .unwrap_or_default();
+        loaded_program.effective_slot = current_slot;
+        self.accounts.add_account(program_id, account)?;
+        self.accounts
+            .programs_cache
+            .replenish(program_id, Arc::new(loaded_program));
+        Ok(())
+    }
+
+    /// Replaces the builtin at `program_id`, e.g. the system or vote program,
+    /// with an SBF program, so tests can swap a core program for a test double.
+    ///
+    /// The builtin is evicted from the program cache and its account is
+    /// replaced by one owned by BPF loader v2. Instructions to `program_id`
+    /// are still budgeted as builtin instructions when the transaction sets
+    /// no compute unit limit, so the stand-in should stay cheap or the
+    /// transaction should request a limit explicitly.
+    ///
+    /// Returns [`LiteSVMError::NotABuiltin`] if no builtin is registered at
+    /// `program_id`. Use [`add_program`](LiteSVM::add_program) to deploy or
+    /// replace other programs.
+    pub fn override_builtin(
+        &mut self,
+        program_id: Pubkey,
+        program_bytes: &[u8],
+    ) -> Result<(), LiteSVMError> {
+        if self.program_kind(&program_id) != Some(ProgramKind::Builtin) {
+            return Err(LiteSVMError::NotABuiltin(program_id));
+        }
+        self.add_program(program_id, program_bytes)
+    }
+
+    /// Adds `program_bytes` as an SBF program owned by BPF loader v2 without
+    /// verifying them, e.g. to fuzz how the runtime handles malformed programs
+    /// at invocation.
+    ///
+    /// Bytes that parse as an ELF are loaded with the bytecode verifier
+    /// skipped. Bytes that do not are cached as having failed verification,
+    /// the same as a program the runtime refused to load.
+    ///
+    /// # Safety caveats
+    ///
+    /// The verifier is what guarantees that a program's jumps, calls and
+    /// instruction encodings are valid. Unverified bytecode may fail in ways
+    /// a deployed program never could, such as jumping out of bounds or
+    /// executing unknown opcodes, so results obtained this way say nothing
+    /// about how the program would behave on a cluster.
+    pub fn add_program_raw_unchecked(&mut self, program_id: &Pubkey, program_bytes: &[u8]) {
+        let program_len = program_bytes.len();
+        let lamports = self.minimum_balance_for_rent_exemption(program_len);
+        let mut account = AccountSharedData::new(lamports, program_len, &bpf_loader::id());
+        account.set_executable(true);
+        account.set_data_from_slice(program_bytes);
+        let current_slot = self
+            .accounts
+            .sysvar_cache
+            .get_clock()
+            .unwrap_or_default()
+            .slot;
+        let environment = self.accounts.environments.program_runtime_v1.clone();
+        let mut loaded_program = solana_bpf_loader_program::load_program_from_bytes(
+            None,
+            &mut LoadProgramMetrics::default(),
+            program_bytes,
+            &bpf_loader::id(),
+            program_len,
             current_slot,
-            self.accounts.environments.program_runtime_v1.clone(),
-            false,
+            environment.clone(),
+            true,
         )
-        .unwrap_or_default();
+        .unwrap_or_else(|_| {
+            ProgramCacheEntry::new_tombstone(
+                current_slot,
+                ProgramCacheEntryOwner::LoaderV2,
+                ProgramCacheEntryType::FailedVerification(environment),
+            )
+        });
         loaded_program.effective_slot = current_slot;
-        self.accounts.add_account(program_id, account)?;
+        self.accounts.add_account_no_checks(*program_id, account);
         self.accounts
             .programs_cache
-            .replenish(program_id, Arc::new(loaded_program));
+            .replenish(*program_id, Arc::new(loaded_program));
+    }
+
+    /// Deploys an SBF program under the upgradeable loader.
+    ///
+    /// Unlike [`add_program`](LiteSVM::add_program), this creates both the program
+    /// account and its programdata account, so the program looks like one deployed
+    /// with `solana program deploy`.
+    pub fn deploy_upgradeable_program(
+        &mut self,
+        program_id: impl Into<Pubkey>,
+        programdata_address: impl Into<Pubkey>,
+        program_bytes: &[u8],
+        upgrade_authority: Option<Pubkey>,
+    ) -> Result<(), LiteSVMError> {
+        let program_id = program_id.into();
+        let programdata_address = programdata_address.into();
+        let current_slot = self
+            .accounts
+            .sysvar_cache
+            .get_clock()
+            .unwrap_or_default()
+            .slot;
+
+        let metadata_len = UpgradeableLoaderState::size_of_programdata_metadata();
+        let programdata_len = metadata_len + program_bytes.len();
+        let mut programdata_account = AccountSharedData::new(
+            self.minimum_balance_for_rent_exemption(programdata_len),
+            programdata_len,
+            &bpf_loader_upgradeable::id(),
+        );
+        programdata_account.set_state(&UpgradeableLoaderState::ProgramData {
+            slot: current_slot,
+            upgrade_authority_address: upgrade_authority,
+        })?;
+        programdata_account.data_as_mut_slice()[metadata_len..].copy_from_slice(program_bytes);
+
+        let program_len = UpgradeableLoaderState::size_of_program();
+        let mut program_account = AccountSharedData::new(
+            self.minimum_balance_for_rent_exemption(program_len),
+            program_len,
+            &bpf_loader_upgradeable::id(),
+        );
+        program_account.set_state(&UpgradeableLoaderState::Program {
+            programdata_address,
+        })?;
+        program_account.set_executable(true);
+
+        // the programdata account must exist before the program can be loaded
+        self.accounts
+            .add_account(programdata_address, programdata_account)?;
+        self.accounts.add_account(program_id, program_account)
+    }
+
+    /// Installs an upgradeable program from its program and programdata accounts,
+    /// e.g. ones fetched from a cluster with `getMultipleAccounts`.
+    ///
+    /// The programdata address is resolved from the program account, and the
+    /// programdata account is written first so the program can be loaded.
+    pub fn set_upgradeable_program(
+        &mut self,
+        program_id: Pubkey,
+        program: Account,
+        programdata: Account,
+    ) -> Result<(), LiteSVMError> {
+        let program = AccountSharedData::from(program);
+        let UpgradeableLoaderState::Program {
+            programdata_address,
+        } = program.state()?
+        else {
+            return Err(InstructionError::InvalidAccountData.into());
+        };
+        self.accounts
+            .add_account(programdata_address, programdata.into())?;
+        self.accounts.add_account(program_id, program)
+    }
+
+    /// Clones an upgradeable program and its programdata account from the
+    /// cluster at `url` and installs them with
+    /// [`set_upgradeable_program`](LiteSVM::set_upgradeable_program).
+    ///
+    /// `url` may be an `http://` or `https://` endpoint. Requests time out
+    /// after 30 seconds and fail with [`LiteSVMError::Rpc`].
+    #[cfg(feature = "fetch")]
+    pub fn clone_upgradeable_program_from_rpc(
+        &mut self,
+        program_id: &Pubkey,
+        url: &str,
+    ) -> Result<(), LiteSVMError> {
+        let not_found = |pubkey: &Pubkey| LiteSVMError::Rpc(format!("account {pubkey} not found"));
+        let program = rpc::get_account(url, program_id)?.ok_or_else(|| not_found(program_id))?;
+        let UpgradeableLoaderState::Program {
+            programdata_address,
+        } = AccountSharedData::from(program.clone()).state()?
+        else {
+            return Err(InstructionError::IncorrectProgramId.into());
+        };
+        let programdata = rpc::get_account(url, &programdata_address)?
+            .ok_or_else(|| not_found(&programdata_address))?;
+        self.set_upgradeable_program(*program_id, program, programdata)
+    }
+
+    /// Makes sure every executable account has a verified and compiled entry
+    /// in the program cache, so no invocation pays for loading the program.
+    ///
+    /// Programs added through this API are already loaded eagerly; this
+    /// catches any executable account that does not have a cache entry yet,
+    /// and is meant to be called before the measured region of a benchmark.
+    pub fn warm_program_cache(&mut self) -> Result<(), LiteSVMError> {
+        let missing: Vec<(Pubkey, AccountSharedData)> = self
+            .accounts
+            .inner
+            .iter()
+            .filter(|(pubkey, account)| {
+                account.executable()
+                    && !native_loader::check_id(account.owner())
+                    && self.accounts.programs_cache.find(pubkey).is_none()
+            })
+            .map(|(pubkey, account)| (*pubkey, account.clone()))
+            .collect();
+        // programdata accounts are not executable, so they are already in place
+        for (program_id, account) in missing {
+            self.accounts.add_account(program_id, account)?;
+        }
         Ok(())
     }
 
@@ -927,6 +2454,9 @@ impl LiteSVM {
         tx: &'b SanitizedTransaction,
         compute_budget_limits: ComputeBudgetLimits,
         log_collector: Rc<RefCell<LogCollector>>,
+        instruction_return_data: Option<&mut InstructionReturnData>,
+        compute_units_remaining: Option<&mut Vec<u64>>,
+        execution_trace: Option<&mut Vec<ExecutionTrace>>,
     ) -> (
         Result<(), TransactionError>,
         u64,
@@ -937,7 +2467,7 @@ impl LiteSVM {
     where
         'a: 'b,
     {
-        let compute_budget = self.compute_budget.unwrap_or_else(|| ComputeBudget {
+        let mut compute_budget = self.compute_budget.unwrap_or_else(|| ComputeBudget {
             compute_unit_limit: u64::from(compute_budget_limits.compute_unit_limit),
             heap_size: compute_budget_limits.updated_heap_bytes,
             ..ComputeBudget::new_with_defaults(
@@ -947,6 +2477,7 @@ impl LiteSVM {
                     .is_active(&increase_cpi_account_info_limit::ID),
             )
         });
+        self.apply_max_cpi_depth(&mut compute_budget);
         let rent = self.accounts.sysvar_cache.get_rent().unwrap();
         let message = tx.message();
         let blockhash = message.recent_blockhash();
@@ -1018,7 +2549,8 @@ impl LiteSVM {
             .message()
             .instructions()
             .iter()
-            .map(|c| {
+            .enumerate()
+            .map(|(instruction_index, c)| {
                 let program_index = c.program_id_index as usize;
                 // This may never error, because the transaction is sanitized
                 let (program_id, program_account) = accounts.get(program_index).unwrap();
@@ -1035,6 +2567,26 @@ impl LiteSVM {
                     return Ok(program_index as IndexOfAccount);
                 }
 
+                // Programs are loaded when they are stored, so a missing cache entry
+                // means the account bypassed that check. Load it now to report why.
+                if program_cache_for_tx_batch.find(program_id).is_none() {
+                    match self.accounts.load_program(program_account) {
+                        Ok(loaded_program) => {
+                            program_cache_for_tx_batch
+                                .replenish(*program_id, Arc::new(loaded_program));
+                        }
+                        Err(err) => {
+                            log_collector
+                                .borrow_mut()
+                                .log(&format!("Program {program_id} failed to load: {err}"));
+                            return Err(TransactionError::InstructionError(
+                                instruction_index as u8,
+                                InstructionError::UnsupportedProgramId,
+                            ));
+                        }
+                    }
+                }
+
                 if !accounts
                     .get(builtins_start_index..)
                     .ok_or(TransactionError::ProgramAccountNotFound)?
@@ -1077,7 +2629,7 @@ impl LiteSVM {
                     ),
                     Some(log_collector),
                     compute_budget.to_budget(),
-                    SVMTransactionExecutionCost::default(),
+                    self.syscall_costs,
                 );
 
                 #[cfg(feature = "invocation-inspect-callback")]
@@ -1093,6 +2645,9 @@ impl LiteSVM {
                     &mut invoke_context,
                     &mut ExecuteTimings::default(),
                     &mut accumulated_consume_units,
+                    instruction_return_data,
+                    compute_units_remaining,
+                    execution_trace,
                 )
                 .map(|_| ());
 
@@ -1100,9 +2655,11 @@ impl LiteSVM {
                 self.invocation_inspect_callback
                     .after_invocation(&invoke_context, self.enable_register_tracing);
 
-                if let Err(err) = self.check_accounts_rent(tx, &context, &rent) {
-                    tx_result = Err(err);
-                };
+                if self.require_rent_exempt {
+                    if let Err(err) = self.check_accounts_rent(tx, &context, &rent) {
+                        tx_result = Err(err);
+                    };
+                }
 
                 (
                     tx_result,
@@ -1180,6 +2737,27 @@ impl LiteSVM {
         sanitized_tx: &SanitizedTransaction,
         log_collector: Rc<RefCell<LogCollector>>,
     ) -> ExecutionResult {
+        let pre_balances = if self.balance_recording {
+            sanitized_tx
+                .message()
+                .account_keys()
+                .iter()
+                .map(|key| (*key, self.get_balance(key).unwrap_or_default()))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let token_balance_keys: Vec<Pubkey> = if self.token_balance_recording {
+            sanitized_tx
+                .message()
+                .account_keys()
+                .iter()
+                .copied()
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let pre_token_balances = self.token_balances(&token_balance_keys);
         let CheckAndProcessTransactionSuccess {
             core:
                 CheckAndProcessTransactionSuccessCore {
@@ -1187,15 +2765,32 @@ impl LiteSVM {
                     compute_units_consumed,
                     context,
                 },
+            compute_unit_limit,
+            compute_unit_price,
+            compute_units_remaining,
+            execution_trace,
             fee,
             payer_key,
-        } = match self.check_and_process_transaction(sanitized_tx, log_collector) {
+        } = match self.check_and_process_transaction(sanitized_tx, log_collector, None) {
             Ok(value) => value,
             Err(value) => return value,
         };
         if let Some(ctx) = context {
-            let mut exec_result =
-                execution_result_if_context(sanitized_tx, ctx, result, compute_units_consumed, fee);
+            let mut exec_result = execution_result_if_context(
+                sanitized_tx,
+                ctx,
+                result,
+                compute_units_consumed,
+                compute_unit_limit,
+                fee,
+            );
+            exec_result.pre_balances = pre_balances;
+            exec_result.token_balance_keys = token_balance_keys;
+            exec_result.pre_token_balances = pre_token_balances;
+            exec_result.compute_unit_price = compute_unit_price;
+            exec_result.compute_units_remaining = compute_units_remaining;
+            exec_result.execution_trace = execution_trace;
+            self.record_program_invocations(sanitized_tx, &exec_result);
 
             if let Some(payer) = payer_key.filter(|_| exec_result.tx_result.is_err()) {
                 exec_result.tx_result = self
@@ -1208,6 +2803,7 @@ impl LiteSVM {
             ExecutionResult {
                 tx_result: result,
                 compute_units_consumed,
+                compute_unit_limit,
                 fee,
                 ..Default::default()
             }
@@ -1218,6 +2814,7 @@ impl LiteSVM {
         &self,
         sanitized_tx: &SanitizedTransaction,
         log_collector: Rc<RefCell<LogCollector>>,
+        instruction_return_data: Option<&mut InstructionReturnData>,
     ) -> ExecutionResult {
         let CheckAndProcessTransactionSuccess {
             core:
@@ -1226,18 +2823,37 @@ impl LiteSVM {
                     compute_units_consumed,
                     context,
                 },
+            compute_unit_limit,
+            compute_units_remaining,
+            execution_trace,
             fee,
             ..
-        } = match self.check_and_process_transaction(sanitized_tx, log_collector) {
+        } = match self.check_and_process_transaction(
+            sanitized_tx,
+            log_collector,
+            instruction_return_data,
+        ) {
             Ok(value) => value,
             Err(value) => return value,
         };
         if let Some(ctx) = context {
-            execution_result_if_context(sanitized_tx, ctx, result, compute_units_consumed, fee)
+            ExecutionResult {
+                compute_units_remaining,
+                execution_trace,
+                ..execution_result_if_context(
+                    sanitized_tx,
+                    ctx,
+                    result,
+                    compute_units_consumed,
+                    compute_unit_limit,
+                    fee,
+                )
+            }
         } else {
             ExecutionResult {
                 tx_result: result,
                 compute_units_consumed,
+                compute_unit_limit,
                 fee,
                 ..Default::default()
             }
@@ -1248,15 +2864,30 @@ impl LiteSVM {
         &'a self,
         sanitized_tx: &'b SanitizedTransaction,
         log_collector: Rc<RefCell<LogCollector>>,
+        instruction_return_data: Option<&mut InstructionReturnData>,
     ) -> Result<CheckAndProcessTransactionSuccess<'b>, ExecutionResult>
     where
         'a: 'b,
     {
         self.maybe_blockhash_check(sanitized_tx)?;
         let compute_budget_limits = get_compute_budget_limits(sanitized_tx, &self.feature_set)?;
+        let compute_unit_limit = self.compute_budget.map_or(
+            u64::from(compute_budget_limits.compute_unit_limit),
+            |budget| budget.compute_unit_limit,
+        );
         self.maybe_history_check(sanitized_tx)?;
-        let (result, compute_units_consumed, context, fee, payer_key) =
-            self.process_transaction(sanitized_tx, compute_budget_limits, log_collector);
+        let compute_unit_price = compute_budget_limits.compute_unit_price;
+        let mut compute_units_remaining = Vec::new();
+        let mut execution_trace = Vec::new();
+        let (result, compute_units_consumed, context, fee, payer_key) = self.process_transaction(
+            sanitized_tx,
+            compute_budget_limits,
+            log_collector,
+            instruction_return_data,
+            self.capture_compute_units_remaining
+                .then_some(&mut compute_units_remaining),
+            self.execution_trace.then_some(&mut execution_trace),
+        );
         Ok(CheckAndProcessTransactionSuccess {
             core: {
                 CheckAndProcessTransactionSuccessCore {
@@ -1265,6 +2896,10 @@ impl LiteSVM {
                     context,
                 }
             },
+            compute_unit_limit,
+            compute_unit_price,
+            compute_units_remaining,
+            execution_trace,
             fee,
             payer_key,
         })
@@ -1274,7 +2909,10 @@ impl LiteSVM {
         &self,
         sanitized_tx: &SanitizedTransaction,
     ) -> Result<(), ExecutionResult> {
-        if self.sigverify && self.history.check_transaction(sanitized_tx.signature()) {
+        if self.transaction_dedup
+            && self.sigverify
+            && self.history.check_transaction(sanitized_tx.signature())
+        {
             return Err(ExecutionResult {
                 tx_result: Err(TransactionError::AlreadyProcessed),
                 ..Default::default()
@@ -1297,9 +2935,14 @@ impl LiteSVM {
         &self,
         tx: VersionedTransaction,
         log_collector: Rc<RefCell<LogCollector>>,
+        instruction_return_data: Option<&mut InstructionReturnData>,
     ) -> ExecutionResult {
         map_sanitize_result(self.sanitize_transaction(tx), |s_tx| {
-            self.execute_sanitized_transaction_readonly(&s_tx, log_collector)
+            self.execute_sanitized_transaction_readonly(
+                &s_tx,
+                log_collector,
+                instruction_return_data,
+            )
         })
     }
 
@@ -1307,9 +2950,14 @@ impl LiteSVM {
         &self,
         tx: VersionedTransaction,
         log_collector: Rc<RefCell<LogCollector>>,
+        instruction_return_data: Option<&mut InstructionReturnData>,
     ) -> ExecutionResult {
         map_sanitize_result(self.sanitize_transaction_no_verify(tx), |s_tx| {
-            self.execute_sanitized_transaction_readonly(&s_tx, log_collector)
+            self.execute_sanitized_transaction_readonly(
+                &s_tx,
+                log_collector,
+                instruction_return_data,
+            )
         })
     }
 
@@ -1321,83 +2969,399 @@ impl LiteSVM {
         };
         let log_collector = Rc::new(RefCell::new(log_collector));
         let vtx: VersionedTransaction = tx.into();
+        let start = Instant::now();
+        if let Some(Err(err)) = self.transaction_filter.as_ref().map(|filter| filter(&vtx)) {
+            // counted as processed, but nothing was executed
+            self.record_metrics(start, 0, None);
+            return TransactionResult::Err(FailedTransactionMetadata {
+                err,
+                meta: TransactionMetadata {
+                    signature: vtx.signatures.first().copied().unwrap_or_default(),
+                    ..Default::default()
+                },
+            });
+        }
+        if let Some(hook) = &self.pre_transaction_hook {
+            let mut hook = hook.lock().unwrap();
+            hook(&mut AccountsView {
+                accounts: &mut self.accounts,
+            });
+        }
+        let instruction_count = vtx.message.instructions().len() as u64;
         let ExecutionResult {
-            post_accounts,
+            mut post_accounts,
             tx_result,
             signature,
             compute_units_consumed,
-            inner_instructions,
+            compute_unit_limit,
+            mut inner_instructions,
             return_data,
             included,
             fee,
+            pre_balances,
+            token_balance_keys,
+            pre_token_balances,
+            compute_unit_price,
+            compute_units_remaining,
+            mut execution_trace,
         } = if self.sigverify {
             self.execute_transaction(vtx, log_collector.clone())
         } else {
             self.execute_transaction_no_verify(vtx, log_collector.clone())
         };
-        let Ok(logs) = Rc::try_unwrap(log_collector).map(|lc| lc.into_inner().messages) else {
+        self.record_metrics(
+            start,
+            compute_units_consumed,
+            included.then_some((instruction_count, &inner_instructions)),
+        );
+        let Ok(LogCollector {
+            messages: logs,
+            limit_warning: logs_truncated,
+            ..
+        }) = Rc::try_unwrap(log_collector).map(RefCell::into_inner)
+        else {
             unreachable!("Log collector should not be used after send_transaction returns")
         };
-        let meta = TransactionMetadata {
+        let inner_instructions_truncated = self
+            .max_trace_entries
+            .is_some_and(|max| truncate_inner_instructions(&mut inner_instructions, max));
+        if included && compute_unit_price > 0 {
+            self.record_prioritization_fee(compute_unit_price);
+        }
+        format_logs::attach_logs(&mut execution_trace, &logs);
+        let mut meta = TransactionMetadata {
+            execution_trace,
+            compute_units_remaining,
             logs,
+            logs_truncated,
             inner_instructions,
+            inner_instructions_truncated,
             compute_units_consumed,
+            compute_unit_limit,
             return_data,
             signature,
             fee,
+            ..Default::default()
         };
 
         if let Err(tx_err) = tx_result {
+            self.record_balances(&mut meta, pre_balances);
+            meta.post_token_balances = self.token_balances(&token_balance_keys);
+            meta.pre_token_balances = pre_token_balances;
             let err = TransactionResult::Err(FailedTransactionMetadata { err: tx_err, meta });
             if included {
                 self.history.add_new_transaction(signature, err.clone());
             }
             err
         } else {
-            self.history
-                .add_new_transaction(signature, Ok(meta.clone()));
+            if let Some(collector) = self.rent_collector() {
+                for (_, account) in post_accounts.iter_mut() {
+                    collector.collect(account);
+                }
+            }
             self.accounts
-                .sync_accounts(post_accounts)
+                .sync_accounts(post_accounts, self.purge_zero_lamport_accounts)
                 .expect("It shouldn't be possible to write invalid sysvars in send_transaction.");
+            self.record_balances(&mut meta, pre_balances);
+            meta.post_token_balances = self.token_balances(&token_balance_keys);
+            meta.pre_token_balances = pre_token_balances;
+            self.history
+                .add_new_transaction(signature, Ok(meta.clone()));
 
             TransactionResult::Ok(meta)
         }
     }
 
+    /// Sends a durable-nonce transaction and makes sure the nonce moves on
+    /// afterwards, so the same nonce value can't be used twice.
+    ///
+    /// The transaction's recent blockhash must be the value stored in
+    /// `nonce_account`, and the latest blockhash must have changed since it was
+    /// stored. Otherwise the transaction fails with
+    /// [`TransactionError::BlockhashNotFound`] without being executed.
+    ///
+    /// The transaction normally starts with an `advance_nonce_account`
+    /// instruction, which advances the nonce itself. If it doesn't, which only
+    /// passes with the blockhash check disabled, the nonce is advanced here once
+    /// the transaction succeeds.
+    pub fn advance_nonce_and_send(
+        &mut self,
+        nonce_account: &Pubkey,
+        tx: Transaction,
+    ) -> TransactionResult {
+        let next_durable_nonce = DurableNonce::from_blockhash(&self.latest_blockhash);
+        let recent_blockhash = tx.message.recent_blockhash;
+        let nonce_data = self
+            .accounts
+            .get_account_ref(nonce_account)
+            .and_then(|account| {
+                solana_nonce_account::verify_nonce_account(account, &recent_blockhash)
+            })
+            .filter(|_| &recent_blockhash != next_durable_nonce.as_hash());
+        let Some(nonce_data) = nonce_data else {
+            log::error!("Blockhash {recent_blockhash} is not the nonce stored in {nonce_account}");
+            return TransactionResult::Err(FailedTransactionMetadata {
+                err: TransactionError::BlockhashNotFound,
+                meta: TransactionMetadata {
+                    signature: tx.signatures.first().copied().unwrap_or_default(),
+                    ..Default::default()
+                },
+            });
+        };
+        let result = self.send_transaction(tx);
+        if result.is_ok() {
+            if let Some(mut account) = self.accounts.get_account(nonce_account).filter(|account| {
+                solana_nonce_account::verify_nonce_account(account, &recent_blockhash).is_some()
+            }) {
+                let data = NonceData::new(
+                    nonce_data.authority,
+                    next_durable_nonce,
+                    self.fee_structure.lamports_per_signature,
+                );
+                account
+                    .set_state(&NonceVersions::new(NonceState::Initialized(data)))
+                    .unwrap();
+                self.accounts.add_account_no_checks(*nonce_account, account);
+            }
+        }
+        result
+    }
+
+    fn record_prioritization_fee(&mut self, compute_unit_price: u64) {
+        let slot = self.get_sysvar::<Clock>().slot;
+        while self
+            .prioritization_fees
+            .front()
+            .is_some_and(|(recorded, _)| !in_prioritization_fee_window(*recorded, slot))
+        {
+            self.prioritization_fees.pop_front();
+        }
+        self.prioritization_fees
+            .push_back((slot, compute_unit_price));
+    }
+
+    /// Returns `(slot, compute unit price)` pairs for the recently processed
+    /// transactions that set a compute unit price, oldest first, like the
+    /// `getRecentPrioritizationFees` RPC method. The price is in micro-lamports
+    /// per compute unit.
+    ///
+    /// Only transactions from the last 150 slots, counting the current one,
+    /// are returned.
+    pub fn recent_prioritization_fees(&self) -> Vec<(u64, u64)> {
+        let slot = self.get_sysvar::<Clock>().slot;
+        self.prioritization_fees
+            .iter()
+            .filter(|(recorded, _)| in_prioritization_fee_window(*recorded, slot))
+            .copied()
+            .collect()
+    }
+
+    fn record_balances(&self, meta: &mut TransactionMetadata, pre_balances: Vec<(Pubkey, u64)>) {
+        meta.post_balances = pre_balances
+            .iter()
+            .map(|(key, _)| self.get_balance(key).unwrap_or_default())
+            .collect();
+        meta.pre_balances = pre_balances
+            .into_iter()
+            .map(|(_, lamports)| lamports)
+            .collect();
+    }
+
+    /// The balances of the initialized token accounts among `keys`.
+    fn token_balances(&self, keys: &[Pubkey]) -> Vec<TokenBalance> {
+        // Token-2022 tags accounts with extensions with an account type byte
+        // right after the base layout.
+        const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+        keys.iter()
+            .enumerate()
+            .filter_map(|(index, key)| {
+                let account = self.accounts.get_account_ref(key)?;
+                let data = account.data();
+                let program_id = *account.owner();
+                let is_token_account = if program_id == spl_token_interface::ID {
+                    data.len() == TokenAccount::LEN
+                } else if program_id == SPL_TOKEN_2022_ID {
+                    data.len() == TokenAccount::LEN
+                        || data.get(TokenAccount::LEN) == Some(&ACCOUNT_TYPE_ACCOUNT)
+                } else {
+                    false
+                };
+                if !is_token_account {
+                    return None;
+                }
+                let token_account = TokenAccount::unpack(&data[..TokenAccount::LEN]).ok()?;
+                let decimals = self.get_mint_info(&token_account.mint)?.decimals;
+                Some(TokenBalance {
+                    account_index: index as u8,
+                    mint: token_account.mint,
+                    owner: token_account.owner,
+                    program_id,
+                    amount: token_account.amount,
+                    decimals,
+                    ui_amount: token_account.amount as f64 / 10f64.powi(i32::from(decimals)),
+                })
+            })
+            .collect()
+    }
+
+    fn record_program_invocations(&mut self, tx: &SanitizedTransaction, result: &ExecutionResult) {
+        let message = tx.message();
+        let executed = match result.tx_result {
+            Err(TransactionError::InstructionError(index, _)) => usize::from(index) + 1,
+            _ => message.instructions().len(),
+        };
+        let account_keys = message.account_keys();
+        let top_level = message
+            .instructions()
+            .iter()
+            .take(executed)
+            .map(|ix| ix.program_id_index);
+        let inner = result
+            .inner_instructions
+            .iter()
+            .flatten()
+            .map(|inner| inner.instruction.program_id_index);
+        for program_id_index in top_level.chain(inner) {
+            if let Some(program_id) = account_keys.get(usize::from(program_id_index)) {
+                *self.program_invocations.entry(*program_id).or_default() += 1;
+            }
+        }
+    }
+
+    fn record_metrics(
+        &mut self,
+        start: Instant,
+        compute_units_consumed: u64,
+        executed: Option<(u64, &InnerInstructionsList)>,
+    ) {
+        let metrics = &mut self.metrics;
+        metrics.execution_time += start.elapsed();
+        metrics.transactions += 1;
+        metrics.compute_units_consumed += compute_units_consumed;
+        if let Some((instruction_count, inner_instructions)) = executed {
+            let cpi_count: usize = inner_instructions.iter().map(Vec::len).sum();
+            metrics.instructions += instruction_count;
+            metrics.program_invocations += instruction_count + cpi_count as u64;
+        }
+    }
+
+    /// Installs a filter that is consulted before every transaction sent with
+    /// [`send_transaction`](LiteSVM::send_transaction).
+    ///
+    /// If the filter returns an error, the transaction is not executed and
+    /// that error is returned instead. It still counts towards
+    /// [`transaction_count`](LiteSVM::transaction_count) and the
+    /// [`metrics`](LiteSVM::metrics).
+    pub fn set_transaction_filter(&mut self, filter: Box<TransactionFilter>) {
+        self.transaction_filter = Some(Arc::from(filter));
+    }
+
+    /// Removes the filter installed with [`set_transaction_filter`](LiteSVM::set_transaction_filter).
+    pub fn clear_transaction_filter(&mut self) {
+        self.transaction_filter = None;
+    }
+
+    /// Installs a hook that is run before every transaction sent with
+    /// [`send_transaction`](LiteSVM::send_transaction), right before its
+    /// accounts are loaded, so tests can perturb the state it will see.
+    ///
+    /// The hook runs after the [transaction filter](LiteSVM::set_transaction_filter)
+    /// admits the transaction. The hook may keep its own mutable state; it is
+    /// shared with clones of this instance and runs for transactions sent
+    /// through any of them.
+    pub fn set_pre_transaction_hook(&mut self, hook: Box<PreTransactionHook>) {
+        self.pre_transaction_hook = Some(Arc::new(Mutex::new(hook)));
+    }
+
+    /// Removes the hook installed with [`set_pre_transaction_hook`](LiteSVM::set_pre_transaction_hook).
+    pub fn clear_pre_transaction_hook(&mut self) {
+        self.pre_transaction_hook = None;
+    }
+
     /// Simulates a transaction.
     pub fn simulate_transaction(
         &self,
         tx: impl Into<VersionedTransaction>,
     ) -> Result<SimulatedTransactionInfo, FailedTransactionMetadata> {
+        self.simulate_transaction_inner(tx.into(), false)
+    }
+
+    /// Simulates a transaction after replacing its recent blockhash with the
+    /// latest blockhash, like the RPC `replaceRecentBlockhash` option, so a
+    /// stale or even invalid blockhash doesn't fail the simulation.
+    ///
+    /// Replacing the blockhash invalidates the signatures, so they are not verified.
+    pub fn simulate_transaction_replace_blockhash(
+        &self,
+        tx: impl Into<VersionedTransaction>,
+    ) -> Result<SimulatedTransactionInfo, FailedTransactionMetadata> {
+        self.simulate_transaction_inner(tx.into(), true)
+    }
+
+    fn simulate_transaction_inner(
+        &self,
+        mut tx: VersionedTransaction,
+        replace_recent_blockhash: bool,
+    ) -> Result<SimulatedTransactionInfo, FailedTransactionMetadata> {
+        if replace_recent_blockhash {
+            tx.message.set_recent_blockhash(self.latest_blockhash);
+        }
         let log_collector = LogCollector {
             bytes_limit: self.log_bytes_limit,
             ..Default::default()
         };
         let log_collector = Rc::new(RefCell::new(log_collector));
+        let mut instruction_return_data = self.capture_instruction_return_data.then(Vec::new);
         let ExecutionResult {
             post_accounts,
             tx_result,
             signature,
             compute_units_consumed,
-            inner_instructions,
+            compute_unit_limit,
+            mut inner_instructions,
             return_data,
             fee,
+            compute_units_remaining,
+            mut execution_trace,
             ..
-        } = if self.sigverify {
-            self.execute_transaction_readonly(tx.into(), log_collector.clone())
+        } = if self.sigverify && !replace_recent_blockhash {
+            self.execute_transaction_readonly(
+                tx,
+                log_collector.clone(),
+                instruction_return_data.as_mut(),
+            )
         } else {
-            self.execute_transaction_no_verify_readonly(tx.into(), log_collector.clone())
+            self.execute_transaction_no_verify_readonly(
+                tx,
+                log_collector.clone(),
+                instruction_return_data.as_mut(),
+            )
         };
-        let Ok(logs) = Rc::try_unwrap(log_collector).map(|lc| lc.into_inner().messages) else {
+        let Ok(LogCollector {
+            messages: logs,
+            limit_warning: logs_truncated,
+            ..
+        }) = Rc::try_unwrap(log_collector).map(RefCell::into_inner)
+        else {
             unreachable!("Log collector should not be used after simulate_transaction returns")
         };
+        let inner_instructions_truncated = self
+            .max_trace_entries
+            .is_some_and(|max| truncate_inner_instructions(&mut inner_instructions, max));
+        format_logs::attach_logs(&mut execution_trace, &logs);
         let meta = TransactionMetadata {
+            execution_trace,
+            compute_units_remaining,
             signature,
             logs,
+            logs_truncated,
             inner_instructions,
+            inner_instructions_truncated,
             compute_units_consumed,
+            compute_unit_limit,
             return_data,
             fee,
+            ..Default::default()
         };
 
         if let Err(tx_err) = tx_result {
@@ -1406,13 +3370,44 @@ impl LiteSVM {
             Ok(SimulatedTransactionInfo {
                 meta,
                 post_accounts,
+                instruction_return_data,
             })
         }
     }
 
-    /// Expires the current blockhash.
+    /// Simulates each transaction independently against the current state.
+    ///
+    /// Like [`simulate_transaction`](LiteSVM::simulate_transaction), nothing is
+    /// written back, so no simulation sees the effects of another.
+    pub fn simulate_transactions(
+        &self,
+        txs: &[VersionedTransaction],
+    ) -> Vec<Result<SimulatedTransactionInfo, FailedTransactionMetadata>> {
+        txs.iter()
+            .map(|tx| self.simulate_transaction(tx.clone()))
+            .collect()
+    }
+
+    /// Expires the current blockhash, and with it every earlier one, so only
+    /// the new latest blockhash is accepted.
+    ///
+    /// Use [`finalize_block`](LiteSVM::finalize_block) instead to keep older
+    /// blockhashes valid for the [expiry window](LiteSVM::with_blockhash_expiry_slots).
     pub fn expire_blockhash(&mut self) {
-        self.latest_blockhash = create_blockhash(&self.latest_blockhash.to_bytes());
+        self.rotate_blockhash();
+        self.recent_blockhashes.clear();
+    }
+
+    /// Replaces the latest blockhash, keeping the previous one in the expiry window.
+    fn rotate_blockhash(&mut self) {
+        self.recent_blockhashes.push_front(self.latest_blockhash);
+        self.recent_blockhashes
+            .truncate(self.blockhash_expiry_slots.saturating_sub(1) as usize);
+        self.replace_latest_blockhash(create_blockhash(&self.latest_blockhash.to_bytes()));
+    }
+
+    fn replace_latest_blockhash(&mut self, hash: Hash) {
+        self.latest_blockhash = hash;
         #[allow(deprecated)]
         self.set_sysvar(&RecentBlockhashes::from_iter([IterItem(
             0,
@@ -1421,11 +3416,195 @@ impl LiteSVM {
         )]));
     }
 
+    /// Ends the current block and starts a new one: the blockhash is rotated,
+    /// which updates `RecentBlockhashes`, and the block height goes up by one.
+    ///
+    /// With `advance_slot`, the new block also gets the next slot, so the clock
+    /// and `SlotHashes` move forward as with [`warp_to_slot`](LiteSVM::warp_to_slot).
+    /// Otherwise the slot is left alone, as when consecutive calls model the
+    /// blocks of slots that are reached by warping.
+    pub fn finalize_block(&mut self, advance_slot: bool) {
+        if advance_slot {
+            let slot = self.get_sysvar::<Clock>().slot;
+            self.warp_to_slot(slot.saturating_add(1));
+        }
+        self.rotate_blockhash();
+        self.block_height = self.block_height.saturating_add(1);
+    }
+
+    /// Returns the number of blocks produced with
+    /// [`finalize_block`](LiteSVM::finalize_block). Unlike the slot, it does
+    /// not move when the clock is warped.
+    pub fn block_height(&self) -> u64 {
+        self.block_height
+    }
+
+    /// Sets the block height, e.g. to match a cluster where slots were skipped.
+    /// Later calls to [`finalize_block`](LiteSVM::finalize_block) count up from it.
+    pub fn set_block_height(&mut self, height: u64) {
+        self.block_height = height;
+    }
+
     /// Warps the clock to the specified slot.
+    ///
+    /// The clock's `unix_timestamp` moves by the number of slots warped times
+    /// the configured slot duration. To pin the timestamp instead, set the
+    /// [`Clock`] sysvar explicitly after warping. The clock's `epoch` and
+    /// `leader_schedule_epoch` follow the new slot under the current
+    /// [`EpochSchedule`].
+    ///
+    /// Each skipped slot, up to the `SlotHashes` capacity, gets a
+    /// `SlotHashes` entry whose hash is the SHA-256 of the slot number
+    /// in little-endian bytes, mixed with the seed if one was set with
+    /// [`with_rng_seed`](LiteSVM::with_rng_seed).
+    ///
+    /// The blockhash queue is left alone, so blockhashes stay valid however
+    /// far the clock moves. Use
+    /// [`warp_to_slot_with_blockhash_expiry`](LiteSVM::warp_to_slot_with_blockhash_expiry)
+    /// to age them as well.
     pub fn warp_to_slot(&mut self, slot: u64) {
+        self.warp_to_slot_with_blockhash_expiry(slot, false);
+    }
+
+    /// Like [`warp_to_slot`](LiteSVM::warp_to_slot), but when
+    /// `expire_blockhashes` is set, also rotates the blockhash once for every
+    /// slot skipped, as if each had produced a block. Blockhashes older than
+    /// the expiry window are then rejected, as they would be after a real
+    /// wait. Warping backwards never expires blockhashes.
+    pub fn warp_to_slot_with_blockhash_expiry(&mut self, slot: u64, expire_blockhashes: bool) {
+        let current_slot = self.get_sysvar::<Clock>().slot;
+        let elapsed_ms = (i128::from(slot) - i128::from(current_slot))
+            .saturating_mul(i128::from(self.slot_duration_ms));
+        let elapsed_seconds =
+            (elapsed_ms / 1000).clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64;
+        self.sub_slot_ms = 0;
+        self.warp(slot, elapsed_seconds, expire_blockhashes);
+    }
+
+    /// Moves the clock to `slot` and its timestamp by `elapsed_seconds`,
+    /// updating the sysvars that follow the slot.
+    fn warp(&mut self, slot: u64, elapsed_seconds: i64, expire_blockhashes: bool) {
         let mut clock = self.get_sysvar::<Clock>();
+        clock.unix_timestamp = clock.unix_timestamp.saturating_add(elapsed_seconds);
+        let old_slot = clock.slot;
         clock.slot = slot;
+        self.update_clock_epochs(&mut clock);
         self.set_sysvar(&clock);
+        self.update_slot_hashes_after_warp(old_slot, slot);
+        self.collect_rent_after_warp();
+        if expire_blockhashes {
+            // once the window has been passed, every older blockhash is gone anyway
+            let expirations = slot
+                .saturating_sub(old_slot)
+                .min(self.blockhash_expiry_slots);
+            for _ in 0..expirations {
+                self.rotate_blockhash();
+            }
+        }
+    }
+
+    fn rent_collector(&self) -> Option<RentCollector> {
+        self.rent_collection.then(|| {
+            let sysvar_cache = &self.accounts.sysvar_cache;
+            RentCollector::new(
+                sysvar_cache.get_rent().unwrap_or_default().as_ref().clone(),
+                sysvar_cache
+                    .get_epoch_schedule()
+                    .unwrap_or_default()
+                    .as_ref()
+                    .clone(),
+                sysvar_cache.get_clock().unwrap_or_default().slot,
+                self.slot_duration_ms,
+            )
+        })
+    }
+
+    /// Gives every slot skipped by a warp a `SlotHashes` entry derived from the
+    /// slot number, so programs reading the sysvar see the same hashes on every run.
+    fn update_slot_hashes_after_warp(&mut self, old_slot: u64, new_slot: u64) {
+        let retained: Vec<_> = self
+            .get_sysvar::<SlotHashes>()
+            .iter()
+            .filter(|(slot, _)| *slot < new_slot)
+            .copied()
+            .collect();
+        let mut slot_hashes = SlotHashes::new(&retained);
+        let first_slot = old_slot.max(new_slot.saturating_sub(slot_hashes::MAX_ENTRIES as u64));
+        for slot in first_slot..new_slot {
+            if slot_hashes.get(&slot).is_none() {
+                slot_hashes.add(slot, self.slot_hash_for_warp(slot));
+            }
+        }
+        self.set_sysvar(&slot_hashes);
+    }
+
+    /// Derives the clock's `epoch` and `leader_schedule_epoch` from its slot
+    /// and the current [`EpochSchedule`], as the runtime does at each slot.
+    fn update_clock_epochs(&self, clock: &mut Clock) {
+        let epoch_schedule = self.get_sysvar::<EpochSchedule>();
+        clock.epoch = epoch_schedule.get_epoch(clock.slot);
+        clock.leader_schedule_epoch = epoch_schedule.get_leader_schedule_epoch(clock.slot);
+    }
+
+    fn slot_hash_for_warp(&self, slot: u64) -> Hash {
+        match self.rng_seed {
+            Some(seed) => create_blockhash(&[slot.to_le_bytes(), seed.to_le_bytes()].concat()),
+            None => create_blockhash(&slot.to_le_bytes()),
+        }
+    }
+
+    fn collect_rent_after_warp(&mut self) {
+        let Some(collector) = self.rent_collector() else {
+            return;
+        };
+        let charged: Vec<_> = self
+            .accounts
+            .inner
+            .iter()
+            .filter_map(|(pubkey, account)| {
+                let mut charged = account.clone();
+                collector.collect(&mut charged);
+                (charged != *account).then_some((*pubkey, charged))
+            })
+            .collect();
+        for (pubkey, account) in charged {
+            // programs and sysvars are never charged, so there is nothing to reload
+            self.accounts.add_account(pubkey, account).unwrap();
+        }
+    }
+
+    /// Restores the [`Clock`] sysvar to the starting clock, undoing any
+    /// warps or `set_sysvar::<Clock>` calls made since.
+    ///
+    /// The starting clock is [`Clock::default`] unless it was changed with
+    /// [`with_initial_clock`](LiteSVM::with_initial_clock). Accounts and the
+    /// other sysvars, such as `SlotHashes`, are left as they are.
+    pub fn reset_clock(&mut self) {
+        self.sub_slot_ms = 0;
+        self.set_sysvar(&self.initial_clock.clone());
+    }
+
+    /// Moves the clock forward by the given number of seconds.
+    ///
+    /// The slot is advanced by however many slots fit in that time,
+    /// according to the configured slot duration. Time that doesn't fill a
+    /// whole slot is carried over to the next call, so ten 1-second steps
+    /// move as many slots as a single 10-second one.
+    pub fn warp_forward_seconds(&mut self, seconds: i64) {
+        let current_slot = self.get_sysvar::<Clock>().slot;
+        let elapsed_ms = i128::from(seconds) * 1000 + i128::from(self.sub_slot_ms);
+        let slot_duration_ms = i128::from(self.slot_duration_ms);
+        let (elapsed_slots, sub_slot_ms) = if slot_duration_ms == 0 {
+            (0, 0)
+        } else {
+            (
+                elapsed_ms.div_euclid(slot_duration_ms),
+                elapsed_ms.rem_euclid(slot_duration_ms),
+            )
+        };
+        let slot = (i128::from(current_slot) + elapsed_slots).clamp(0, i128::from(u64::MAX));
+        self.sub_slot_ms = sub_slot_ms as u64;
+        self.warp(slot as u64, seconds, false);
     }
 
     /// Gets the current compute budget.
@@ -1433,6 +3612,33 @@ impl LiteSVM {
         self.compute_budget
     }
 
+    /// Returns the compute budget in effect: the one set with
+    /// [`with_compute_budget`](LiteSVM::with_compute_budget), or else the
+    /// defaults for the current feature set.
+    ///
+    /// Without an override, the `compute_unit_limit` reported here is the
+    /// per-transaction maximum; a transaction without ComputeBudget
+    /// instructions is limited to 200k units per instruction up to that maximum.
+    pub fn current_compute_budget(&self) -> ComputeBudget {
+        let mut compute_budget = self.compute_budget.unwrap_or_else(|| {
+            ComputeBudget::new_with_defaults(
+                self.feature_set
+                    .is_active(&raise_cpi_nesting_limit_to_8::ID),
+                self.feature_set
+                    .is_active(&increase_cpi_account_info_limit::ID),
+            )
+        });
+        self.apply_max_cpi_depth(&mut compute_budget);
+        compute_budget
+    }
+
+    fn apply_max_cpi_depth(&self, compute_budget: &mut ComputeBudget) {
+        // the instruction stack also holds the top-level instruction
+        if let Some(depth) = self.max_cpi_depth {
+            compute_budget.max_instruction_stack_depth = depth.saturating_add(1);
+        }
+    }
+
     pub fn get_sigverify(&self) -> bool {
         self.sigverify
     }
@@ -1456,6 +3662,7 @@ impl LiteSVM {
     ) -> solana_transaction_error::TransactionResult<()> {
         let recent_blockhash = tx.message().recent_blockhash();
         if recent_blockhash == &self.latest_blockhash
+            || self.is_recent_blockhash_young_enough(recent_blockhash)
             || self.check_transaction_for_nonce(
                 tx,
                 &DurableNonce::from_blockhash(&self.latest_blockhash),
@@ -1472,6 +3679,17 @@ impl LiteSVM {
         }
     }
 
+    fn is_recent_blockhash_young_enough(&self, blockhash: &Hash) -> bool {
+        self.recent_blockhashes
+            .iter()
+            .position(|recent| recent == blockhash)
+            .is_some_and(|index| {
+                // the blockhash at `index` is `index + 1` blocks old
+                self.max_blockhash_age
+                    .is_none_or(|max_age| (index as u64) < max_age)
+            })
+    }
+
     fn check_message_for_nonce(&self, message: &SanitizedMessage) -> bool {
         message
             .get_durable_nonce()
@@ -1515,15 +3733,30 @@ struct CheckAndProcessTransactionSuccessCore<'ix_data> {
 
 struct CheckAndProcessTransactionSuccess<'ix_data> {
     core: CheckAndProcessTransactionSuccessCore<'ix_data>,
+    compute_unit_limit: u64,
+    compute_unit_price: u64,
+    compute_units_remaining: Vec<u64>,
+    execution_trace: Vec<ExecutionTrace>,
     fee: u64,
     payer_key: Option<Pubkey>,
 }
 
+fn in_prioritization_fee_window(recorded_slot: u64, slot: u64) -> bool {
+    slot.saturating_sub(recorded_slot) < PRIORITIZATION_FEE_SLOTS
+}
+
+fn sysvar_account<T: Sysvar + SysvarSerialize>(sysvar: &T) -> AccountSharedData {
+    let mut account = AccountSharedData::new(1, T::size_of(), &solana_sdk_ids::sysvar::id());
+    account.serialize_data(sysvar).unwrap();
+    account
+}
+
 fn execution_result_if_context(
     sanitized_tx: &SanitizedTransaction,
     ctx: TransactionContext,
     result: Result<(), TransactionError>,
     compute_units_consumed: u64,
+    compute_unit_limit: u64,
     fee: u64,
 ) -> ExecutionResult {
     let (signature, return_data, inner_instructions, post_accounts) =
@@ -1534,9 +3767,16 @@ fn execution_result_if_context(
         post_accounts,
         inner_instructions,
         compute_units_consumed,
+        compute_unit_limit,
         return_data,
         included: true,
         fee,
+        pre_balances: Vec::new(),
+        token_balance_keys: Vec::new(),
+        pre_token_balances: Vec::new(),
+        compute_unit_price: 0,
+        compute_units_remaining: Vec::new(),
+        execution_trace: Vec::new(),
     }
 }
 
@@ -1606,7 +3846,7 @@ fn validate_fee_payer(
         SystemAccountKind::Nonce => {
             // Should we ever allow a fees charge to zero a nonce account's
             // balance. The state MUST be set to uninitialized in that case
-            rent.minimum_balance(solana_nonce::state::State::size())
+            rent.minimum_balance(NonceState::size())
         }
     };
 
@@ -1702,4 +3942,44 @@ mod tests {
 
         assert!(!sanitized.message().is_writable(1));
     }
+
+    #[test]
+    fn unloadable_program_is_reported_at_invocation() {
+        let mut svm = LiteSVM::new();
+        let payer = Keypair::new();
+        svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let program_id = Pubkey::new_unique();
+        // store the program without loading it, as set_account would reject it
+        svm.accounts.add_account_no_checks(
+            program_id,
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data: b"\x7fELF not really a program".to_vec(),
+                owner: bpf_loader::id(),
+                executable: true,
+                rent_epoch: 0,
+            }
+            .into(),
+        );
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(
+                &[Instruction::new_with_bytes(program_id, &[], vec![])],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+
+        let failed = svm.send_transaction(tx).unwrap_err();
+        assert_eq!(
+            failed.err,
+            TransactionError::InstructionError(0, InstructionError::UnsupportedProgramId)
+        );
+        assert_eq!(
+            failed.meta.logs,
+            vec![format!(
+                "Program {program_id} failed to load: invalid account data for instruction"
+            )]
+        );
+    }
 }
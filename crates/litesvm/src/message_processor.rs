@@ -1,8 +1,13 @@
 // copied from agave commit 63b13a1f6ad263fb62e1f80156eaf09838f1aff0
-// with some execute_timings usage removed
+// with some execute_timings usage removed and per-instruction return data,
+// remaining compute unit and execution trace capture added
 use {
-    solana_program_runtime::invoke_context::InvokeContext, solana_svm_timings::ExecuteTimings,
-    solana_svm_transaction::svm_message::SVMMessage, solana_transaction_context::IndexOfAccount,
+    crate::types::{ExecutionTrace, InstructionReturnData},
+    solana_program_runtime::{invoke_context::InvokeContext, solana_sbpf::vm::ContextObject},
+    solana_pubkey::Pubkey,
+    solana_svm_timings::ExecuteTimings,
+    solana_svm_transaction::svm_message::SVMMessage,
+    solana_transaction_context::{IndexOfAccount, TransactionContext},
     solana_transaction_error::TransactionError,
 };
 
@@ -11,12 +16,16 @@ use {
 /// For each instruction it calls the program entrypoint method and verifies that the result of
 /// the call does not violate the bank's accounting rules.
 /// The accounts are committed back to the bank only if every instruction succeeds.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn process_message<'ix_data>(
     message: &'ix_data impl SVMMessage,
     program_indices: &[IndexOfAccount],
     invoke_context: &mut InvokeContext<'_, 'ix_data>,
     execute_timings: &mut ExecuteTimings,
     accumulated_consumed_units: &mut u64,
+    mut instruction_return_data: Option<&mut InstructionReturnData>,
+    mut compute_units_remaining: Option<&mut Vec<u64>>,
+    mut execution_trace: Option<&mut Vec<ExecutionTrace>>,
 ) -> Result<(), TransactionError> {
     debug_assert_eq!(program_indices.len(), message.num_instructions());
     for (top_level_instruction_index, ((program_id, instruction), program_account_index)) in message
@@ -35,8 +44,12 @@ pub(crate) fn process_message<'ix_data>(
                 TransactionError::InstructionError(top_level_instruction_index as u8, err)
             })?;
 
+        let trace_start = invoke_context
+            .transaction_context
+            .get_instruction_trace_length();
         let mut compute_units_consumed = 0;
-        let result = if invoke_context.is_precompile(program_id) {
+        let is_precompile = invoke_context.is_precompile(program_id);
+        let result = if is_precompile {
             invoke_context.process_precompile(
                 program_id,
                 instruction.data,
@@ -49,9 +62,81 @@ pub(crate) fn process_message<'ix_data>(
         *accumulated_consumed_units =
             accumulated_consumed_units.saturating_add(compute_units_consumed);
 
+        if let Some(execution_trace) = execution_trace.as_deref_mut() {
+            let (_, data) = invoke_context.transaction_context.get_return_data();
+            execution_trace.push(ExecutionTrace {
+                compute_units_consumed,
+                return_data: (!is_precompile && !data.is_empty()).then(|| data.to_vec()),
+                ..instruction_tree(
+                    invoke_context.transaction_context,
+                    trace_start,
+                    program_id,
+                    top_level_instruction_index,
+                )
+            });
+        }
+
         result.map_err(|err| {
             TransactionError::InstructionError(top_level_instruction_index as u8, err)
         })?;
+
+        // Precompiles don't reset the return data, so anything there is stale.
+        if let Some(instruction_return_data) = instruction_return_data.as_deref_mut() {
+            let (program_id, data) = invoke_context.transaction_context.get_return_data();
+            instruction_return_data
+                .push((!is_precompile && !data.is_empty()).then(|| (*program_id, data.to_vec())));
+        }
+        if let Some(compute_units_remaining) = compute_units_remaining.as_deref_mut() {
+            compute_units_remaining.push(invoke_context.get_remaining());
+        }
     }
     Ok(())
 }
+
+/// Builds the call graph of the top-level instruction whose entries start at
+/// `trace_start` in the instruction trace, from the stack height of each entry.
+fn instruction_tree(
+    transaction_context: &TransactionContext,
+    trace_start: usize,
+    program_id: &Pubkey,
+    instruction_index: usize,
+) -> ExecutionTrace {
+    fn finish(stack: &mut Vec<ExecutionTrace>) {
+        let node = stack.pop().unwrap();
+        stack.last_mut().unwrap().inner_instructions.push(node);
+    }
+
+    let mut stack = vec![ExecutionTrace {
+        program_id: *program_id,
+        instruction_index,
+        ..Default::default()
+    }];
+    for index_in_trace in trace_start..transaction_context.get_instruction_trace_length() {
+        let Ok(instruction_context) =
+            transaction_context.get_instruction_context_at_index_in_trace(index_in_trace)
+        else {
+            continue;
+        };
+        let stack_height = instruction_context.get_stack_height();
+        // the top-level instruction itself is the root
+        if stack_height <= 1 {
+            continue;
+        }
+        while stack.len() >= stack_height {
+            finish(&mut stack);
+        }
+        let parent = stack.last().unwrap();
+        stack.push(ExecutionTrace {
+            program_id: instruction_context
+                .get_program_key()
+                .copied()
+                .unwrap_or_default(),
+            instruction_index: parent.inner_instructions.len(),
+            ..Default::default()
+        });
+    }
+    while stack.len() > 1 {
+        finish(&mut stack);
+    }
+    stack.pop().unwrap()
+}
@@ -1,7 +1,8 @@
 use {
-    crate::LiteSVM,
+    crate::{types::ProgramKind, LiteSVM},
     agave_precompiles::get_precompiles,
     solana_account::{AccountSharedData, WritableAccount},
+    solana_pubkey::Pubkey,
     solana_sdk_ids::native_loader,
 };
 
@@ -21,3 +22,16 @@ pub(crate) fn load_precompiles(svm: &mut LiteSVM) {
         }
     }
 }
+
+pub(crate) fn installed_precompiles(svm: &LiteSVM) -> Vec<Pubkey> {
+    get_precompiles()
+        .iter()
+        .filter(|precompile| {
+            precompile
+                .feature
+                .is_none_or(|feature_id| svm.feature_set.is_active(&feature_id))
+                && svm.program_kind(&precompile.program_id) == Some(ProgramKind::Precompile)
+        })
+        .map(|precompile| precompile.program_id)
+        .collect()
+}
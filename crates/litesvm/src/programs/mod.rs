@@ -1,17 +1,19 @@
 use {
     crate::LiteSVM,
-    solana_pubkey::pubkey,
+    solana_pubkey::{pubkey, Pubkey},
     solana_sdk_ids::{address_lookup_table, config},
 };
 
+pub(crate) const SPL_TOKEN_2022_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
 pub fn load_default_programs(svm: &mut LiteSVM) {
     svm.add_program(
-        pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        spl_token_interface::ID,
         include_bytes!("elf/spl_token-3.5.0.so"),
     )
     .unwrap();
     svm.add_program(
-        pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"),
+        SPL_TOKEN_2022_ID,
         include_bytes!("elf/spl_token_2022-10.0.0.so"),
     )
     .unwrap();
@@ -25,13 +27,8 @@ pub fn load_default_programs(svm: &mut LiteSVM) {
         include_bytes!("elf/spl_memo-3.0.0.so"),
     )
     .unwrap();
-    svm.add_program(
-        pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
-        include_bytes!("elf/spl_associated_token_account-1.1.1.so"),
-    )
-    .unwrap();
-    svm.add_program(config::ID, include_bytes!("elf/config.so"))
-        .unwrap();
+    load_spl_associated_token_account_program(svm);
+    load_config_program(svm);
     svm.add_program(
         address_lookup_table::ID,
         include_bytes!("elf/address_lookup_table.so"),
@@ -43,3 +40,16 @@ pub fn load_default_programs(svm: &mut LiteSVM) {
     )
     .unwrap()
 }
+
+pub fn load_config_program(svm: &mut LiteSVM) {
+    svm.add_program(config::ID, include_bytes!("elf/config.so"))
+        .unwrap();
+}
+
+pub fn load_spl_associated_token_account_program(svm: &mut LiteSVM) {
+    svm.add_program(
+        pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+        include_bytes!("elf/spl_associated_token_account-1.1.1.so"),
+    )
+    .unwrap();
+}
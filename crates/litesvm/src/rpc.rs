@@ -0,0 +1,91 @@
+//! A minimal JSON-RPC client for fetching accounts from a cluster.
+//!
+//! Both `http://` and `https://` endpoints are supported. Requests use a
+//! blocking client, so they must not be made from inside an async runtime.
+
+use {
+    crate::error::LiteSVMError,
+    base64::{prelude::BASE64_STANDARD, Engine},
+    reqwest::blocking::Client,
+    serde_json::{json, Value},
+    solana_account::Account,
+    solana_pubkey::Pubkey,
+    std::{error::Error, str::FromStr, time::Duration},
+};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn rpc_error(reason: impl Into<String>) -> LiteSVMError {
+    LiteSVMError::Rpc(reason.into())
+}
+
+/// Fetches the account stored at `pubkey` with `getAccountInfo`.
+/// Returns `None` if the cluster has no account there.
+pub(crate) fn get_account(url: &str, pubkey: &Pubkey) -> Result<Option<Account>, LiteSVMError> {
+    let value = request(
+        url,
+        "getAccountInfo",
+        json!([pubkey.to_string(), { "encoding": "base64" }]),
+    )?;
+    match &value["value"] {
+        Value::Null => Ok(None),
+        account => parse_account(account).map(Some),
+    }
+}
+
+fn parse_account(value: &Value) -> Result<Account, LiteSVMError> {
+    let invalid = || rpc_error(format!("invalid account in response: {value}"));
+    let data = value["data"][0].as_str().ok_or_else(invalid)?;
+    Ok(Account {
+        lamports: value["lamports"].as_u64().ok_or_else(invalid)?,
+        data: BASE64_STANDARD.decode(data).map_err(|_| invalid())?,
+        owner: value["owner"]
+            .as_str()
+            .and_then(|owner| Pubkey::from_str(owner).ok())
+            .ok_or_else(invalid)?,
+        executable: value["executable"].as_bool().ok_or_else(invalid)?,
+        rent_epoch: value["rentEpoch"].as_u64().ok_or_else(invalid)?,
+    })
+}
+
+/// Sends a JSON-RPC request and returns its `result`.
+fn request(url: &str, method: &str, params: Value) -> Result<Value, LiteSVMError> {
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return Err(rpc_error(format!(
+            "unsupported URL {url}, expected http:// or https://"
+        )));
+    }
+    let failed = |e: reqwest::Error| {
+        // reqwest's own message leaves out the cause, e.g. a refused connection
+        let mut reason = format!("{method} request to {url} failed: {e}");
+        let mut source = e.source();
+        while let Some(cause) = source {
+            reason.push_str(&format!(": {cause}"));
+            source = cause.source();
+        }
+        rpc_error(reason)
+    };
+
+    let client = Client::builder()
+        .connect_timeout(CONNECT_TIMEOUT)
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(failed)?;
+    let response = client
+        .post(url)
+        .json(&json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }))
+        .send()
+        .map_err(failed)?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(rpc_error(format!(
+            "{method} request to {url} failed: HTTP {status}"
+        )));
+    }
+    let mut response: Value = response.json().map_err(failed)?;
+    if let Some(error) = response.get("error") {
+        return Err(rpc_error(format!("{method} failed: {error}")));
+    }
+    Ok(response["result"].take())
+}
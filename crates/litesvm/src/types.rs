@@ -1,6 +1,12 @@
 use {
-    crate::format_logs::format_logs,
-    solana_account::AccountSharedData,
+    crate::{
+        accounts_db::AccountsDb,
+        error::LiteSVMError,
+        format_logs::{format_logs, logs_for_program},
+    },
+    solana_account::{Account, AccountSharedData},
+    solana_compute_budget::compute_budget::SVMTransactionExecutionCost,
+    solana_hash::Hash,
     solana_instruction::error::InstructionError,
     solana_message::inner_instruction::InnerInstructionsList,
     solana_program_error::ProgramError,
@@ -8,6 +14,7 @@ use {
     solana_signature::Signature,
     solana_transaction_context::TransactionReturnData,
     solana_transaction_error::{TransactionError, TransactionResult as Result},
+    std::time::Duration,
 };
 
 #[derive(Debug, Default, Clone, PartialEq)]
@@ -16,23 +23,108 @@ pub struct TransactionMetadata {
     #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde_with_str"))]
     pub signature: Signature,
     pub logs: Vec<String>,
+    /// Set when the logs hit [`LiteSVM::with_log_bytes_limit`](crate::LiteSVM::with_log_bytes_limit),
+    /// so later messages were dropped and the last one is `"Log truncated"`.
+    pub logs_truncated: bool,
     pub inner_instructions: InnerInstructionsList,
+    /// Set when older inner instructions were dropped to respect
+    /// [`LiteSVM::with_max_trace_entries`](crate::LiteSVM::with_max_trace_entries).
+    pub inner_instructions_truncated: bool,
     pub compute_units_consumed: u64,
+    /// The compute unit limit the transaction ran with, or 0 if it never started executing.
+    pub compute_unit_limit: u64,
     pub return_data: TransactionReturnData,
     pub fee: u64,
+    /// Lamports of each account before the transaction, in account key order.
+    /// Only recorded with [`LiteSVM::with_balance_recording`](crate::LiteSVM::with_balance_recording).
+    pub pre_balances: Vec<u64>,
+    /// Lamports of each account after the transaction, in account key order.
+    /// Only recorded with [`LiteSVM::with_balance_recording`](crate::LiteSVM::with_balance_recording).
+    pub post_balances: Vec<u64>,
+    /// SPL Token and Token-2022 account balances before the transaction.
+    /// Only recorded with [`LiteSVM::with_token_balance_recording`](crate::LiteSVM::with_token_balance_recording).
+    pub pre_token_balances: Vec<TokenBalance>,
+    /// SPL Token and Token-2022 account balances after the transaction.
+    /// Only recorded with [`LiteSVM::with_token_balance_recording`](crate::LiteSVM::with_token_balance_recording).
+    pub post_token_balances: Vec<TokenBalance>,
+    /// The call graph of the transaction, one tree per top-level instruction that ran.
+    /// Only recorded with [`LiteSVM::with_execution_trace`](crate::LiteSVM::with_execution_trace).
+    pub execution_trace: Vec<ExecutionTrace>,
+    /// Compute units left after each top-level instruction that completed.
+    /// Only recorded with [`LiteSVM::with_capture_compute_units_remaining`](crate::LiteSVM::with_capture_compute_units_remaining).
+    pub compute_units_remaining: Vec<u64>,
+}
+
+/// An instruction in the call graph of a transaction, with the instructions
+/// it invoked through CPI.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecutionTrace {
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde_with_str"))]
+    pub program_id: Pubkey,
+    /// The index among the top-level instructions, or among the instructions
+    /// invoked by the parent.
+    pub instruction_index: usize,
+    pub inner_instructions: Vec<ExecutionTrace>,
+    /// The lines this instruction logged itself, without the runtime's
+    /// invoke and completion lines or the lines of the instructions it invoked.
+    pub logs: Vec<String>,
+    /// Compute units consumed, including by the instructions it invoked.
+    /// Inner instructions of builtin programs do not report their usage and
+    /// are left at 0.
+    pub compute_units_consumed: u64,
+    /// The return data set while this instruction was running, if any.
+    pub return_data: Option<Vec<u8>>,
 }
 
 impl TransactionMetadata {
     pub fn pretty_logs(&self) -> String {
         format_logs(&self.logs)
     }
+
+    /// The lines `program_id` logged itself, e.g. with `msg!`, including when it
+    /// was reached through CPI. Lines logged by its callers or callees and the
+    /// runtime's invoke and completion lines are left out.
+    pub fn logs_for_program(&self, program_id: &Pubkey) -> Vec<String> {
+        logs_for_program(&self.logs, program_id)
+    }
+
+    /// Checks that some log line contains `needle`, returning an error that
+    /// lists every log line when none does.
+    pub fn assert_logs_contain(&self, needle: &str) -> std::result::Result<(), String> {
+        if self.logs.iter().any(|log| log.contains(needle)) {
+            return Ok(());
+        }
+        Err(format!(
+            "no log line contains {needle:?}, logs:\n{}",
+            self.logs.join("\n")
+        ))
+    }
+
+    /// Whether the transaction used up its whole compute unit limit,
+    /// which is what happens when a program runs out of compute.
+    pub fn hit_compute_limit(&self) -> bool {
+        self.compute_unit_limit > 0 && self.compute_units_consumed >= self.compute_unit_limit
+    }
 }
 
+/// The compute unit cost of each syscall, see
+/// [`LiteSVM::with_syscall_costs`](crate::LiteSVM::with_syscall_costs).
+pub type SyscallCosts = SVMTransactionExecutionCost;
+
+/// The program id and return data left by each top-level instruction of a
+/// transaction, or `None` for instructions that set no return data.
+pub type InstructionReturnData = Vec<Option<(Pubkey, Vec<u8>)>>;
+
 #[derive(Debug, Default, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SimulatedTransactionInfo {
     pub meta: TransactionMetadata,
     pub post_accounts: Vec<(Pubkey, AccountSharedData)>,
+    /// The return data left by each top-level instruction, by instruction index.
+    /// Only recorded with
+    /// [`LiteSVM::with_capture_instruction_return_data`](crate::LiteSVM::with_capture_instruction_return_data).
+    pub instruction_return_data: Option<InstructionReturnData>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -56,16 +148,221 @@ impl From<ProgramError> for FailedTransactionMetadata {
 
 pub type TransactionResult = std::result::Result<TransactionMetadata, FailedTransactionMetadata>;
 
+/// Mutable access to the account store, handed to the hook installed with
+/// [`LiteSVM::set_pre_transaction_hook`](crate::LiteSVM::set_pre_transaction_hook).
+pub struct AccountsView<'a> {
+    pub(crate) accounts: &'a mut AccountsDb,
+}
+
+impl AccountsView<'_> {
+    pub fn get_account(&self, pubkey: &Pubkey) -> Option<Account> {
+        self.accounts.get_account(pubkey).map(Into::into)
+    }
+
+    /// Sets an account, with the same checks as
+    /// [`LiteSVM::set_account`](crate::LiteSVM::set_account).
+    pub fn set_account(
+        &mut self,
+        pubkey: Pubkey,
+        data: Account,
+    ) -> std::result::Result<(), LiteSVMError> {
+        self.accounts.add_account(pubkey, data.into())
+    }
+}
+
+/// The header fields of an account, without its data.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountSummary {
+    pub lamports: u64,
+    pub data_len: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde_with_str"))]
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+/// The state of an address lookup table, see
+/// [`LiteSVM::get_address_lookup_table`](crate::LiteSVM::get_address_lookup_table).
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddressLookupTableAccount {
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde_with_str"))]
+    pub key: Pubkey,
+    /// `None` once the table is frozen.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_with_str::option")
+    )]
+    pub authority: Option<Pubkey>,
+    /// `u64::MAX` while the table is active.
+    pub deactivation_slot: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde_with_str::vec"))]
+    pub addresses: Vec<Pubkey>,
+}
+
+impl From<AddressLookupTableAccount> for solana_message::AddressLookupTableAccount {
+    fn from(table: AddressLookupTableAccount) -> Self {
+        Self {
+            key: table.key,
+            addresses: table.addresses,
+        }
+    }
+}
+
+/// The base fields of an SPL Token or Token-2022 mint.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MintInfo {
+    pub decimals: u8,
+    pub supply: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_with_str::option")
+    )]
+    pub mint_authority: Option<Pubkey>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_with_str::option")
+    )]
+    pub freeze_authority: Option<Pubkey>,
+}
+
+/// The balance of a token account referenced by a transaction, as in the
+/// `preTokenBalances` and `postTokenBalances` of RPC transaction responses.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenBalance {
+    /// The index of the token account in the transaction's account keys.
+    pub account_index: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde_with_str"))]
+    pub mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde_with_str"))]
+    pub owner: Pubkey,
+    /// The token program that owns the account.
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde_with_str"))]
+    pub program_id: Pubkey,
+    /// The balance in base units.
+    pub amount: u64,
+    pub decimals: u8,
+    /// `amount` scaled down by `decimals`.
+    pub ui_amount: f64,
+}
+
+/// The accounts a transaction locks, as reported by
+/// [`LiteSVM::get_account_locks`](crate::LiteSVM::get_account_locks).
+/// Both lists are in the order the accounts appear in the message,
+/// with addresses loaded from lookup tables after the static ones.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AccountLocks {
+    pub writable: Vec<Pubkey>,
+    pub readonly: Vec<Pubkey>,
+}
+
+/// The latest blockhash and how long it stays valid, as reported by
+/// [`LiteSVM::latest_blockhash_info`](crate::LiteSVM::latest_blockhash_info).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LatestBlockhashInfo {
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde_with_str"))]
+    pub blockhash: Hash,
+    /// The last block height at which a transaction using `blockhash` is accepted.
+    pub last_valid_block_height: u64,
+}
+
+/// The parts of a transaction fee, as reported by
+/// [`LiteSVM::get_fee_breakdown_for_message`](crate::LiteSVM::get_fee_breakdown_for_message).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeBreakdown {
+    /// The signatures charged for: those of the transaction itself plus
+    /// the ones verified by precompile instructions.
+    pub signature_count: u64,
+    /// `signature_count` times the lamports per signature.
+    pub signature_fee: u64,
+    /// The fee requested with the compute unit price.
+    pub prioritization_fee: u64,
+    pub total_fee: u64,
+}
+
+/// The activation state of a stake account, as reported by
+/// [`LiteSVM::get_stake_activation`](crate::LiteSVM::get_stake_activation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StakeActivationState {
+    Activating,
+    Active,
+    Deactivating,
+    Inactive,
+}
+
+/// The activation of a stake account at the current epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeActivation {
+    pub state: StakeActivationState,
+    /// The effective stake, in lamports.
+    pub active: u64,
+    /// The stake that is not yet effective, excluding the rent-exempt reserve.
+    pub inactive: u64,
+}
+
+/// How a program is executed, as reported by [`LiteSVM::program_kind`](crate::LiteSVM::program_kind).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProgramKind {
+    /// A native program such as the system program.
+    Builtin,
+    /// An SBF program owned by the deprecated BPF loader.
+    BpfLoaderDeprecated,
+    /// An SBF program owned by BPF loader v2.
+    BpfLoaderV2,
+    /// An SBF program owned by the upgradeable BPF loader.
+    BpfLoaderUpgradeable,
+    /// An SBF program owned by loader v4.
+    LoaderV4,
+    /// A precompile such as the ed25519 program.
+    Precompile,
+}
+
+/// Cumulative execution counters, useful for tracking performance regressions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SvmMetrics {
+    /// Total compute units consumed by all transactions.
+    pub compute_units_consumed: u64,
+    /// Total number of transactions processed, successful or not.
+    pub transactions: u64,
+    /// Total number of top-level instructions executed.
+    pub instructions: u64,
+    /// Total number of program invocations, including CPIs.
+    pub program_invocations: u64,
+    /// Wall-clock time spent executing transactions.
+    pub execution_time: Duration,
+}
+
 pub(crate) struct ExecutionResult {
     pub(crate) post_accounts: Vec<(Pubkey, AccountSharedData)>,
     pub(crate) tx_result: Result<()>,
     pub(crate) signature: Signature,
     pub(crate) compute_units_consumed: u64,
+    pub(crate) compute_unit_limit: u64,
     pub(crate) inner_instructions: InnerInstructionsList,
     pub(crate) return_data: TransactionReturnData,
     /// Whether the transaction can be included in a block
     pub(crate) included: bool,
     pub(crate) fee: u64,
+    /// Account keys and their lamports before execution, when balances are recorded
+    pub(crate) pre_balances: Vec<(Pubkey, u64)>,
+    /// Account keys, when token balances are recorded
+    pub(crate) token_balance_keys: Vec<Pubkey>,
+    /// Token balances before execution, when token balances are recorded
+    pub(crate) pre_token_balances: Vec<TokenBalance>,
+    /// Micro-lamports per compute unit requested by the transaction
+    pub(crate) compute_unit_price: u64,
+    /// Compute units left after each top-level instruction, when captured
+    pub(crate) compute_units_remaining: Vec<u64>,
+    /// Call graph of each top-level instruction that ran, when captured
+    pub(crate) execution_trace: Vec<ExecutionTrace>,
 }
 
 impl Default for ExecutionResult {
@@ -75,10 +372,17 @@ impl Default for ExecutionResult {
             tx_result: Err(TransactionError::UnsupportedVersion),
             signature: Default::default(),
             compute_units_consumed: Default::default(),
+            compute_unit_limit: Default::default(),
             inner_instructions: Default::default(),
             return_data: Default::default(),
             included: false,
             fee: 0,
+            pre_balances: Vec::new(),
+            token_balance_keys: Vec::new(),
+            pre_token_balances: Vec::new(),
+            compute_unit_price: 0,
+            compute_units_remaining: Vec::new(),
+            execution_trace: Vec::new(),
         }
     }
 }
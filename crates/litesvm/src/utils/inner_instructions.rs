@@ -54,3 +54,27 @@ pub fn inner_instructions_list_from_instruction_trace(
     }
     outer_instructions
 }
+
+/// Drops the oldest inner instructions until at most `max_entries` remain.
+///
+/// The outer list keeps one entry per top-level instruction so indices stay
+/// meaningful. Returns whether anything was dropped.
+pub fn truncate_inner_instructions(
+    inner_instructions: &mut InnerInstructionsList,
+    max_entries: usize,
+) -> bool {
+    let total: usize = inner_instructions.iter().map(Vec::len).sum();
+    let mut excess = total.saturating_sub(max_entries);
+    if excess == 0 {
+        return false;
+    }
+    for instructions in inner_instructions.iter_mut() {
+        let dropped = excess.min(instructions.len());
+        instructions.drain(..dropped);
+        excess -= dropped;
+        if excess == 0 {
+            break;
+        }
+    }
+    true
+}
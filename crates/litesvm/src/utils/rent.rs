@@ -1,8 +1,11 @@
 //! this code is taken from https://github.com/anza-xyz/agave/blob/master/svm/src/rent_calculator.rs
 //! Commit 6fbbaf67837e2dc973822be9e1c20e1fed58e8eb
 use {
+    solana_account::{AccountSharedData, ReadableAccount, WritableAccount},
+    solana_clock::Epoch,
+    solana_epoch_schedule::EpochSchedule,
     solana_pubkey::Pubkey,
-    solana_rent::Rent,
+    solana_rent::{Rent, RentDue},
     solana_transaction_context::IndexOfAccount,
     solana_transaction_error::{TransactionError, TransactionResult},
 };
@@ -92,3 +95,66 @@ pub fn transition_allowed(pre_rent_state: &RentState, post_rent_state: &RentStat
         }
     }
 }
+
+/// The `rent_epoch` given to accounts that will never pay rent again.
+const RENT_EXEMPT_RENT_EPOCH: Epoch = Epoch::MAX;
+
+/// Approximate length of a year in seconds, as used by the runtime.
+const SECONDS_PER_YEAR: f64 = 365.242_199 * 24.0 * 60.0 * 60.0;
+
+/// Collects rent from accounts, as the runtime did before rent collection
+/// was disabled on mainnet.
+pub(crate) struct RentCollector {
+    pub(crate) rent: Rent,
+    pub(crate) epoch_schedule: EpochSchedule,
+    pub(crate) epoch: Epoch,
+    pub(crate) slots_per_year: f64,
+}
+
+impl RentCollector {
+    pub(crate) fn new(
+        rent: Rent,
+        epoch_schedule: EpochSchedule,
+        slot: u64,
+        slot_duration_ms: u64,
+    ) -> Self {
+        Self {
+            rent,
+            epoch: epoch_schedule.get_epoch(slot),
+            epoch_schedule,
+            slots_per_year: SECONDS_PER_YEAR * 1000.0 / slot_duration_ms.max(1) as f64,
+        }
+    }
+
+    /// Charges `account` the rent it owes for the epochs since its `rent_epoch`.
+    ///
+    /// Sysvars and executable accounts are never charged.
+    pub(crate) fn collect(&self, account: &mut AccountSharedData) {
+        if account.executable()
+            || account.lamports() == 0
+            || account.rent_epoch() >= self.epoch
+            || solana_sdk_ids::sysvar::check_id(account.owner())
+            || solana_sdk_ids::native_loader::check_id(account.owner())
+        {
+            return;
+        }
+        let slots_elapsed = self
+            .epoch_schedule
+            .get_first_slot_in_epoch(self.epoch)
+            .saturating_sub(
+                self.epoch_schedule
+                    .get_first_slot_in_epoch(account.rent_epoch()),
+            );
+        let years_elapsed = slots_elapsed as f64 / self.slots_per_year;
+        match self
+            .rent
+            .due(account.lamports(), account.data().len(), years_elapsed)
+        {
+            RentDue::Exempt => account.set_rent_epoch(RENT_EXEMPT_RENT_EPOCH),
+            RentDue::Paying(due) => {
+                account.set_lamports(account.lamports().saturating_sub(due));
+                account.set_rent_epoch(self.epoch);
+            }
+        }
+    }
+}
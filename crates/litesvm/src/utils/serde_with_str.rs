@@ -21,3 +21,68 @@ where
     s.parse()
         .map_err(|e| de::Error::custom(format!("Parse error: {e:?}")))
 }
+
+/// The same, for optional values, with `None` as `null`.
+pub mod option {
+    use {
+        serde::{de, Deserialize, Deserializer, Serialize, Serializer},
+        std::str::FromStr,
+    };
+
+    pub fn serialize<T, S>(t: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ToString,
+        S: Serializer,
+    {
+        t.as_ref().map(ToString::to_string).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        D: Deserializer<'de>,
+        <T as FromStr>::Err: std::fmt::Debug,
+    {
+        let s: Option<String> = Option::deserialize(deserializer)?;
+        s.map(|s| {
+            s.parse()
+                .map_err(|e| de::Error::custom(format!("Parse error: {e:?}")))
+        })
+        .transpose()
+    }
+}
+
+/// The same, for lists of values.
+pub mod vec {
+    use {
+        serde::{de, ser::SerializeSeq, Deserialize, Deserializer, Serializer},
+        std::str::FromStr,
+    };
+
+    pub fn serialize<T, S>(t: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ToString,
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(t.len()))?;
+        for item in t {
+            seq.serialize_element(&item.to_string())?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        T: FromStr,
+        D: Deserializer<'de>,
+        <T as FromStr>::Err: std::fmt::Debug,
+    {
+        let s: Vec<String> = Vec::deserialize(deserializer)?;
+        s.into_iter()
+            .map(|s| {
+                s.parse()
+                    .map_err(|e| de::Error::custom(format!("Parse error: {e:?}")))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,56 @@
+// This program creates an account owned by itself by invoking the system
+// program's `create_account_with_seed`, using the payer as the base.
+//
+// Instruction data: lamports (u64 LE), space (u64 LE), then the seed bytes.
+
+use {
+    solana_account_info::{next_account_info, AccountInfo},
+    solana_cpi::invoke,
+    solana_program_entrypoint::entrypoint,
+    solana_program_error::{ProgramError, ProgramResult},
+    solana_pubkey::{declare_id, Pubkey},
+    solana_system_interface::instruction::create_account_with_seed,
+};
+
+declare_id!("9YRHJmvVXvh7B3NfUiw8wA3zr64HncdzEAjnPgegRV9M");
+
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let payer = next_account_info(accounts_iter)?;
+    let new_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    if instruction_data.len() < 16 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let (lamports, rest) = instruction_data.split_at(8);
+    let (space, seed) = rest.split_at(8);
+    let lamports = u64::from_le_bytes(lamports.try_into().unwrap());
+    let space = u64::from_le_bytes(space.try_into().unwrap());
+    let seed = core::str::from_utf8(seed).map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    let expected = Pubkey::create_with_seed(payer.key, seed, program_id)
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+    if expected != *new_account.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    invoke(
+        &create_account_with_seed(
+            payer.key,
+            new_account.key,
+            payer.key,
+            seed,
+            lamports,
+            space,
+            program_id,
+        ),
+        &[payer.clone(), new_account.clone(), system_program.clone()],
+    )
+}
@@ -0,0 +1,38 @@
+// This program reads the instructions sysvar and checks that the
+// instruction right before it was sent to the program whose id is
+// passed as instruction data.
+
+use {
+    solana_account_info::AccountInfo,
+    solana_instructions_sysvar::{get_instruction_relative, load_current_index_checked},
+    solana_program_entrypoint::entrypoint,
+    solana_program_error::{ProgramError, ProgramResult},
+    solana_pubkey::{declare_id, Pubkey},
+};
+
+declare_id!("HWadFPQYp9xbDTFePHtQN7uN9E9PKarEJr3vSY7juGu");
+
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let instructions_sysvar = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let expected_program_id =
+        Pubkey::try_from(instruction_data).map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    if load_current_index_checked(instructions_sysvar)? == 0 {
+        return Err(ProgramError::Custom(0));
+    }
+    let current = get_instruction_relative(0, instructions_sysvar)?;
+    if current.program_id != *program_id {
+        return Err(ProgramError::Custom(1));
+    }
+    let previous = get_instruction_relative(-1, instructions_sysvar)?;
+    if previous.program_id != expected_program_id {
+        return Err(ProgramError::Custom(2));
+    }
+    Ok(())
+}
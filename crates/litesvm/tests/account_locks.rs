@@ -0,0 +1,77 @@
+use {
+    litesvm::LiteSVM,
+    solana_address_lookup_table_interface::{
+        instruction::{create_lookup_table, extend_lookup_table},
+        state::AddressLookupTable,
+    },
+    solana_instruction::{account_meta::AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_message::{
+        v0::Message as MessageV0, AddressLookupTableAccount, Message, VersionedMessage,
+    },
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::{versioned::VersionedTransaction, Transaction},
+};
+
+#[test_log::test]
+fn test_account_locks_with_lookup_table() {
+    let mut svm = LiteSVM::new();
+    let payer_kp = Keypair::new();
+    let payer_pk = payer_kp.pubkey();
+    svm.airdrop(&payer_pk, 1_000_000_000).unwrap();
+    let program_id = Pubkey::new_unique();
+    let [static_writable, table_writable, table_readonly] =
+        std::array::from_fn(|_| Pubkey::new_unique());
+
+    let (lookup_table_ix, lookup_table_address) = create_lookup_table(payer_pk, payer_pk, 0);
+    let extend_ix = extend_lookup_table(
+        lookup_table_address,
+        payer_pk,
+        Some(payer_pk),
+        vec![table_writable, table_readonly],
+    );
+    svm.send_transaction(Transaction::new(
+        &[&payer_kp],
+        Message::new(&[lookup_table_ix, extend_ix], Some(&payer_pk)),
+        svm.latest_blockhash(),
+    ))
+    .unwrap();
+    svm.warp_to_slot(1); // can't use the lookup table in the same slot
+
+    let table_account = svm.get_account(&lookup_table_address).unwrap();
+    let table = AddressLookupTable::deserialize(&table_account.data).unwrap();
+    let message = MessageV0::try_compile(
+        &payer_pk,
+        &[Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(static_writable, false),
+                AccountMeta::new(table_writable, false),
+                AccountMeta::new_readonly(table_readonly, false),
+                // writable in the message, but sysvars are locked readonly
+                AccountMeta::new(solana_sdk_ids::sysvar::clock::ID, false),
+            ],
+            data: vec![],
+        }],
+        &[AddressLookupTableAccount {
+            key: lookup_table_address,
+            addresses: table.addresses.to_vec(),
+        }],
+        svm.latest_blockhash(),
+    )
+    .unwrap();
+    assert_eq!(message.address_table_lookups.len(), 1);
+    let tx = VersionedTransaction::try_new(VersionedMessage::V0(message), &[&payer_kp]).unwrap();
+
+    let locks = svm.get_account_locks(tx).unwrap();
+    assert_eq!(locks.writable, [payer_pk, static_writable, table_writable]);
+    assert_eq!(locks.readonly.len(), 3);
+    for key in [
+        program_id,
+        solana_sdk_ids::sysvar::clock::ID,
+        table_readonly,
+    ] {
+        assert!(locks.readonly.contains(&key));
+    }
+}
@@ -0,0 +1,418 @@
+use {
+    litesvm::{error::LiteSVMError, LiteSVM},
+    solana_account::{Account, AccountSharedData, ReadableAccount},
+    solana_address_lookup_table_interface::state::AddressLookupTable,
+    solana_instruction::error::InstructionError,
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_native_token::LAMPORTS_PER_SOL,
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::{allocate, transfer},
+    solana_transaction::Transaction,
+    spl_token_interface::state::Mint,
+};
+
+#[test_log::test]
+fn test_set_account_data() {
+    let mut svm = LiteSVM::new();
+    let owner = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    let data: Vec<u8> = (0..64).collect();
+    svm.set_account(
+        address,
+        Account {
+            lamports: LAMPORTS_PER_SOL,
+            data: data.clone(),
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    // flip the discriminator, which lives at offset 8 in this made-up layout
+    svm.set_account_data(&address, 8, &[0xff]).unwrap();
+
+    let mut expected = data;
+    expected[8] = 0xff;
+    let account = svm.get_account(&address).unwrap();
+    assert_eq!(account.data, expected);
+    assert_eq!(account.owner, owner);
+    assert_eq!(account.lamports, LAMPORTS_PER_SOL);
+
+    // writes past the end of the data are rejected and leave the account untouched
+    assert!(svm.set_account_data(&address, 60, &[0; 5]).is_err());
+    assert_eq!(svm.get_account(&address).unwrap().data, expected);
+
+    // missing accounts are rejected
+    assert!(svm
+        .set_account_data(&Pubkey::new_unique(), 0, &[1])
+        .is_err());
+}
+
+#[test_log::test]
+fn test_set_account_lamports() {
+    let mut svm = LiteSVM::new();
+    let owner = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    let data: Vec<u8> = (0..64).collect();
+    svm.set_account(
+        address,
+        Account {
+            lamports: LAMPORTS_PER_SOL,
+            data: data.clone(),
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    // a donation on top of the existing balance
+    svm.set_account_lamports(&address, 3 * LAMPORTS_PER_SOL)
+        .unwrap();
+    let account = svm.get_account(&address).unwrap();
+    assert_eq!(account.lamports, 3 * LAMPORTS_PER_SOL);
+    assert_eq!(account.data, data);
+    assert_eq!(account.owner, owner);
+
+    // zero lamports removes the account
+    svm.set_account_lamports(&address, 0).unwrap();
+    assert_eq!(svm.get_account(&address), None);
+
+    // missing accounts are rejected
+    assert!(svm.set_account_lamports(&Pubkey::new_unique(), 1).is_err());
+}
+
+#[test_log::test]
+fn test_assert_account_data_eq() {
+    let mut svm = LiteSVM::new();
+    let address = Pubkey::new_unique();
+    let data = vec![7u8; 100];
+    svm.set_account(
+        address,
+        Account {
+            lamports: LAMPORTS_PER_SOL,
+            data: data.clone(),
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    svm.assert_account_data_eq(&address, &data).unwrap();
+
+    let mut expected = data.clone();
+    expected[42] = 0;
+    let err = svm.assert_account_data_eq(&address, &expected).unwrap_err();
+    assert!(err.contains("differs at offset 42"), "{err}");
+
+    let err = svm
+        .assert_account_data_eq(&address, &data[..90])
+        .unwrap_err();
+    assert!(err.contains("differs at offset 90"), "{err}");
+
+    assert!(svm
+        .assert_account_data_eq(&Pubkey::new_unique(), &data)
+        .is_err());
+}
+
+#[test_log::test]
+fn test_get_account_summary() {
+    let mut svm = LiteSVM::new();
+    let address = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    svm.set_account(
+        address,
+        Account {
+            lamports: LAMPORTS_PER_SOL,
+            data: vec![1; 10 * 1024 * 1024],
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    let account = svm.get_account(&address).unwrap();
+    let summary = svm.get_account_summary(&address).unwrap();
+    assert_eq!(summary.lamports, account.lamports);
+    assert_eq!(summary.data_len, account.data.len());
+    assert_eq!(summary.owner, account.owner);
+    assert_eq!(summary.executable, account.executable);
+
+    assert!(svm.get_account_summary(&Pubkey::new_unique()).is_none());
+}
+
+#[test_log::test]
+fn test_get_account_data_len() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    let account = Keypair::new();
+    svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&account.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    assert_eq!(svm.get_account_data_len(&account.pubkey()), Some(0));
+
+    svm.process_instruction(allocate(&account.pubkey(), 1_234), &[&payer, &account])
+        .unwrap();
+    assert_eq!(svm.get_account_data_len(&account.pubkey()), Some(1_234));
+    assert_eq!(
+        svm.get_account_data_len(&account.pubkey()),
+        svm.get_account(&account.pubkey()).map(|a| a.data.len())
+    );
+
+    assert_eq!(svm.get_account_data_len(&Pubkey::new_unique()), None);
+}
+
+#[test_log::test]
+fn test_is_executable() {
+    let mut svm = LiteSVM::new();
+    let program_id = Pubkey::new_unique();
+    let bytes = include_bytes!("../../node-litesvm/program_bytes/spl_example_logging.so");
+    svm.add_program(program_id, bytes).unwrap();
+    assert_eq!(svm.is_executable(&program_id), Some(true));
+
+    let data_account = Pubkey::new_unique();
+    svm.airdrop(&data_account, LAMPORTS_PER_SOL).unwrap();
+    assert_eq!(svm.is_executable(&data_account), Some(false));
+
+    assert_eq!(svm.is_executable(&Pubkey::new_unique()), None);
+}
+
+#[test_log::test]
+fn test_get_account_modified_slot() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    let untouched = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&untouched, LAMPORTS_PER_SOL).unwrap();
+    assert_eq!(svm.get_account_modified_slot(&untouched), Some(0));
+
+    svm.warp_to_slot(5);
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(
+            &[transfer(&payer.pubkey(), &recipient, LAMPORTS_PER_SOL / 2)],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+    assert_eq!(svm.get_account_modified_slot(&payer.pubkey()), Some(5));
+    assert_eq!(svm.get_account_modified_slot(&recipient), Some(5));
+    assert_eq!(svm.get_account_modified_slot(&untouched), Some(0));
+
+    svm.warp_to_slot(8);
+    svm.set_account_data(&untouched, 0, &[]).unwrap();
+    assert_eq!(svm.get_account_modified_slot(&untouched), Some(8));
+
+    assert_eq!(svm.get_account_modified_slot(&Pubkey::new_unique()), None);
+}
+
+#[test_log::test]
+fn test_create_helpers_reject_existing_accounts() {
+    let mut svm = LiteSVM::new();
+    let authority = Pubkey::new_unique();
+
+    let mint = Pubkey::new_unique();
+    svm.create_token_mint(&mint, &authority, 6).unwrap();
+    let mint_state = Mint::unpack(&svm.get_account(&mint).unwrap().data).unwrap();
+    assert_eq!(mint_state.decimals, 6);
+    assert_eq!(mint_state.mint_authority, COption::Some(authority));
+    assert!(matches!(
+        svm.create_token_mint(&mint, &authority, 6),
+        Err(LiteSVMError::AccountAlreadyExists(address)) if address == mint
+    ));
+
+    let nonce = Pubkey::new_unique();
+    svm.create_nonce_account(&nonce, &authority).unwrap();
+    let nonce_account = svm.get_account(&nonce).unwrap();
+    assert_eq!(nonce_account.owner, solana_sdk_ids::system_program::ID);
+    assert_eq!(
+        nonce_account.lamports,
+        svm.minimum_balance_for_rent_exemption(nonce_account.data.len())
+    );
+    assert!(matches!(
+        svm.create_nonce_account(&nonce, &authority),
+        Err(LiteSVMError::AccountAlreadyExists(address)) if address == nonce
+    ));
+
+    let table = Pubkey::new_unique();
+    let addresses = [Pubkey::new_unique(), Pubkey::new_unique()];
+    svm.create_address_lookup_table(&table, &authority, &addresses)
+        .unwrap();
+    let table_account = svm.get_account(&table).unwrap();
+    assert_eq!(
+        AddressLookupTable::deserialize(&table_account.data)
+            .unwrap()
+            .addresses[..],
+        addresses
+    );
+    assert!(matches!(
+        svm.create_address_lookup_table(&table, &authority, &addresses),
+        Err(LiteSVMError::AccountAlreadyExists(address)) if address == table
+    ));
+}
+
+#[test_log::test]
+fn test_purge_zero_lamport_accounts() {
+    for purge in [true, false] {
+        let mut svm = LiteSVM::new().with_purge_zero_lamport_accounts(purge);
+        let payer = Keypair::new();
+        let drained = Keypair::new();
+        svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        svm.airdrop(&drained.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let tx = Transaction::new(
+            &[&payer, &drained],
+            Message::new(
+                &[transfer(
+                    &drained.pubkey(),
+                    &payer.pubkey(),
+                    LAMPORTS_PER_SOL,
+                )],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        let account = svm.get_account(&drained.pubkey());
+        if purge {
+            assert!(account.is_none());
+        } else {
+            assert_eq!(account.unwrap().lamports, 0);
+        }
+    }
+}
+
+#[test_log::test]
+fn test_create_pda_account() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let program_id = Pubkey::new_unique();
+    let seeds: &[&[u8]] = &[b"vault", &[7]];
+
+    let pda = svm
+        .create_pda_account(&program_id, seeds, 100, &payer)
+        .unwrap();
+    assert_eq!(pda, Pubkey::find_program_address(seeds, &program_id).0);
+    let rent = svm.minimum_balance_for_rent_exemption(100);
+    assert_eq!(
+        svm.get_account(&pda).unwrap(),
+        Account {
+            lamports: rent,
+            data: vec![0; 100],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        }
+    );
+    assert_eq!(
+        svm.get_balance(&payer.pubkey()),
+        Some(LAMPORTS_PER_SOL - rent)
+    );
+
+    assert!(matches!(
+        svm.create_pda_account(&program_id, seeds, 100, &payer),
+        Err(LiteSVMError::AccountAlreadyExists(address)) if address == pda
+    ));
+
+    // the payer can't cover the rent-exempt balance
+    let balance = svm.get_balance(&payer.pubkey()).unwrap();
+    let space = 1_000_000;
+    assert!(svm.minimum_balance_for_rent_exemption(space) > balance);
+    assert!(matches!(
+        svm.create_pda_account(&program_id, &[b"too expensive"], space, &payer),
+        Err(LiteSVMError::Instruction(
+            InstructionError::InsufficientFunds
+        ))
+    ));
+    assert_eq!(svm.get_balance(&payer.pubkey()), Some(balance));
+
+    // paying would leave the payer below its own rent-exempt minimum
+    let payer_rent = svm.minimum_balance_for_rent_exemption(0);
+    svm.set_account_lamports(&payer.pubkey(), rent + payer_rent - 1)
+        .unwrap();
+    assert!(matches!(
+        svm.create_pda_account(&program_id, &[b"rent paying"], 100, &payer),
+        Err(LiteSVMError::Instruction(
+            InstructionError::InsufficientFunds
+        ))
+    ));
+    assert_eq!(
+        svm.get_balance(&payer.pubkey()),
+        Some(rent + payer_rent - 1)
+    );
+
+    // paying with the whole balance closes the payer
+    svm.set_account_lamports(&payer.pubkey(), rent).unwrap();
+    let pda = svm
+        .create_pda_account(&program_id, &[b"everything"], 100, &payer)
+        .unwrap();
+    assert_eq!(svm.get_balance(&pda), Some(rent));
+    assert!(svm.get_account(&payer.pubkey()).is_none());
+}
+
+#[test_log::test]
+fn test_set_accounts() {
+    let mut svm = LiteSVM::new();
+    let owner = Pubkey::new_unique();
+    let entries: Vec<_> = (0..100u8)
+        .map(|i| {
+            let mut account = AccountSharedData::new(LAMPORTS_PER_SOL, 8, &owner);
+            account.set_data_from_slice(&[i; 8]);
+            (Pubkey::new_unique(), account)
+        })
+        .collect();
+    svm.set_accounts(&entries).unwrap();
+    for (pubkey, expected) in &entries {
+        let account = svm.get_account(pubkey).unwrap();
+        assert_eq!(account.data(), expected.data());
+        assert_eq!(account.owner, owner);
+    }
+
+    // an invalid entry leaves every account untouched
+    let untouched = Pubkey::new_unique();
+    let bad_clock = AccountSharedData::new(LAMPORTS_PER_SOL, 1, &solana_sdk_ids::sysvar::ID);
+    let err = svm
+        .set_accounts(&[
+            (
+                untouched,
+                AccountSharedData::new(LAMPORTS_PER_SOL, 0, &owner),
+            ),
+            (solana_sdk_ids::sysvar::clock::ID, bad_clock),
+        ])
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        LiteSVMError::SetAccounts { index: 1, pubkey, .. } if pubkey == solana_sdk_ids::sysvar::clock::ID
+    ));
+    assert_eq!(svm.get_account(&untouched), None);
+}
+
+#[test_log::test]
+fn test_get_account_or_default() {
+    let mut svm = LiteSVM::new();
+    let missing = Pubkey::new_unique();
+    let account = svm.get_account_or_default(&missing);
+    assert_eq!(account, AccountSharedData::default());
+    assert_eq!(account.lamports(), 0);
+    assert_eq!(account.owner(), &solana_sdk_ids::system_program::ID);
+    assert!(account.data().is_empty());
+
+    let address = Pubkey::new_unique();
+    svm.airdrop(&address, LAMPORTS_PER_SOL).unwrap();
+    let account = svm.get_account_or_default(&address);
+    assert_eq!(account.lamports(), LAMPORTS_PER_SOL);
+    assert_eq!(
+        svm.get_account(&address),
+        Some(Account::from(account.clone()))
+    );
+}
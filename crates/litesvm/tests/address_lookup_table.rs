@@ -0,0 +1,45 @@
+use {
+    litesvm::LiteSVM,
+    solana_address_lookup_table_interface::instruction::{
+        create_lookup_table, extend_lookup_table,
+    },
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+};
+
+#[test_log::test]
+fn test_get_address_lookup_table() {
+    let mut svm = LiteSVM::new();
+    let payer_kp = Keypair::new();
+    let payer_pk = payer_kp.pubkey();
+    svm.airdrop(&payer_pk, 1_000_000_000).unwrap();
+    let addresses: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+
+    let (lookup_table_ix, lookup_table_address) = create_lookup_table(payer_pk, payer_pk, 0);
+    let extend_ix = extend_lookup_table(
+        lookup_table_address,
+        payer_pk,
+        Some(payer_pk),
+        addresses.clone(),
+    );
+    svm.send_transaction(Transaction::new(
+        &[&payer_kp],
+        Message::new(&[lookup_table_ix, extend_ix], Some(&payer_pk)),
+        svm.latest_blockhash(),
+    ))
+    .unwrap();
+
+    let table = svm.get_address_lookup_table(&lookup_table_address).unwrap();
+    assert_eq!(table.key, lookup_table_address);
+    assert_eq!(table.authority, Some(payer_pk));
+    assert_eq!(table.deactivation_slot, u64::MAX);
+    assert_eq!(table.addresses, addresses);
+
+    assert!(svm.get_address_lookup_table(&payer_pk).is_none());
+    assert!(svm
+        .get_address_lookup_table(&Pubkey::new_unique())
+        .is_none());
+}
@@ -1,6 +1,7 @@
 use {
     litesvm::LiteSVM,
     solana_account::{state_traits::StateMut, ReadableAccount},
+    solana_clock::Clock,
     solana_keypair::Keypair,
     solana_message::Message,
     solana_nonce::{
@@ -10,6 +11,7 @@ use {
     solana_pubkey::Pubkey,
     solana_rent::Rent,
     solana_signer::Signer,
+    solana_slot_hashes::SlotHashes,
     solana_system_interface::instruction::{advance_nonce_account, transfer},
     solana_transaction::Transaction,
     solana_transaction_error::TransactionError,
@@ -84,3 +86,270 @@ fn test_durable_nonce() {
     svm.expire_blockhash();
     svm.send_transaction(tx_using_nonce).unwrap();
 }
+
+#[test_log::test]
+fn test_advance_nonce_and_send() {
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let nonce_kp = Keypair::new();
+    let nonce = nonce_kp.pubkey();
+
+    let mut svm = LiteSVM::new();
+
+    svm.airdrop(&from, 1_000_000_000).unwrap();
+    let create_nonce_ixns =
+        solana_system_interface::instruction::create_nonce_account(&from, &nonce, &from, 1_500_000);
+    let tx = Transaction::new(
+        &[&from_keypair, &nonce_kp],
+        Message::new(&create_nonce_ixns, Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+    let stored = data_from_account(&svm.get_account(&nonce).unwrap()).blockhash();
+    svm.expire_blockhash();
+
+    let tx_using_nonce = |lamports: u64| {
+        let msg = Message::new_with_blockhash(
+            &[
+                advance_nonce_account(&nonce, &from),
+                transfer(&from, &Pubkey::new_unique(), lamports),
+            ],
+            Some(&from),
+            &stored,
+        );
+        Transaction::new(&[&from_keypair], msg, stored)
+    };
+    svm.advance_nonce_and_send(&nonce, tx_using_nonce(1_000_000))
+        .unwrap();
+    let advanced = data_from_account(&svm.get_account(&nonce).unwrap()).blockhash();
+    assert_ne!(advanced, stored);
+
+    svm.expire_blockhash();
+    let err = svm
+        .advance_nonce_and_send(&nonce, tx_using_nonce(2_000_000))
+        .unwrap_err();
+    assert_eq!(err.err, TransactionError::BlockhashNotFound);
+    assert_eq!(
+        data_from_account(&svm.get_account(&nonce).unwrap()).blockhash(),
+        advanced
+    );
+}
+
+#[test_log::test]
+fn test_blockhash_expiry_slots() {
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+
+    let mut svm = LiteSVM::new().with_blockhash_expiry_slots(2);
+    svm.airdrop(&from, 1_000_000_000).unwrap();
+    let blockhash = svm.latest_blockhash();
+    let transfer_tx = |lamports| {
+        Transaction::new(
+            &[&from_keypair],
+            Message::new(
+                &[transfer(&from, &Pubkey::new_unique(), lamports)],
+                Some(&from),
+            ),
+            blockhash,
+        )
+    };
+
+    svm.finalize_block(true);
+    svm.send_transaction(transfer_tx(1_000_000)).unwrap();
+
+    svm.finalize_block(true);
+    let tx_res = svm.send_transaction(transfer_tx(2_000_000));
+    assert_eq!(tx_res.unwrap_err().err, TransactionError::BlockhashNotFound);
+}
+
+#[test_log::test]
+fn test_expire_blockhash_ignores_expiry_window() {
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&from, 1_000_000_000).unwrap();
+    svm.finalize_block(true);
+    let older = svm.latest_blockhash();
+    svm.finalize_block(true);
+    let blockhash = svm.latest_blockhash();
+    svm.expire_blockhash();
+
+    for (lamports, blockhash) in [(1_000_000, blockhash), (2_000_000, older)] {
+        let tx = Transaction::new(
+            &[&from_keypair],
+            Message::new(
+                &[transfer(&from, &Pubkey::new_unique(), lamports)],
+                Some(&from),
+            ),
+            blockhash,
+        );
+        let tx_res = svm.send_transaction(tx);
+        assert_eq!(tx_res.unwrap_err().err, TransactionError::BlockhashNotFound);
+    }
+}
+
+#[test_log::test]
+fn test_genesis_hash() {
+    let mut svm = LiteSVM::new();
+    assert_eq!(svm.genesis_hash(), svm.latest_blockhash());
+
+    let genesis_hash = solana_hash::Hash::new_unique();
+    svm.set_genesis_hash(genesis_hash);
+    svm.expire_blockhash();
+    assert_eq!(svm.genesis_hash(), genesis_hash);
+}
+
+#[test_log::test]
+fn test_warp_to_slot_with_blockhash_expiry() {
+    for expire_blockhashes in [false, true] {
+        let from_keypair = Keypair::new();
+        let from = from_keypair.pubkey();
+        let mut svm = LiteSVM::new().with_blockhash_expiry_slots(10);
+        svm.airdrop(&from, 1_000_000_000).unwrap();
+        let blockhash = svm.latest_blockhash();
+
+        let transfer_tx = |lamports: u64| {
+            Transaction::new(
+                &[&from_keypair],
+                Message::new(
+                    &[transfer(&from, &Pubkey::new_unique(), lamports)],
+                    Some(&from),
+                ),
+                blockhash,
+            )
+        };
+        // still inside the expiry window
+        svm.warp_to_slot_with_blockhash_expiry(9, expire_blockhashes);
+        svm.send_transaction(transfer_tx(1_000_000)).unwrap();
+
+        svm.warp_to_slot_with_blockhash_expiry(100, expire_blockhashes);
+        let result = svm.send_transaction(transfer_tx(2_000_000));
+        if expire_blockhashes {
+            assert_ne!(svm.latest_blockhash(), blockhash);
+            assert_eq!(result.unwrap_err().err, TransactionError::BlockhashNotFound);
+        } else {
+            assert_eq!(svm.latest_blockhash(), blockhash);
+            result.unwrap();
+        }
+    }
+}
+
+#[test_log::test]
+fn test_finalize_block() {
+    let mut svm = LiteSVM::new();
+    let blockhash = svm.latest_blockhash();
+    assert_eq!(svm.block_height(), 0);
+
+    svm.finalize_block(true);
+    assert_ne!(svm.latest_blockhash(), blockhash);
+    assert_eq!(svm.block_height(), 1);
+    assert_eq!(svm.get_sysvar::<Clock>().slot, 1);
+    assert!(svm.get_sysvar::<SlotHashes>().get(&0).is_some());
+
+    let blockhash = svm.latest_blockhash();
+    svm.finalize_block(false);
+    assert_ne!(svm.latest_blockhash(), blockhash);
+    assert_eq!(svm.block_height(), 2);
+    assert_eq!(svm.get_sysvar::<Clock>().slot, 1);
+}
+
+#[test_log::test]
+fn test_block_height_independent_of_slot() {
+    let mut svm = LiteSVM::new();
+    svm.warp_to_slot(1000);
+    assert_eq!(svm.block_height(), 0);
+
+    svm.finalize_block(true);
+    assert_eq!(svm.block_height(), 1);
+    assert_eq!(svm.get_sysvar::<Clock>().slot, 1001);
+
+    svm.set_block_height(500);
+    svm.warp_to_slot(2000);
+    svm.finalize_block(false);
+    assert_eq!(svm.block_height(), 501);
+    assert_eq!(svm.get_sysvar::<Clock>().slot, 2000);
+}
+
+#[test_log::test]
+fn test_latest_blockhash_info() {
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let mut svm = LiteSVM::new().with_blockhash_expiry_slots(10);
+    svm.airdrop(&from, 1_000_000_000).unwrap();
+    svm.set_block_height(100);
+
+    let info = svm.latest_blockhash_info();
+    assert_eq!(info.blockhash, svm.latest_blockhash());
+    assert_eq!(info.last_valid_block_height, 109);
+
+    let transfer_tx = |lamports: u64| {
+        Transaction::new(
+            &[&from_keypair],
+            Message::new(
+                &[transfer(&from, &Pubkey::new_unique(), lamports)],
+                Some(&from),
+            ),
+            info.blockhash,
+        )
+    };
+    // every block finalized narrows the window, and the blockhash stays usable
+    let mut lamports = 1_000_000;
+    let mut remaining = info.last_valid_block_height - svm.block_height();
+    while remaining > 0 {
+        svm.finalize_block(true);
+        assert_eq!(
+            info.last_valid_block_height - svm.block_height(),
+            remaining - 1
+        );
+        remaining -= 1;
+        svm.send_transaction(transfer_tx(lamports)).unwrap();
+        lamports += 1;
+    }
+
+    svm.finalize_block(true);
+    assert!(svm.block_height() > info.last_valid_block_height);
+    let result = svm.send_transaction(transfer_tx(lamports));
+    assert_eq!(result.unwrap_err().err, TransactionError::BlockhashNotFound);
+}
+
+#[test_log::test]
+fn test_max_blockhash_age() {
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let mut svm = LiteSVM::new()
+        .with_blockhash_expiry_slots(10)
+        .with_max_blockhash_age(3);
+    svm.airdrop(&from, 1_000_000_000).unwrap();
+    let blockhash = svm.latest_blockhash();
+
+    let transfer_tx = |lamports: u64| {
+        Transaction::new(
+            &[&from_keypair],
+            Message::new(
+                &[transfer(&from, &Pubkey::new_unique(), lamports)],
+                Some(&from),
+            ),
+            blockhash,
+        )
+    };
+    for _ in 0..3 {
+        svm.finalize_block(true);
+    }
+    svm.send_transaction(transfer_tx(1_000_000)).unwrap();
+
+    // still in the expiry window, but older than the max age
+    svm.finalize_block(true);
+    let tx_res = svm.send_transaction(transfer_tx(2_000_000));
+    assert_eq!(tx_res.unwrap_err().err, TransactionError::BlockhashNotFound);
+}
+
+#[test_log::test]
+fn test_set_latest_blockhash() {
+    let mut svm = LiteSVM::new();
+    let blockhash = solana_hash::Hash::new_unique();
+    svm.finalize_block(true);
+    svm.set_latest_blockhash(blockhash);
+    assert_eq!(svm.latest_blockhash(), blockhash);
+    assert_eq!(svm.latest_blockhash_info().blockhash, blockhash);
+}
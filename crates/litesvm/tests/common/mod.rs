@@ -0,0 +1,46 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use {
+    litesvm::LiteSVM, solana_instruction::Instruction, solana_keypair::Keypair,
+    solana_program_pack::Pack, solana_pubkey::Pubkey, solana_signer::Signer,
+    solana_transaction::Transaction, spl_token_interface::state::Mint,
+};
+
+/// Instructions creating a token mint at `mint` with `payer` as its authority
+/// and 6 decimals.
+pub fn create_mint_instructions(svm: &LiteSVM, payer: &Pubkey, mint: &Pubkey) -> [Instruction; 2] {
+    [
+        solana_system_interface::instruction::create_account(
+            payer,
+            mint,
+            svm.minimum_balance_for_rent_exemption(Mint::LEN),
+            Mint::LEN as u64,
+            &spl_token_interface::ID,
+        ),
+        spl_token_interface::instruction::initialize_mint2(
+            &spl_token_interface::ID,
+            mint,
+            payer,
+            None,
+            6,
+        )
+        .unwrap(),
+    ]
+}
+
+/// Funds `payer_kp` and creates a token mint with it as the authority.
+pub fn create_mint(svm: &mut LiteSVM, payer_kp: &Keypair) -> Pubkey {
+    let payer_pk = payer_kp.pubkey();
+    let mint_kp = Keypair::new();
+    let mint_pk = mint_kp.pubkey();
+    svm.airdrop(&payer_pk, 1_000_000_000).unwrap();
+    svm.send_transaction(Transaction::new_signed_with_payer(
+        &create_mint_instructions(svm, &payer_pk, &mint_pk),
+        Some(&payer_pk),
+        &[payer_kp, &mint_kp],
+        svm.latest_blockhash(),
+    ))
+    .unwrap();
+    mint_pk
+}
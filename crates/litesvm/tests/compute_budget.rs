@@ -1,9 +1,15 @@
 use {
-    litesvm::LiteSVM, solana_compute_budget::compute_budget::ComputeBudget,
+    litesvm::LiteSVM,
+    solana_compute_budget::compute_budget::ComputeBudget,
     solana_compute_budget_interface::ComputeBudgetInstruction,
-    solana_instruction::error::InstructionError, solana_keypair::Keypair, solana_message::Message,
-    solana_pubkey::Pubkey, solana_signer::Signer, solana_system_interface::instruction::transfer,
-    solana_transaction::Transaction, solana_transaction_error::TransactionError,
+    solana_instruction::error::InstructionError,
+    solana_keypair::Keypair,
+    solana_message::{Message, VersionedMessage},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::transfer,
+    solana_transaction::Transaction,
+    solana_transaction_error::TransactionError,
 };
 
 #[test_log::test]
@@ -126,3 +132,123 @@ fn test_priority_fee_is_charged() {
     let recipient_balance = svm.get_balance(&to).unwrap();
     assert_eq!(recipient_balance, transfer_amount);
 }
+
+#[test_log::test]
+fn test_get_fee_for_message() {
+    let from = Pubkey::new_unique();
+    let to = Pubkey::new_unique();
+    let svm = LiteSVM::new();
+
+    let message = Message::new_with_blockhash(
+        &[transfer(&from, &to, 100)],
+        Some(&from),
+        &svm.latest_blockhash(),
+    );
+    let fee = svm
+        .get_fee_for_message(VersionedMessage::Legacy(message))
+        .unwrap();
+    assert_eq!(fee, 5000);
+
+    // priority_fee = 1_000_000 * 10_000 / 1_000_000 = 10_000 lamports
+    let message = Message::new_with_blockhash(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_price(1_000_000),
+            ComputeBudgetInstruction::set_compute_unit_limit(10_000),
+            transfer(&from, &to, 100),
+        ],
+        Some(&from),
+        &svm.latest_blockhash(),
+    );
+    let fee = svm
+        .get_fee_for_message(VersionedMessage::Legacy(message))
+        .unwrap();
+    assert_eq!(fee, 15_000);
+}
+
+#[test_log::test]
+fn test_get_fee_breakdown_for_message() {
+    let payer = Keypair::new();
+    let other_signer = Keypair::new();
+    let to = Pubkey::new_unique();
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000).unwrap();
+    svm.airdrop(&other_signer.pubkey(), 1_000_000).unwrap();
+
+    // priority_fee = 1_000_000 * 10_000 / 1_000_000 = 10_000 lamports
+    let message = Message::new_with_blockhash(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_price(1_000_000),
+            ComputeBudgetInstruction::set_compute_unit_limit(10_000),
+            transfer(&payer.pubkey(), &to, 100),
+            transfer(&other_signer.pubkey(), &to, 100),
+        ],
+        Some(&payer.pubkey()),
+        &svm.latest_blockhash(),
+    );
+    let breakdown = svm
+        .get_fee_breakdown_for_message(VersionedMessage::Legacy(message.clone()))
+        .unwrap();
+    assert_eq!(breakdown.signature_count, 2);
+    assert_eq!(breakdown.signature_fee, 10_000);
+    assert_eq!(breakdown.prioritization_fee, 10_000);
+    assert_eq!(breakdown.total_fee, 20_000);
+    assert_eq!(
+        svm.get_fee_for_message(VersionedMessage::Legacy(message.clone()))
+            .unwrap(),
+        breakdown.total_fee
+    );
+
+    let tx = Transaction::new(&[&payer, &other_signer], message, svm.latest_blockhash());
+    assert_eq!(svm.send_transaction(tx).unwrap().fee, breakdown.total_fee);
+}
+
+#[test_log::test]
+fn test_hit_compute_limit() {
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Pubkey::new_unique();
+
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&from, 1_000_000).unwrap();
+
+    let send_with_limit = |svm: &mut LiteSVM, compute_unit_limit: u32| {
+        let tx = Transaction::new(
+            &[&from_keypair],
+            Message::new(
+                &[
+                    ComputeBudgetInstruction::set_compute_unit_limit(compute_unit_limit),
+                    transfer(&from, &to, 64),
+                ],
+                Some(&from),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+    };
+
+    let meta = send_with_limit(&mut svm, 10_000).unwrap();
+    assert_eq!(meta.compute_unit_limit, 10_000);
+    assert!(meta.compute_units_consumed < 10_000);
+    assert!(!meta.hit_compute_limit());
+
+    let failed = send_with_limit(&mut svm, 10).unwrap_err();
+    assert_eq!(
+        failed.err,
+        TransactionError::InstructionError(0, InstructionError::ComputationalBudgetExceeded)
+    );
+    assert!(failed.meta.hit_compute_limit());
+}
+
+#[test_log::test]
+fn test_current_compute_budget() {
+    let svm = LiteSVM::new();
+    let default_budget = svm.current_compute_budget();
+    assert_eq!(default_budget.compute_unit_limit, 1_400_000);
+    assert_eq!(default_budget.heap_size, 32 * 1024);
+
+    let mut compute_budget = ComputeBudget::new_with_defaults(false, false);
+    compute_budget.compute_unit_limit = 10_000;
+    compute_budget.heap_size = 64 * 1024;
+    let svm = svm.with_compute_budget(compute_budget);
+    assert_eq!(svm.current_compute_budget(), compute_budget);
+}
@@ -0,0 +1,59 @@
+use {
+    litesvm::LiteSVM,
+    solana_compute_budget_interface::ComputeBudgetInstruction,
+    solana_instruction::{account_meta::AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+};
+
+#[test_log::test]
+fn test_compute_units_remaining() {
+    let mut svm = LiteSVM::new()
+        .with_capture_compute_units_remaining(true)
+        .with_execution_trace(true);
+    let program_id = Pubkey::new_unique();
+    let bytes = include_bytes!("../../node-litesvm/program_bytes/spl_example_logging.so");
+    svm.add_program(program_id, bytes).unwrap();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+
+    let limit = 50_000;
+    let log_ix = Instruction {
+        program_id,
+        accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+        data: vec![5, 10, 11, 12, 13, 14],
+    };
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(limit),
+                log_ix.clone(),
+                log_ix.clone(),
+            ],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    let meta = svm.send_transaction(tx).unwrap();
+
+    let remaining = &meta.compute_units_remaining;
+    assert_eq!(remaining.len(), 3);
+    assert_eq!(remaining[2], u64::from(limit) - meta.compute_units_consumed);
+    // each logging instruction spends exactly what its log line reports
+    for index in 1..3 {
+        let cost = meta.execution_trace[index].compute_units_consumed;
+        assert!(cost > 0);
+        assert_eq!(remaining[index - 1] - remaining[index], cost);
+    }
+
+    // nothing is captured unless asked for
+    let mut svm = LiteSVM::new();
+    svm.add_program(program_id, bytes).unwrap();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let meta = svm.process_instruction(log_ix, &[&payer]).unwrap();
+    assert!(meta.compute_units_remaining.is_empty());
+}
@@ -730,3 +730,38 @@ fn test_maximum_keys_input() {
         TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
     );
 }
+
+#[test]
+fn test_config_program_without_default_programs() {
+    let payer = Keypair::new();
+    let mut svm = LiteSVM::default()
+        .with_builtins()
+        .with_lamports(1_000_000_000_000)
+        .with_sysvars()
+        .with_sigverify(true)
+        .with_config_program();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let mut context = TestContext { svm, payer };
+
+    let config_keypair = Keypair::new();
+    let my_config = MyConfig::new(7);
+    create_config_account(&mut context, &config_keypair, vec![]);
+    let instruction = store(&config_keypair.pubkey(), true, vec![], &my_config);
+    let payer = &context.payer;
+    context
+        .svm
+        .send_transaction(Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer, &config_keypair],
+            context.svm.latest_blockhash(),
+        ))
+        .unwrap();
+
+    let config_account = context.svm.get_account(&config_keypair.pubkey()).unwrap();
+    assert_eq!(config_account.owner, solana_sdk_ids::config::ID);
+    assert_eq!(
+        Some(my_config),
+        deserialize(get_config_data(config_account.data()).unwrap()).ok()
+    );
+}
@@ -387,3 +387,51 @@ fn test_register_tracing_handler() {
         );
     }
 }
+
+#[test]
+pub fn test_duplicate_readonly_and_writable_key() {
+    let mut svm = LiteSVM::new();
+    let payer_kp = Keypair::new();
+    let payer_pk = payer_kp.pubkey();
+    let program_id = pubkey!("GtdambwDgHWrDJdVPBkEHGhCwokqgAoch162teUjJse2");
+    svm.add_program(program_id, &read_counter_program())
+        .unwrap();
+    svm.airdrop(&payer_pk, 1000000000).unwrap();
+    let counter_address = pubkey!("J39wvrFY2AkoAUCke5347RMNk3ditxZfVidoZ7U6Fguf");
+    svm.set_account(
+        counter_address,
+        Account {
+            lamports: 5,
+            data: vec![0_u8; std::mem::size_of::<u32>()],
+            owner: program_id,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    // the counter is listed readonly first and writable second; the program
+    // must still see a writable account in both positions
+    let msg = Message::new_with_blockhash(
+        &[Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(counter_address, false),
+                AccountMeta::new(counter_address, false),
+            ],
+            data: vec![0],
+        }],
+        Some(&payer_pk),
+        &svm.latest_blockhash(),
+    );
+    let counter_keys = msg
+        .account_keys
+        .iter()
+        .filter(|key| **key == counter_address)
+        .count();
+    assert_eq!(counter_keys, 1);
+    let tx = Transaction::new(&[&payer_kp], msg, svm.latest_blockhash());
+    svm.send_transaction(tx).unwrap();
+    assert_eq!(
+        svm.get_account(&counter_address).unwrap().data,
+        1u32.to_le_bytes().to_vec()
+    );
+}
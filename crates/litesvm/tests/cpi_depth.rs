@@ -0,0 +1,142 @@
+use {
+    common::create_mint,
+    litesvm::LiteSVM,
+    solana_instruction::{account_meta::AccountMeta, error::InstructionError, Instruction},
+    solana_keypair::Keypair,
+    solana_program_runtime::declare_process_instruction,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction_error::TransactionError,
+    spl_associated_token_account_interface::{
+        address::get_associated_token_address, instruction::create_associated_token_account,
+    },
+};
+
+mod common;
+
+// Invokes itself as many more times as the first byte of instruction data says.
+// The program is passed as its own account, since a callee must be one of the
+// caller's accounts.
+declare_process_instruction!(Recurse, 150, |invoke_context| {
+    let instruction_context = invoke_context
+        .transaction_context
+        .get_current_instruction_context()?;
+    let program_id = *instruction_context.get_program_key()?;
+    let remaining = instruction_context.get_instruction_data()[0];
+    if remaining > 0 {
+        invoke_context.native_invoke(recurse_instruction(program_id, remaining - 1), &[])?;
+    }
+    Ok(())
+});
+
+#[test_log::test]
+fn test_max_cpi_depth() {
+    assert_eq!(
+        LiteSVM::new()
+            .current_compute_budget()
+            .max_instruction_stack_depth,
+        9
+    );
+
+    // creating an associated token account invokes the system and token
+    // programs one level deep
+    for (depth, succeeds) in [(None, true), (Some(1), true), (Some(0), false)] {
+        let mut svm = LiteSVM::new();
+        if let Some(depth) = depth {
+            svm = svm.with_max_cpi_depth(depth);
+            assert_eq!(
+                svm.current_compute_budget().max_instruction_stack_depth,
+                depth + 1
+            );
+        }
+        let payer_kp = Keypair::new();
+        let mint_pk = create_mint(&mut svm, &payer_kp);
+        let owner_pk = Pubkey::new_unique();
+
+        let result = svm.process_instruction(
+            create_associated_token_account(
+                &payer_kp.pubkey(),
+                &owner_pk,
+                &mint_pk,
+                &spl_token_interface::ID,
+            ),
+            &[&payer_kp],
+        );
+        let ata = get_associated_token_address(&owner_pk, &mint_pk);
+        if succeeds {
+            result.unwrap();
+            assert!(svm.get_account(&ata).is_some());
+        } else {
+            assert_eq!(
+                result.unwrap_err().err,
+                TransactionError::InstructionError(0, InstructionError::CallDepth)
+            );
+            assert!(svm.get_account(&ata).is_none());
+        }
+    }
+}
+
+fn recurse_instruction(program_id: Pubkey, remaining: u8) -> Instruction {
+    Instruction::new_with_bytes(
+        program_id,
+        &[remaining],
+        vec![AccountMeta::new_readonly(program_id, false)],
+    )
+}
+
+#[test_log::test]
+fn test_max_cpi_depth_recursion() {
+    let mut svm = LiteSVM::new().with_max_cpi_depth(4);
+    let program_id = Pubkey::new_unique();
+    svm.add_builtin(program_id, Recurse::vm);
+    let payer_kp = Keypair::new();
+    svm.airdrop(&payer_kp.pubkey(), 1_000_000_000).unwrap();
+
+    for (depth, succeeds) in [(4, true), (5, false)] {
+        let result = svm.process_instruction(recurse_instruction(program_id, depth), &[&payer_kp]);
+        if succeeds {
+            result.unwrap();
+        } else {
+            assert_eq!(
+                result.unwrap_err().err,
+                TransactionError::InstructionError(0, InstructionError::CallDepth)
+            );
+        }
+    }
+}
+
+#[test_log::test]
+fn test_program_invocation_count() {
+    let mut svm = LiteSVM::new().with_execution_trace(true);
+    let payer_kp = Keypair::new();
+    let mint_pk = create_mint(&mut svm, &payer_kp);
+    let ata_program = spl_associated_token_account_interface::program::ID;
+    assert_eq!(svm.program_invocation_count(&ata_program), 0);
+    // the mint was initialized with one direct call
+    assert_eq!(svm.program_invocation_count(&spl_token_interface::ID), 1);
+
+    for calls in 1..=2 {
+        let meta = svm
+            .process_instruction(
+                create_associated_token_account(
+                    &payer_kp.pubkey(),
+                    &Pubkey::new_unique(),
+                    &mint_pk,
+                    &spl_token_interface::ID,
+                ),
+                &[&payer_kp],
+            )
+            .unwrap();
+        let token_cpis = meta.execution_trace[0]
+            .inner_instructions
+            .iter()
+            .filter(|inner| inner.program_id == spl_token_interface::ID)
+            .count() as u64;
+        assert!(token_cpis > 0);
+        assert_eq!(svm.program_invocation_count(&ata_program), calls);
+        assert_eq!(
+            svm.program_invocation_count(&spl_token_interface::ID),
+            1 + calls * token_cpis
+        );
+    }
+}
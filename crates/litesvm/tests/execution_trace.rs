@@ -0,0 +1,125 @@
+use {
+    common::create_mint_instructions, litesvm::LiteSVM, solana_keypair::Keypair,
+    solana_program_pack::Pack, solana_pubkey::Pubkey, solana_signer::Signer,
+    solana_transaction::Transaction,
+    spl_associated_token_account_interface::instruction::create_associated_token_account,
+};
+
+mod common;
+
+#[test_log::test]
+fn test_execution_trace() {
+    let mut svm = LiteSVM::new().with_execution_trace(true);
+    let payer_kp = Keypair::new();
+    let payer_pk = payer_kp.pubkey();
+    let mint_kp = Keypair::new();
+    let mint_pk = mint_kp.pubkey();
+    svm.airdrop(&payer_pk, 1_000_000_000).unwrap();
+    let meta = svm
+        .send_transaction(Transaction::new_signed_with_payer(
+            &create_mint_instructions(&svm, &payer_pk, &mint_pk),
+            Some(&payer_pk),
+            &[&payer_kp, &mint_kp],
+            svm.latest_blockhash(),
+        ))
+        .unwrap();
+    let programs: Vec<_> = meta
+        .execution_trace
+        .iter()
+        .map(|trace| (trace.program_id, trace.instruction_index))
+        .collect();
+    assert_eq!(
+        programs,
+        [
+            (solana_sdk_ids::system_program::ID, 0),
+            (spl_token_interface::ID, 1)
+        ]
+    );
+
+    // creating an associated token account invokes the token and system programs
+    let meta = svm
+        .process_instruction(
+            create_associated_token_account(
+                &payer_pk,
+                &Pubkey::new_unique(),
+                &mint_pk,
+                &spl_token_interface::ID,
+            ),
+            &[&payer_kp],
+        )
+        .unwrap();
+    let [create] = meta.execution_trace.as_slice() else {
+        panic!("expected one top-level instruction");
+    };
+    assert_eq!(
+        create.program_id,
+        spl_associated_token_account_interface::program::ID
+    );
+    assert_eq!(create.compute_units_consumed, meta.compute_units_consumed);
+    assert!(create.logs.contains(&"Program log: Create".to_string()));
+    assert_eq!(
+        create.inner_instructions.len(),
+        meta.inner_instructions[0].len()
+    );
+    for (index, inner) in create.inner_instructions.iter().enumerate() {
+        assert_eq!(inner.instruction_index, index);
+        assert!(inner.inner_instructions.is_empty());
+        assert!(inner.compute_units_consumed < create.compute_units_consumed);
+    }
+    let get_size = &create.inner_instructions[0];
+    assert_eq!(get_size.program_id, spl_token_interface::ID);
+    assert!(get_size.compute_units_consumed > 0);
+    assert_eq!(
+        get_size.return_data,
+        Some(
+            (spl_token_interface::state::Account::LEN as u64)
+                .to_le_bytes()
+                .to_vec()
+        )
+    );
+
+    let meta = LiteSVM::new().airdrop(&payer_pk, 1).unwrap();
+    assert!(!meta.logs.is_empty());
+    assert!(meta.execution_trace.is_empty());
+}
+
+#[test_log::test]
+fn test_execution_trace_with_truncated_logs() {
+    let mut svm = LiteSVM::new()
+        .with_execution_trace(true)
+        .with_log_bytes_limit(Some(100));
+    let payer_kp = Keypair::new();
+    let payer_pk = payer_kp.pubkey();
+    let mint_pk = common::create_mint(&mut svm, &payer_kp);
+
+    let meta = svm
+        .process_instruction(
+            create_associated_token_account(
+                &payer_pk,
+                &Pubkey::new_unique(),
+                &mint_pk,
+                &spl_token_interface::ID,
+            ),
+            &[&payer_kp],
+        )
+        .unwrap();
+    assert!(meta.logs_truncated);
+    // the call graph comes from the runtime, so it is complete without the logs
+    let [create] = meta.execution_trace.as_slice() else {
+        panic!("expected one top-level instruction");
+    };
+    assert_eq!(create.compute_units_consumed, meta.compute_units_consumed);
+    assert_eq!(
+        create.inner_instructions.len(),
+        meta.inner_instructions[0].len()
+    );
+    for (index, inner) in create.inner_instructions.iter().enumerate() {
+        assert_eq!(inner.instruction_index, index);
+    }
+    assert_eq!(
+        create.inner_instructions[0].program_id,
+        spl_token_interface::ID
+    );
+    // nothing was logged past the limit
+    assert!(create.inner_instructions.last().unwrap().logs.is_empty());
+}
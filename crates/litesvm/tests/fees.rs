@@ -1,5 +1,6 @@
 use {
     litesvm::LiteSVM,
+    solana_compute_budget_interface::ComputeBudgetInstruction,
     solana_instruction::{error::InstructionError, Instruction},
     solana_keypair::Keypair,
     solana_message::Message,
@@ -72,3 +73,83 @@ fn test_fees_failed_transaction() {
     assert_eq!(initial_balance - balance_after, expected_fee);
     assert!(svm.get_transaction(&signature).unwrap().is_err());
 }
+
+#[test_log::test]
+fn test_fee_in_metadata() {
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Pubkey::new_unique();
+
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&from, 1_000_000_000).unwrap();
+    let balance_before = svm.get_balance(&from).unwrap();
+    let transferred = 1_000_000;
+    let instruction = transfer(&from, &to, transferred);
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[instruction], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    let meta = svm.send_transaction(tx).unwrap();
+
+    let balance_after = svm.get_balance(&from).unwrap();
+    assert_eq!(meta.fee, balance_before - balance_after - transferred);
+    assert_eq!(meta.fee, 5000);
+}
+
+#[test_log::test]
+fn test_fee_rate_governor() {
+    let governor = LiteSVM::new().fee_rate_governor();
+    assert_eq!(governor.lamports_per_signature, 5000);
+    assert_eq!(governor.burn_percent, 50);
+
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let mut svm = LiteSVM::new().with_lamports_per_signature(7000);
+    let governor = svm.fee_rate_governor();
+    assert_eq!(governor.lamports_per_signature, 7000);
+    assert_eq!(governor.target_lamports_per_signature, 7000);
+
+    svm.airdrop(&from, 1_000_000_000).unwrap();
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[transfer(&from, &Pubkey::new_unique(), 1)], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    let meta = svm.send_transaction(tx).unwrap();
+    assert_eq!(meta.fee, governor.lamports_per_signature);
+}
+
+#[test_log::test]
+fn test_recent_prioritization_fees() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let to = Pubkey::new_unique();
+
+    for (slot, price) in [(1, 0), (2, 5), (3, 10)] {
+        svm.warp_to_slot(slot);
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(
+                &[
+                    ComputeBudgetInstruction::set_compute_unit_price(price),
+                    transfer(&payer.pubkey(), &to, 1_000_000),
+                ],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+    }
+
+    assert_eq!(svm.recent_prioritization_fees(), [(2, 5), (3, 10)]);
+
+    // fees are kept for 150 slots, counting the one they were recorded in
+    svm.warp_to_slot(151);
+    assert_eq!(svm.recent_prioritization_fees(), [(2, 5), (3, 10)]);
+    svm.warp_to_slot(152);
+    assert_eq!(svm.recent_prioritization_fees(), [(3, 10)]);
+    svm.warp_to_slot(153);
+    assert!(svm.recent_prioritization_fees().is_empty());
+}
@@ -0,0 +1,144 @@
+use {
+    base64::{prelude::BASE64_STANDARD, Engine},
+    litesvm::{error::LiteSVMError, LiteSVM},
+    serde_json::{json, Value},
+    solana_account::Account,
+    solana_instruction::{account_meta::AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+    std::{
+        collections::HashMap,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    },
+};
+
+/// Serves `getAccountInfo` for `accounts` on a local port and returns its URL.
+fn mock_rpc(accounts: HashMap<String, Account>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            let request: Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(request["method"], "getAccountInfo");
+            let value = match accounts.get(request["params"][0].as_str().unwrap()) {
+                Some(account) => json!({
+                    "lamports": account.lamports,
+                    "data": [BASE64_STANDARD.encode(&account.data), "base64"],
+                    "owner": account.owner.to_string(),
+                    "executable": account.executable,
+                    "rentEpoch": account.rent_epoch,
+                }),
+                None => Value::Null,
+            };
+            let body = json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "result": { "context": { "slot": 1 }, "value": value },
+            })
+            .to_string();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+    url
+}
+
+#[test_log::test]
+fn test_clone_upgradeable_program_from_rpc() {
+    let mut cluster = LiteSVM::new();
+    let program_id = Pubkey::new_unique();
+    let programdata_address = Pubkey::new_unique();
+    let bytes = include_bytes!("../../node-litesvm/program_bytes/spl_example_logging.so");
+    cluster
+        .deploy_upgradeable_program(program_id, programdata_address, bytes, None)
+        .unwrap();
+    let program = cluster.get_account(&program_id).unwrap();
+    let programdata = cluster.get_account(&programdata_address).unwrap();
+    let url = mock_rpc(HashMap::from([
+        (program_id.to_string(), program.clone()),
+        (programdata_address.to_string(), programdata.clone()),
+    ]));
+
+    let mut svm = LiteSVM::new();
+    let missing = Pubkey::new_unique();
+    assert!(matches!(
+        svm.clone_upgradeable_program_from_rpc(&missing, &url),
+        Err(LiteSVMError::Rpc(_))
+    ));
+    svm.clone_upgradeable_program_from_rpc(&program_id, &url)
+        .unwrap();
+    assert_eq!(svm.get_account(&program_id).unwrap(), program);
+    assert_eq!(svm.get_account(&programdata_address).unwrap(), programdata);
+
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let ix = Instruction {
+        program_id,
+        accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+        data: vec![5, 10, 11, 12, 13, 14],
+    };
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(&[ix], Some(&payer.pubkey())),
+        svm.latest_blockhash(),
+    );
+    let meta = svm.send_transaction(tx).unwrap();
+    assert_eq!(meta.logs[1], "Program log: static string");
+}
+
+#[test_log::test]
+fn test_clone_upgradeable_program_from_rpc_errors() {
+    let mut svm = LiteSVM::new();
+    let program_id = Pubkey::new_unique();
+
+    let Err(LiteSVMError::Rpc(reason)) =
+        svm.clone_upgradeable_program_from_rpc(&program_id, "ws://127.0.0.1:8900")
+    else {
+        panic!("expected an RPC error");
+    };
+    assert_eq!(
+        reason,
+        "unsupported URL ws://127.0.0.1:8900, expected http:// or https://"
+    );
+
+    // bind and drop a listener to get a port nothing is listening on
+    let url = format!(
+        "http://{}",
+        TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+    );
+    let Err(LiteSVMError::Rpc(reason)) = svm.clone_upgradeable_program_from_rpc(&program_id, &url)
+    else {
+        panic!("expected an RPC error");
+    };
+    assert!(
+        reason.starts_with(&format!("getAccountInfo request to {url} failed: ")),
+        "{reason}"
+    );
+}
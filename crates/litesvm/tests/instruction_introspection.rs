@@ -0,0 +1,83 @@
+use {
+    litesvm::LiteSVM,
+    solana_instruction::{error::InstructionError, AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_pubkey::{pubkey, Pubkey},
+    solana_sdk_ids::{system_program, sysvar},
+    solana_signer::Signer,
+    solana_system_interface::instruction::transfer,
+    solana_transaction::Transaction,
+    solana_transaction_error::TransactionError,
+    std::path::PathBuf,
+};
+
+const PROGRAM_ID: Pubkey = pubkey!("HWadFPQYp9xbDTFePHtQN7uN9E9PKarEJr3vSY7juGu");
+
+fn setup() -> (LiteSVM, Keypair) {
+    let mut svm = LiteSVM::new();
+    let mut so_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    so_path.push("test_programs/target/deploy/instruction_introspection.so");
+    svm.add_program_from_file(PROGRAM_ID, &so_path).unwrap();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    (svm, payer)
+}
+
+fn introspection_ix(expected_previous: Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+        data: expected_previous.to_bytes().to_vec(),
+    }
+}
+
+#[test_log::test]
+fn test_instruction_introspection() {
+    let (mut svm, payer) = setup();
+    let ixs = [
+        transfer(&payer.pubkey(), &Pubkey::new_unique(), 1_000_000),
+        introspection_ix(system_program::ID),
+    ];
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(&ixs, Some(&payer.pubkey())),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+}
+
+#[test_log::test]
+fn test_instruction_introspection_wrong_sibling() {
+    let (mut svm, payer) = setup();
+    let ixs = [
+        transfer(&payer.pubkey(), &Pubkey::new_unique(), 1_000_000),
+        introspection_ix(Pubkey::new_unique()),
+    ];
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(&ixs, Some(&payer.pubkey())),
+        svm.latest_blockhash(),
+    );
+    assert_eq!(
+        svm.send_transaction(tx).unwrap_err().err,
+        TransactionError::InstructionError(1, InstructionError::Custom(2))
+    );
+}
+
+#[test_log::test]
+fn test_instruction_introspection_no_sibling() {
+    let (mut svm, payer) = setup();
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(
+            &[introspection_ix(system_program::ID)],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    assert_eq!(
+        svm.send_transaction(tx).unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(0))
+    );
+}
@@ -0,0 +1,46 @@
+use {
+    litesvm::LiteSVM,
+    solana_instruction::{account_meta::AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+};
+
+#[test_log::test]
+fn test_logs_truncated() {
+    let limit = 200;
+    let program_id = Pubkey::new_unique();
+    let bytes = include_bytes!("../../node-litesvm/program_bytes/spl_example_logging.so");
+    let payer = Keypair::new();
+    let log_ix = Instruction {
+        program_id,
+        accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+        data: vec![5, 10, 11, 12, 13, 14],
+    };
+
+    for log_bytes_limit in [Some(limit), None] {
+        let mut svm = LiteSVM::new().with_log_bytes_limit(log_bytes_limit);
+        svm.add_program(program_id, bytes).unwrap();
+        svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&vec![log_ix.clone(); 5], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let simulated = svm.simulate_transaction(tx.clone()).unwrap();
+        let meta = svm.send_transaction(tx).unwrap();
+        assert_eq!(simulated.meta.logs_truncated, meta.logs_truncated);
+
+        if log_bytes_limit.is_some() {
+            assert!(meta.logs_truncated);
+            let (marker, logs) = meta.logs.split_last().unwrap();
+            assert_eq!(marker, "Log truncated");
+            assert!(logs.iter().map(String::len).sum::<usize>() < limit);
+        } else {
+            assert!(!meta.logs_truncated);
+            assert!(meta.logs.iter().map(String::len).sum::<usize>() >= limit);
+        }
+    }
+}
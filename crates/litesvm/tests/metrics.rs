@@ -0,0 +1,41 @@
+use {
+    litesvm::LiteSVM, solana_keypair::Keypair, solana_message::Message, solana_pubkey::Pubkey,
+    solana_signer::Signer, solana_system_interface::instruction::transfer,
+    solana_transaction::Transaction,
+};
+
+#[test_log::test]
+fn test_metrics() {
+    let payer = Keypair::new();
+    let mut svm = LiteSVM::new();
+    assert_eq!(svm.metrics(), Default::default());
+
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let after_airdrop = svm.metrics();
+    assert_eq!(after_airdrop.transactions, 1);
+    assert_eq!(after_airdrop.instructions, 1);
+    assert_eq!(after_airdrop.program_invocations, 1);
+    assert!(after_airdrop.compute_units_consumed > 0);
+
+    let instructions = [
+        transfer(&payer.pubkey(), &Pubkey::new_unique(), 100),
+        transfer(&payer.pubkey(), &Pubkey::new_unique(), 100),
+    ];
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(&instructions, Some(&payer.pubkey())),
+        svm.latest_blockhash(),
+    );
+    let meta = svm.send_transaction(tx).unwrap();
+
+    let metrics = svm.metrics();
+    assert_eq!(metrics.transactions, 2);
+    assert_eq!(metrics.instructions, 3);
+    assert_eq!(metrics.program_invocations, 3);
+    assert_eq!(
+        metrics.compute_units_consumed,
+        after_airdrop.compute_units_consumed + meta.compute_units_consumed
+    );
+    assert!(metrics.execution_time >= after_airdrop.execution_time);
+    assert_eq!(svm.transaction_count(), metrics.transactions);
+}
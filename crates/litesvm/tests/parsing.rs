@@ -44,3 +44,29 @@ fn test_inner_instruction_parsing() {
         result.inner_instructions[0][0].instruction.data
     );
 }
+
+#[test]
+fn test_max_trace_entries() {
+    let mut svm = LiteSVM::new().with_max_trace_entries(2);
+    let payer_kp = Keypair::new();
+    let payer_pk = payer_kp.pubkey();
+    svm.airdrop(&payer_pk, 1000000000).unwrap();
+    let blockhash = svm.latest_blockhash();
+    let (lookup_table_ix, lookup_table_address) = create_lookup_table(payer_pk, payer_pk, 0);
+    let extend_ix = extend_lookup_table(
+        lookup_table_address,
+        payer_pk,
+        Some(payer_pk),
+        vec![Pubkey::new_unique()],
+    );
+    let lookup_msg = Message::new(&[lookup_table_ix, extend_ix], Some(&payer_pk));
+    let lookup_tx = Transaction::new(&[&payer_kp], lookup_msg, blockhash);
+    let result = svm.send_transaction(lookup_tx).unwrap();
+    // 4 inner instructions are produced; the 2 oldest are dropped
+    assert!(result.inner_instructions_truncated);
+    assert_eq!(2, result.inner_instructions.len());
+    assert_eq!(1, result.inner_instructions[0].len());
+    assert_eq!(1, result.inner_instructions[1].len());
+    // the last CPI made by create_lookup_table is kept
+    assert_eq!(2, result.inner_instructions[0][0].stack_height);
+}
@@ -0,0 +1,124 @@
+use {
+    litesvm::{types::AccountsView, LiteSVM},
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::transfer,
+    solana_transaction::Transaction,
+    std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+#[test_log::test]
+fn test_pre_transaction_hook() {
+    let payer = Keypair::new();
+    let drained = Pubkey::new_unique();
+
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    svm.airdrop(&drained, 1_000_000).unwrap();
+    svm.set_pre_transaction_hook(Box::new(move |accounts: &mut AccountsView| {
+        if let Some(mut account) = accounts.get_account(&drained) {
+            account.lamports = 0;
+            accounts.set_account(drained, account).unwrap();
+        }
+    }));
+
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(
+            &[transfer(&payer.pubkey(), &drained, 100)],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+    // the transfer landed on the drained balance
+    assert_eq!(svm.get_balance(&drained), Some(100));
+
+    svm.clear_pre_transaction_hook();
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(
+            &[transfer(&payer.pubkey(), &drained, 200)],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+    assert_eq!(svm.get_balance(&drained), Some(300));
+}
+
+#[test_log::test]
+fn test_pre_transaction_hook_is_shared_with_clones() {
+    let payer = Keypair::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let counter = calls.clone();
+    svm.set_pre_transaction_hook(Box::new(move |_: &mut AccountsView| {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }));
+    let mut clone = svm.clone();
+
+    for (svm, lamports) in [(&mut svm, 100), (&mut clone, 200)] {
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(
+                &[transfer(&payer.pubkey(), &Pubkey::new_unique(), lamports)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+    }
+    assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+    // clearing the hook on one instance leaves the other's in place
+    clone.clear_pre_transaction_hook();
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(
+            &[transfer(&payer.pubkey(), &Pubkey::new_unique(), 300)],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+    assert_eq!(calls.load(Ordering::Relaxed), 3);
+}
+
+#[test_log::test]
+fn test_pre_transaction_hook_keeps_state() {
+    let payer = Keypair::new();
+    let target = Pubkey::new_unique();
+
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    svm.airdrop(&target, 1_000_000).unwrap();
+    // bump the target balance by one more lamport before each transaction
+    let mut bump = 0;
+    svm.set_pre_transaction_hook(Box::new(move |accounts: &mut AccountsView| {
+        bump += 1;
+        let mut account = accounts.get_account(&target).unwrap();
+        account.lamports += bump;
+        accounts.set_account(target, account).unwrap();
+    }));
+
+    for lamports in [100, 200] {
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(
+                &[transfer(&payer.pubkey(), &Pubkey::new_unique(), lamports)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+    }
+    assert_eq!(svm.get_balance(&target), Some(1_000_003));
+}
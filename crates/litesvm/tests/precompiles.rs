@@ -1,19 +1,27 @@
 use {
+    agave_feature_set::FeatureSet,
+    common::create_mint,
     ed25519_dalek::ed25519::signature::Signer,
-    litesvm::LiteSVM,
+    litesvm::{types::ProgramKind, LiteSVM},
+    solana_account::Account,
     solana_ed25519_program::{self as ed25519_instruction, new_ed25519_instruction_with_signature},
     solana_instruction::error::InstructionError,
     solana_keypair::Keypair,
     solana_message::Message,
+    solana_pubkey::Pubkey,
     solana_secp256k1_program::{
         self as secp256k1_instruction, eth_address_from_pubkey,
         new_secp256k1_instruction_with_signature, sign_message,
     },
     solana_signer::Signer as SolanaSigner,
+    solana_system_interface::instruction::transfer,
     solana_transaction::Transaction,
     solana_transaction_error::TransactionError,
+    spl_associated_token_account_interface::instruction::create_associated_token_account,
 };
 
+mod common;
+
 #[test_log::test]
 fn ed25519_precompile_ok() {
     let kp = Keypair::new();
@@ -75,6 +83,50 @@ fn ed25519_precompile_err() {
     );
 }
 
+#[test_log::test]
+fn ed25519_precompile_sigverify_disabled() {
+    let kp = Keypair::new();
+    let kp_dalek = ed25519_dalek::Keypair::from_bytes(&kp.to_bytes()).unwrap();
+
+    // Act - Produce an invalid ed25519 instruction followed by a transfer.
+    let message = b"hello world";
+    let signature = kp_dalek.sign(message);
+    let mut ix = new_ed25519_instruction_with_signature(
+        message,
+        &signature.to_bytes(),
+        kp.pubkey().as_array(),
+    );
+    ix.data[ed25519_instruction::DATA_START + 32] = 0;
+    let recipient = Pubkey::new_unique();
+    let transfer_ix = transfer(&kp.pubkey(), &recipient, 1_000_000);
+
+    for sigverify_precompiles in [true, false] {
+        let mut svm = LiteSVM::new().with_sigverify_precompiles(sigverify_precompiles);
+        svm.airdrop(&kp.pubkey(), 10u64.pow(9)).unwrap();
+        let tx = Transaction::new(
+            &[&kp],
+            Message::new(&[ix.clone(), transfer_ix.clone()], Some(&kp.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let res = svm.send_transaction(tx);
+
+        // Assert - Only fails when precompile signatures are verified.
+        if sigverify_precompiles {
+            assert_eq!(
+                res.err().map(|fail| fail.err),
+                Some(TransactionError::InstructionError(
+                    0,
+                    InstructionError::Custom(2)
+                ))
+            );
+            assert_eq!(svm.get_balance(&recipient), None);
+        } else {
+            assert!(res.is_ok());
+            assert_eq!(svm.get_balance(&recipient), Some(1_000_000));
+        }
+    }
+}
+
 #[test_log::test]
 fn secp256k1_precompile_ok() {
     let kp = Keypair::new();
@@ -134,3 +186,104 @@ fn secp256k1_precompile_err() {
         ))
     );
 }
+
+#[test_log::test]
+fn precompile_program_kind() {
+    let svm = LiteSVM::new();
+    assert_eq!(
+        svm.program_kind(&solana_sdk_ids::ed25519_program::ID),
+        Some(ProgramKind::Precompile)
+    );
+}
+
+#[test_log::test]
+fn installed_precompiles() {
+    let mut installed = LiteSVM::new().installed_precompiles();
+    installed.sort();
+    let mut expected = vec![
+        solana_sdk_ids::ed25519_program::ID,
+        solana_sdk_ids::secp256k1_program::ID,
+        solana_sdk_ids::secp256r1_program::ID,
+    ];
+    expected.sort();
+    assert_eq!(installed, expected);
+
+    // without the standard precompiles, install only ed25519
+    let mut svm = LiteSVM::default()
+        .with_feature_set(FeatureSet::all_enabled())
+        .with_builtins()
+        .with_sysvars();
+    assert!(svm.installed_precompiles().is_empty());
+    svm.set_account(
+        solana_sdk_ids::ed25519_program::ID,
+        Account {
+            lamports: 1,
+            data: vec![],
+            owner: solana_sdk_ids::native_loader::ID,
+            executable: true,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        svm.installed_precompiles(),
+        vec![solana_sdk_ids::ed25519_program::ID]
+    );
+}
+
+#[test_log::test]
+fn execution_trace_after_precompile() {
+    let kp = Keypair::new();
+    let kp_dalek = ed25519_dalek::Keypair::from_bytes(&kp.to_bytes()).unwrap();
+
+    let mut svm = LiteSVM::new().with_execution_trace(true);
+    let mint = create_mint(&mut svm, &kp);
+
+    // Act - Verify a signature before a program that invokes others.
+    let message = b"hello world";
+    let signature = kp_dalek.sign(message);
+    let precompile_ix = new_ed25519_instruction_with_signature(
+        message,
+        &signature.to_bytes(),
+        kp.pubkey().as_array(),
+    );
+    let create_ix = create_associated_token_account(
+        &kp.pubkey(),
+        &Pubkey::new_unique(),
+        &mint,
+        &spl_token_interface::ID,
+    );
+    let tx = Transaction::new(
+        &[&kp],
+        Message::new(&[precompile_ix, create_ix], Some(&kp.pubkey())),
+        svm.latest_blockhash(),
+    );
+    let meta = svm.send_transaction(tx).unwrap();
+
+    // Assert - The precompile keeps its slot and logs nothing.
+    let [precompile, create] = meta.execution_trace.as_slice() else {
+        panic!("expected two top-level instructions");
+    };
+    assert_eq!(precompile.program_id, solana_sdk_ids::ed25519_program::ID);
+    assert_eq!(precompile.instruction_index, 0);
+    assert!(precompile.inner_instructions.is_empty());
+    assert!(precompile.logs.is_empty());
+    assert_eq!(
+        create.program_id,
+        spl_associated_token_account_interface::program::ID
+    );
+    assert_eq!(create.instruction_index, 1);
+    assert_eq!(create.compute_units_consumed, meta.compute_units_consumed);
+    assert!(create.logs.contains(&"Program log: Create".to_string()));
+    assert_eq!(
+        create.inner_instructions.len(),
+        meta.inner_instructions[1].len()
+    );
+    let get_size = &create.inner_instructions[0];
+    assert_eq!(get_size.program_id, spl_token_interface::ID);
+    assert_eq!(
+        get_size.logs,
+        ["Program log: Instruction: GetAccountDataSize"]
+    );
+    assert!(get_size.return_data.is_some());
+}
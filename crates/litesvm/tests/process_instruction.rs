@@ -0,0 +1,109 @@
+use {
+    litesvm::LiteSVM,
+    solana_instruction::{account_meta::AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_native_token::LAMPORTS_PER_SOL,
+    solana_pubkey::{pubkey, Pubkey},
+    solana_signer::Signer,
+    solana_system_interface::instruction::transfer,
+    solana_transaction_error::TransactionError,
+};
+
+const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+#[test_log::test]
+fn test_process_instruction() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    let recipient = Pubkey::new_unique();
+    svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+    let meta = svm
+        .process_instruction(transfer(&payer.pubkey(), &recipient, 1_000), &[&payer])
+        .unwrap();
+    assert!(svm.get_transaction(&meta.signature).is_some());
+    assert_eq!(svm.get_balance(&recipient), Some(1_000));
+    assert_eq!(
+        svm.get_balance(&payer.pubkey()),
+        Some(LAMPORTS_PER_SOL - 1_000 - 5_000)
+    );
+}
+
+#[test_log::test]
+fn test_process_instruction_extra_signer() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    let author = Keypair::new();
+    svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+    let memo = Instruction {
+        program_id: MEMO_PROGRAM_ID,
+        accounts: vec![AccountMeta::new_readonly(author.pubkey(), true)],
+        data: b"hello".to_vec(),
+    };
+    let meta = svm
+        .process_instruction(memo.clone(), &[&payer, &author])
+        .unwrap();
+    assert!(meta
+        .logs
+        .iter()
+        .any(|log| log.contains(r#"Memo (len 5): "hello""#)));
+
+    // a memo naming a signer that did not sign is rejected by the program
+    let mut unsigned = memo;
+    unsigned.accounts[0].is_signer = false;
+    unsigned.data = b"unsigned".to_vec();
+    let err = svm.process_instruction(unsigned, &[&payer]).unwrap_err();
+    assert!(matches!(err.err, TransactionError::InstructionError(0, _)));
+}
+
+#[test_log::test]
+fn test_assert_logs_contain() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+    let memo = Instruction {
+        program_id: MEMO_PROGRAM_ID,
+        accounts: vec![],
+        data: b"checksum 42".to_vec(),
+    };
+    let meta = svm.process_instruction(memo, &[&payer]).unwrap();
+    meta.assert_logs_contain(r#"Memo (len 11): "checksum 42""#)
+        .unwrap();
+
+    let err = meta.assert_logs_contain("checksum 43").unwrap_err();
+    assert!(err.contains(r#""checksum 43""#));
+    for log in &meta.logs {
+        assert!(err.contains(log.as_str()));
+    }
+}
+
+#[test_log::test]
+fn test_send_with_fee_payer() {
+    let mut svm = LiteSVM::new();
+    let fee_payer = Keypair::new();
+    let sender = Keypair::new();
+    let recipient = Pubkey::new_unique();
+    svm.airdrop(&fee_payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&sender.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+    // the sender is the first signer of the instruction, but doesn't pay the fee
+    let meta = svm
+        .send_with_fee_payer(
+            &[transfer(&sender.pubkey(), &recipient, 1_000)],
+            &fee_payer,
+            &[&sender],
+        )
+        .unwrap();
+    assert_eq!(meta.fee, 10_000);
+    assert_eq!(
+        svm.get_balance(&fee_payer.pubkey()),
+        Some(LAMPORTS_PER_SOL - meta.fee)
+    );
+    assert_eq!(
+        svm.get_balance(&sender.pubkey()),
+        Some(LAMPORTS_PER_SOL - 1_000)
+    );
+    assert_eq!(svm.get_balance(&recipient), Some(1_000));
+}
@@ -0,0 +1,58 @@
+use {
+    litesvm::{types::ProgramKind, LiteSVM},
+    solana_instruction::Instruction,
+    solana_keypair::Keypair,
+    solana_program_runtime::declare_process_instruction,
+    solana_pubkey::Pubkey,
+    solana_sdk_ids::{native_loader, system_program},
+    solana_signer::Signer,
+};
+
+declare_process_instruction!(Noop, 150, |_invoke_context| { Ok(()) });
+
+#[test_log::test]
+fn test_program_kind() {
+    let mut svm = LiteSVM::new();
+    let bytes = include_bytes!("../../node-litesvm/program_bytes/spl_example_logging.so");
+
+    let program_id = Pubkey::new_unique();
+    svm.add_program(program_id, bytes).unwrap();
+    assert_eq!(
+        svm.program_kind(&program_id),
+        Some(ProgramKind::BpfLoaderV2)
+    );
+
+    let upgradeable_id = Pubkey::new_unique();
+    svm.deploy_upgradeable_program(upgradeable_id, Pubkey::new_unique(), bytes, None)
+        .unwrap();
+    assert_eq!(
+        svm.program_kind(&upgradeable_id),
+        Some(ProgramKind::BpfLoaderUpgradeable)
+    );
+
+    assert_eq!(
+        svm.program_kind(&system_program::id()),
+        Some(ProgramKind::Builtin)
+    );
+    assert_eq!(svm.program_kind(&Pubkey::new_unique()), None);
+}
+
+#[test_log::test]
+fn test_add_builtin() {
+    let mut svm = LiteSVM::new();
+    let program_id = Pubkey::new_unique();
+    svm.add_builtin(program_id, Noop::vm);
+    assert_eq!(
+        svm.get_account(&program_id).unwrap().owner,
+        native_loader::id()
+    );
+    assert_eq!(svm.program_kind(&program_id), Some(ProgramKind::Builtin));
+
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    svm.process_instruction(
+        Instruction::new_with_bytes(program_id, &[], vec![]),
+        &[&payer],
+    )
+    .unwrap();
+}
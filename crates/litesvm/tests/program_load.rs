@@ -0,0 +1,192 @@
+use {
+    litesvm::{error::LiteSVMError, LiteSVM},
+    solana_account::Account,
+    solana_compute_budget_interface::ComputeBudgetInstruction,
+    solana_instruction::{error::InstructionError, Instruction},
+    solana_keypair::Keypair,
+    solana_loader_v3_interface::state::UpgradeableLoaderState,
+    solana_message::Message,
+    solana_pubkey::Pubkey,
+    solana_sdk_ids::{bpf_loader, bpf_loader_upgradeable, vote},
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+    solana_transaction_error::TransactionError,
+};
+
+#[test_log::test]
+fn test_corrupt_program_is_rejected() {
+    let mut svm = LiteSVM::new();
+    let program_id = Pubkey::new_unique();
+    let err = svm
+        .set_account(
+            program_id,
+            Account {
+                lamports: 1_000_000_000,
+                data: b"\x7fELF not really a program".to_vec(),
+                owner: bpf_loader::id(),
+                executable: true,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        LiteSVMError::Instruction(InstructionError::InvalidAccountData)
+    ));
+
+    // nothing was stored, so invoking the program fails cleanly
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(
+            &[Instruction::new_with_bytes(program_id, &[], vec![])],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    let failed = svm.send_transaction(tx).unwrap_err();
+    assert_eq!(failed.err, TransactionError::InvalidProgramForExecution);
+}
+
+#[test_log::test]
+fn test_corrupt_upgradeable_program_is_rejected() {
+    let mut svm = LiteSVM::new();
+    let program_id = Pubkey::new_unique();
+    let programdata_address = Pubkey::new_unique();
+    let mut programdata = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: None,
+    })
+    .unwrap();
+    programdata.resize(UpgradeableLoaderState::size_of_programdata_metadata(), 0);
+    programdata.extend_from_slice(b"\x7fELF not really a program");
+    // programdata is not executable, so it is stored without being loaded
+    svm.set_account(
+        programdata_address,
+        Account {
+            lamports: 1_000_000_000,
+            data: programdata,
+            owner: bpf_loader_upgradeable::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    let err = svm
+        .set_account(
+            program_id,
+            Account {
+                lamports: 1_000_000_000,
+                data: bincode::serialize(&UpgradeableLoaderState::Program {
+                    programdata_address,
+                })
+                .unwrap(),
+                owner: bpf_loader_upgradeable::id(),
+                executable: true,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        LiteSVMError::Instruction(InstructionError::InvalidAccountData)
+    ));
+    assert!(svm.get_account(&program_id).is_none());
+}
+
+#[test_log::test]
+fn test_add_program_raw_unchecked() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let invoke = |svm: &mut LiteSVM, program_id: Pubkey, data: &[u8]| {
+        svm.process_instruction(
+            Instruction::new_with_bytes(program_id, data, vec![]),
+            &[&payer],
+        )
+    };
+
+    for garbage in [
+        &[0xde, 0xad, 0xbe, 0xef][..],
+        b"\x7fELF not really a program",
+    ] {
+        let program_id = Pubkey::new_unique();
+        svm.add_program_raw_unchecked(&program_id, garbage);
+        let account = svm.get_account(&program_id).unwrap();
+        assert!(account.executable);
+        assert_eq!(account.data, garbage);
+        let failed = invoke(&mut svm, program_id, &[]).unwrap_err();
+        assert_eq!(
+            failed.err,
+            TransactionError::InstructionError(0, InstructionError::UnsupportedProgramId)
+        );
+    }
+
+    // bytes that parse are loaded and run
+    let program_id = Pubkey::new_unique();
+    svm.add_program_raw_unchecked(
+        &program_id,
+        include_bytes!("../src/programs/elf/spl_memo-3.0.0.so"),
+    );
+    let meta = invoke(&mut svm, program_id, b"raw").unwrap();
+    assert!(meta
+        .logs
+        .iter()
+        .any(|log| log.contains(r#"Memo (len 3): "raw""#)));
+}
+
+#[test_log::test]
+fn test_override_builtin() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let memo_bytes = include_bytes!("../src/programs/elf/spl_memo-3.0.0.so");
+    let memo = |data: &[u8]| Instruction::new_with_bytes(vote::id(), data, vec![]);
+
+    // only registered builtins can be overridden
+    let program_id = Pubkey::new_unique();
+    let err = svm.override_builtin(program_id, memo_bytes).unwrap_err();
+    assert!(matches!(err, LiteSVMError::NotABuiltin(id) if id == program_id));
+    assert!(svm.get_account(&program_id).is_none());
+
+    // the vote builtin rejects the memo
+    let failed = svm
+        .process_instruction(memo(b"builtin"), &[&payer])
+        .unwrap_err();
+    assert!(matches!(
+        failed.err,
+        TransactionError::InstructionError(0, _)
+    ));
+
+    svm.override_builtin(vote::id(), memo_bytes).unwrap();
+    assert_eq!(
+        svm.get_account(&vote::id()).unwrap().owner,
+        bpf_loader::id()
+    );
+    // once overridden the address holds an SBF program, not a builtin
+    let err = svm.override_builtin(vote::id(), memo_bytes).unwrap_err();
+    assert!(matches!(err, LiteSVMError::NotABuiltin(_)));
+
+    // without a compute unit limit the stand-in only gets a builtin's budget
+    let failed = svm
+        .process_instruction(memo(b"unbudgeted"), &[&payer])
+        .unwrap_err();
+    assert!(failed.meta.hit_compute_limit());
+
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+                memo(b"mocked"),
+            ],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    let meta = svm.send_transaction(tx).unwrap();
+    meta.assert_logs_contain(r#"Memo (len 6): "mocked""#)
+        .unwrap();
+}
@@ -0,0 +1,146 @@
+use {
+    litesvm::LiteSVM,
+    solana_account::Account,
+    solana_instruction::error::InstructionError,
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_pubkey::Pubkey,
+    solana_rent::{Rent, ACCOUNT_STORAGE_OVERHEAD},
+    solana_signer::Signer,
+    solana_system_interface::{
+        instruction::{create_account, transfer},
+        program as system_program,
+    },
+    solana_transaction::Transaction,
+    solana_transaction_error::TransactionError,
+};
+
+#[test_log::test]
+fn test_custom_lamports_per_byte_year() {
+    let mut svm = LiteSVM::new();
+    let rent = Rent {
+        lamports_per_byte_year: 1_000,
+        ..svm.get_sysvar::<Rent>()
+    };
+    svm.set_sysvar(&rent);
+
+    let expected =
+        (((ACCOUNT_STORAGE_OVERHEAD + 100) * 1_000) as f64 * rent.exemption_threshold) as u64;
+    assert_eq!(svm.minimum_balance_for_rent_exemption(100), expected);
+}
+
+#[test_log::test]
+fn test_rent_fields_set_independently() {
+    let mut svm = LiteSVM::new();
+    let default_rent = svm.get_sysvar::<Rent>();
+
+    let mut rent = default_rent.clone();
+    rent.exemption_threshold = 1.0;
+    svm.set_sysvar(&rent);
+    assert_eq!(
+        svm.minimum_balance_for_rent_exemption(100),
+        (ACCOUNT_STORAGE_OVERHEAD + 100) * default_rent.lamports_per_byte_year
+    );
+
+    rent.burn_percent = 100;
+    svm.set_sysvar(&rent);
+    let stored = svm.get_sysvar::<Rent>();
+    assert_eq!(stored.burn_percent, 100);
+    assert_eq!(stored.exemption_threshold, 1.0);
+    assert_eq!(
+        stored.lamports_per_byte_year,
+        default_rent.lamports_per_byte_year
+    );
+    assert_eq!(
+        svm.minimum_balance_for_rent_exemption(100),
+        (ACCOUNT_STORAGE_OVERHEAD + 100) * default_rent.lamports_per_byte_year
+    );
+}
+
+#[test_log::test]
+fn test_require_rent_exempt() {
+    for require_rent_exempt in [true, false] {
+        let mut svm = LiteSVM::new().with_require_rent_exempt(require_rent_exempt);
+        let payer = Keypair::new();
+        let account = Keypair::new();
+        svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+
+        let lamports = svm.minimum_balance_for_rent_exemption(100) - 1;
+        let tx = Transaction::new(
+            &[&payer, &account],
+            Message::new(
+                &[create_account(
+                    &payer.pubkey(),
+                    &account.pubkey(),
+                    lamports,
+                    100,
+                    &Pubkey::new_unique(),
+                )],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        if require_rent_exempt {
+            assert_eq!(
+                result.unwrap_err().err,
+                TransactionError::InsufficientFundsForRent { account_index: 1 }
+            );
+            assert_eq!(svm.get_account(&account.pubkey()), None);
+        } else {
+            result.unwrap();
+            assert_eq!(svm.get_balance(&account.pubkey()), Some(lamports));
+        }
+    }
+}
+
+#[test_log::test]
+fn test_transfer_rent_exempt_reserve() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    let recipient = Pubkey::new_unique();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+
+    // a source carrying data can't be drained below the rent-exempt minimum
+    let source = Keypair::new();
+    let minimum = svm.minimum_balance_for_rent_exemption(10);
+    svm.set_account(
+        source.pubkey(),
+        Account {
+            lamports: minimum,
+            data: vec![0; 10],
+            owner: system_program::id(),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let tx = Transaction::new(
+        &[&payer, &source],
+        Message::new(
+            &[transfer(&source.pubkey(), &recipient, 1)],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    // the system program refuses to debit a source that carries data
+    assert_eq!(
+        svm.send_transaction(tx).unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+    assert_eq!(svm.get_balance(&source.pubkey()), Some(minimum));
+
+    // a plain system account may be emptied entirely
+    let plain = Keypair::new();
+    svm.airdrop(&plain.pubkey(), 1_000_000).unwrap();
+    let tx = Transaction::new(
+        &[&payer, &plain],
+        Message::new(
+            &[transfer(&plain.pubkey(), &recipient, 1_000_000)],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+    assert_eq!(svm.get_balance(&plain.pubkey()).unwrap_or_default(), 0);
+    assert_eq!(svm.get_balance(&recipient), Some(1_000_000));
+}
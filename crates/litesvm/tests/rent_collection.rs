@@ -0,0 +1,108 @@
+use {
+    litesvm::LiteSVM, solana_account::Account, solana_clock::Clock,
+    solana_epoch_schedule::EpochSchedule, solana_keypair::Keypair, solana_message::Message,
+    solana_pubkey::Pubkey, solana_signer::Signer, solana_system_interface::instruction::transfer,
+    solana_transaction::Transaction,
+};
+
+/// Creates an account holding half of its rent-exempt minimum.
+fn rent_paying_account(svm: &mut LiteSVM) -> (Pubkey, u64) {
+    let address = Pubkey::new_unique();
+    let lamports = svm.minimum_balance_for_rent_exemption(100) / 2;
+    svm.set_account(
+        address,
+        Account {
+            lamports,
+            data: vec![0; 100],
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+    (address, lamports)
+}
+
+/// Sends a transaction that writes to `address` without changing its balance.
+fn touch(svm: &mut LiteSVM, payer: &Keypair, address: &Pubkey) {
+    let tx = Transaction::new(
+        &[payer],
+        Message::new(
+            &[transfer(&payer.pubkey(), address, 0)],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+}
+
+/// Moves the clock to `epoch` without going through a warp.
+fn set_epoch(svm: &mut LiteSVM, epoch: u64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.slot = svm
+        .get_sysvar::<EpochSchedule>()
+        .get_first_slot_in_epoch(epoch);
+    clock.epoch = epoch;
+    svm.set_sysvar(&clock);
+}
+
+#[test_log::test]
+fn test_rent_collection_disabled_by_default() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let (address, lamports) = rent_paying_account(&mut svm);
+
+    let schedule = svm.get_sysvar::<EpochSchedule>();
+    svm.warp_to_slot(schedule.get_first_slot_in_epoch(10));
+    assert_eq!(svm.get_balance(&address), Some(lamports));
+
+    set_epoch(&mut svm, 20);
+    touch(&mut svm, &payer, &address);
+    assert_eq!(svm.get_balance(&address), Some(lamports));
+}
+
+#[test_log::test]
+fn test_rent_collection_on_warp() {
+    let mut svm = LiteSVM::new().with_rent_collection(true);
+    let (address, lamports) = rent_paying_account(&mut svm);
+
+    let drained = Pubkey::new_unique();
+    svm.set_account(
+        drained,
+        Account {
+            lamports: 1,
+            data: vec![0; 100],
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    let schedule = svm.get_sysvar::<EpochSchedule>();
+    let slot = schedule.get_first_slot_in_epoch(10);
+    svm.warp_to_slot(slot);
+    let after_warp = svm.get_balance(&address).unwrap();
+    assert!(after_warp < lamports);
+    assert_eq!(svm.get_account_modified_slot(&address), Some(slot));
+    assert_eq!(svm.get_account(&drained), None);
+
+    // rent for the elapsed epochs has already been paid
+    svm.warp_to_slot(slot + 1);
+    assert_eq!(svm.get_balance(&address), Some(after_warp));
+    assert_eq!(svm.get_account_modified_slot(&address), Some(slot));
+}
+
+#[test_log::test]
+fn test_rent_collection_on_transaction() {
+    let mut svm = LiteSVM::new().with_rent_collection(true);
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let (address, lamports) = rent_paying_account(&mut svm);
+
+    set_epoch(&mut svm, 20);
+    assert_eq!(svm.get_balance(&address), Some(lamports));
+    touch(&mut svm, &payer, &address);
+    assert!(svm.get_balance(&address).unwrap() < lamports);
+}
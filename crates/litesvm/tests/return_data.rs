@@ -0,0 +1,91 @@
+use {
+    litesvm::LiteSVM,
+    solana_account::Account,
+    solana_keypair::Keypair,
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_stake_interface::{self as stake, instruction::get_minimum_delegation},
+    solana_transaction::Transaction,
+    spl_token_interface::{instruction::get_account_data_size, state::Mint},
+};
+
+#[test]
+fn simulate_captures_return_data_per_instruction() {
+    let mut svm = LiteSVM::new().with_capture_instruction_return_data(true);
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let mint = Pubkey::new_unique();
+    let mut mint_data = vec![0; Mint::LEN];
+    Mint {
+        mint_authority: COption::None,
+        supply: 0,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut mint_data);
+    svm.set_account(
+        mint,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(Mint::LEN),
+            data: mint_data,
+            owner: spl_token_interface::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    let minimum_delegation = svm
+        .simulate_transaction(Transaction::new_signed_with_payer(
+            &[get_minimum_delegation()],
+            Some(&payer.pubkey()),
+            &[&payer],
+            svm.latest_blockhash(),
+        ))
+        .unwrap()
+        .meta
+        .return_data
+        .data;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            get_minimum_delegation(),
+            get_account_data_size(&spl_token_interface::ID, &mint).unwrap(),
+        ],
+        Some(&payer.pubkey()),
+        &[&payer],
+        svm.latest_blockhash(),
+    );
+    let info = svm.simulate_transaction(tx).unwrap();
+    let instruction_return_data = info.instruction_return_data.unwrap();
+
+    assert_eq!(instruction_return_data.len(), 2);
+    let (program_id, data) = instruction_return_data[0].clone().unwrap();
+    assert_eq!(program_id, stake::program::ID);
+    assert_eq!(data, minimum_delegation);
+    let (program_id, data) = instruction_return_data[1].clone().unwrap();
+    assert_eq!(program_id, spl_token_interface::ID);
+    assert_eq!(
+        data,
+        (spl_token_interface::state::Account::LEN as u64).to_le_bytes()
+    );
+    assert_eq!(info.meta.return_data.data, data);
+}
+
+#[test]
+fn simulate_skips_per_instruction_return_data_by_default() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[get_minimum_delegation()],
+        Some(&payer.pubkey()),
+        &[&payer],
+        svm.latest_blockhash(),
+    );
+    let info = svm.simulate_transaction(tx).unwrap();
+    assert!(info.instruction_return_data.is_none());
+}
@@ -0,0 +1,59 @@
+use {
+    litesvm::LiteSVM, solana_account::AccountSharedData, solana_keypair::Keypair,
+    solana_message::Message, solana_pubkey::Pubkey, solana_signature::Signature,
+    solana_signer::Signer, solana_slot_hashes::SlotHashes,
+    solana_system_interface::instruction::transfer, solana_transaction::Transaction,
+};
+
+fn run(svm: &mut LiteSVM) -> (Vec<Signature>, Vec<(Pubkey, AccountSharedData)>) {
+    let payer = Keypair::new_from_array([1; 32]);
+    let recipient = svm.next_unique_pubkey();
+    let mut signatures = vec![
+        svm.airdrop(&payer.pubkey(), 1_000_000_000)
+            .unwrap()
+            .signature,
+    ];
+    for lamports in [1_000_000, 2_000_000] {
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(
+                &[transfer(&payer.pubkey(), &recipient, lamports)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        signatures.push(svm.send_transaction(tx).unwrap().signature);
+        svm.warp_to_slot(svm.get_clock().slot + 10);
+        svm.expire_blockhash();
+    }
+    let mut accounts: Vec<_> = svm
+        .accounts_db()
+        .inner
+        .iter()
+        .map(|(pubkey, account)| (*pubkey, account.clone()))
+        .collect();
+    accounts.sort_unstable_by_key(|(pubkey, _)| *pubkey);
+    (signatures, accounts)
+}
+
+#[test_log::test]
+fn test_rng_seed() {
+    let mut first = LiteSVM::new().with_rng_seed(42);
+    let mut second = LiteSVM::new().with_rng_seed(42);
+    assert_eq!(run(&mut first), run(&mut second));
+    assert_eq!(first.latest_blockhash(), second.latest_blockhash());
+    assert_eq!(first.genesis_hash(), second.genesis_hash());
+    assert_eq!(
+        first.get_sysvar::<SlotHashes>(),
+        second.get_sysvar::<SlotHashes>()
+    );
+
+    let mut other = LiteSVM::new().with_rng_seed(43);
+    run(&mut other);
+    assert_ne!(first.latest_blockhash(), other.latest_blockhash());
+    assert_ne!(first.airdrop_pubkey(), other.airdrop_pubkey());
+    assert_ne!(
+        first.get_sysvar::<SlotHashes>(),
+        other.get_sysvar::<SlotHashes>()
+    );
+}
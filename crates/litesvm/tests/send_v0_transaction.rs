@@ -0,0 +1,52 @@
+use {
+    litesvm::LiteSVM,
+    solana_address_lookup_table_interface::{
+        instruction::{create_lookup_table, extend_lookup_table},
+        state::AddressLookupTable,
+    },
+    solana_keypair::Keypair,
+    solana_message::{AddressLookupTableAccount, Message},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::transfer,
+    solana_transaction::Transaction,
+};
+
+#[test_log::test]
+fn test_send_v0_transaction() {
+    let mut svm = LiteSVM::new();
+    let payer_kp = Keypair::new();
+    let payer_pk = payer_kp.pubkey();
+    svm.airdrop(&payer_pk, 1_000_000_000).unwrap();
+    let recipients: [Pubkey; 2] = std::array::from_fn(|_| Pubkey::new_unique());
+
+    let (lookup_table_ix, lookup_table_address) = create_lookup_table(payer_pk, payer_pk, 0);
+    let extend_ix = extend_lookup_table(
+        lookup_table_address,
+        payer_pk,
+        Some(payer_pk),
+        recipients.to_vec(),
+    );
+    svm.send_transaction(Transaction::new(
+        &[&payer_kp],
+        Message::new(&[lookup_table_ix, extend_ix], Some(&payer_pk)),
+        svm.latest_blockhash(),
+    ))
+    .unwrap();
+    svm.warp_to_slot(1); // can't use the lookup table in the same slot
+
+    let table_account = svm.get_account(&lookup_table_address).unwrap();
+    let table = AddressLookupTable::deserialize(&table_account.data).unwrap();
+    let lookup_tables = [AddressLookupTableAccount {
+        key: lookup_table_address,
+        addresses: table.addresses.to_vec(),
+    }];
+    let instructions = recipients.map(|recipient| transfer(&payer_pk, &recipient, 1_000_000));
+
+    svm.send_v0_transaction(&instructions, &[&payer_kp], &lookup_tables)
+        .unwrap();
+
+    for recipient in recipients {
+        assert_eq!(svm.get_balance(&recipient), Some(1_000_000));
+    }
+}
@@ -0,0 +1,42 @@
+use {
+    litesvm::{types::AddressLookupTableAccount, LiteSVM},
+    serde_json::json,
+    solana_pubkey::Pubkey,
+};
+
+#[test_log::test]
+fn test_address_lookup_table_serde() {
+    let mut svm = LiteSVM::new();
+    let key = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let addresses = [Pubkey::new_unique(), Pubkey::new_unique()];
+    svm.create_address_lookup_table(&key, &authority, &addresses)
+        .unwrap();
+    let table = svm.get_address_lookup_table(&key).unwrap();
+
+    let value = serde_json::to_value(&table).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "key": key.to_string(),
+            "authority": authority.to_string(),
+            "deactivation_slot": u64::MAX,
+            "addresses": [addresses[0].to_string(), addresses[1].to_string()],
+        })
+    );
+    assert_eq!(
+        serde_json::from_value::<AddressLookupTableAccount>(value).unwrap(),
+        table
+    );
+
+    let frozen = AddressLookupTableAccount {
+        authority: None,
+        ..table
+    };
+    let value = serde_json::to_value(&frozen).unwrap();
+    assert_eq!(value["authority"], json!(null));
+    assert_eq!(
+        serde_json::from_value::<AddressLookupTableAccount>(value).unwrap(),
+        frozen
+    );
+}
@@ -0,0 +1,88 @@
+use {
+    litesvm::LiteSVM,
+    solana_account::ReadableAccount,
+    solana_hash::Hash,
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_native_token::LAMPORTS_PER_SOL,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::transfer,
+    solana_transaction::{versioned::VersionedTransaction, Transaction},
+    solana_transaction_error::TransactionError,
+};
+
+#[test_log::test]
+fn test_simulate_transactions() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let recipients = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let transaction_count = svm.transaction_count();
+
+    let tx = |to: &Pubkey, lamports: u64| {
+        VersionedTransaction::from(Transaction::new(
+            &[&payer],
+            Message::new(
+                &[transfer(&payer.pubkey(), to, lamports)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        ))
+    };
+    // each of the first two would leave too little for the other if they were sent
+    let txs = [
+        tx(&recipients[0], LAMPORTS_PER_SOL * 6 / 10),
+        tx(&recipients[1], LAMPORTS_PER_SOL * 6 / 10),
+        tx(&recipients[0], 2 * LAMPORTS_PER_SOL),
+    ];
+    let results = svm.simulate_transactions(&txs);
+
+    assert_eq!(results.len(), 3);
+    for (result, to) in results[..2].iter().zip(&recipients) {
+        let info = result.as_ref().unwrap();
+        let (_, account) = info
+            .post_accounts
+            .iter()
+            .find(|(address, _)| address == to)
+            .unwrap();
+        assert_eq!(account.lamports(), LAMPORTS_PER_SOL * 6 / 10);
+    }
+    assert!(results[2].is_err());
+
+    assert_eq!(svm.get_balance(&payer.pubkey()), Some(LAMPORTS_PER_SOL));
+    assert_eq!(svm.get_balance(&recipients[0]), None);
+    assert_eq!(svm.get_balance(&recipients[1]), None);
+    assert_eq!(svm.transaction_count(), transaction_count);
+    for tx in &txs {
+        assert!(svm.get_transaction(&tx.signatures[0]).is_none());
+    }
+}
+
+#[test_log::test]
+fn test_simulate_transaction_replace_blockhash() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let recipient = Pubkey::new_unique();
+
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(
+            &[transfer(&payer.pubkey(), &recipient, LAMPORTS_PER_SOL / 2)],
+            Some(&payer.pubkey()),
+        ),
+        Hash::default(),
+    );
+    let err = svm.simulate_transaction(tx.clone()).unwrap_err();
+    assert_eq!(err.err, TransactionError::BlockhashNotFound);
+
+    let info = svm.simulate_transaction_replace_blockhash(tx).unwrap();
+    let (_, account) = info
+        .post_accounts
+        .iter()
+        .find(|(address, _)| address == &recipient)
+        .unwrap();
+    assert_eq!(account.lamports(), LAMPORTS_PER_SOL / 2);
+    assert_eq!(svm.get_balance(&recipient), None);
+}
@@ -1,8 +1,26 @@
 use {
-    litesvm::LiteSVM, solana_keypair::Keypair, solana_program_pack::Pack, solana_rent::Rent,
-    solana_signer::Signer, solana_transaction::Transaction, spl_token_interface::state::Mint,
+    agave_feature_set::FeatureSet,
+    common::{create_mint, create_mint_instructions},
+    litesvm::{
+        types::{MintInfo, TokenBalance},
+        LiteSVM,
+    },
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_native_token::LAMPORTS_PER_SOL,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+    spl_associated_token_account_interface::{
+        address::get_associated_token_address, instruction::create_associated_token_account,
+    },
+    spl_token_interface::state::{Account as TokenAccount, Mint},
 };
 
+mod common;
+
 #[test]
 fn spl_token() {
     let mut svm = LiteSVM::new();
@@ -50,3 +68,229 @@ fn spl_token() {
     assert_eq!(mint.decimals, 8);
     assert_eq!(mint.mint_authority, Some(payer_pk).into());
 }
+
+#[test]
+fn spl_associated_token_account() {
+    let mut svm = LiteSVM::default()
+        .with_feature_set(FeatureSet::all_enabled())
+        .with_builtins()
+        .with_lamports(1_000_000 * LAMPORTS_PER_SOL)
+        .with_sysvars()
+        .with_spl_associated_token_account_program();
+    svm.add_program(
+        spl_token_interface::ID,
+        include_bytes!("../src/programs/elf/spl_token-3.5.0.so"),
+    )
+    .unwrap();
+    let payer_kp = Keypair::new();
+    let payer_pk = payer_kp.pubkey();
+    let mint_kp = Keypair::new();
+    let mint_pk = mint_kp.pubkey();
+    let owner_pk = Pubkey::new_unique();
+
+    svm.airdrop(&payer_pk, 1000000000).unwrap();
+
+    let create_acc_ins = solana_system_interface::instruction::create_account(
+        &payer_pk,
+        &mint_pk,
+        svm.minimum_balance_for_rent_exemption(Mint::LEN),
+        Mint::LEN as u64,
+        &spl_token_interface::ID,
+    );
+    let init_mint_ins = spl_token_interface::instruction::initialize_mint2(
+        &spl_token_interface::ID,
+        &mint_pk,
+        &payer_pk,
+        None,
+        8,
+    )
+    .unwrap();
+    let create_ata_ins =
+        create_associated_token_account(&payer_pk, &owner_pk, &mint_pk, &spl_token_interface::ID);
+    svm.send_transaction(Transaction::new_signed_with_payer(
+        &[create_acc_ins, init_mint_ins, create_ata_ins],
+        Some(&payer_pk),
+        &[&payer_kp, &mint_kp],
+        svm.latest_blockhash(),
+    ))
+    .unwrap();
+
+    let ata = get_associated_token_address(&owner_pk, &mint_pk);
+    let ata_acc = svm.get_account(&ata).unwrap();
+    assert_eq!(ata_acc.owner, spl_token_interface::ID);
+    let token_account = TokenAccount::unpack(&ata_acc.data).unwrap();
+    assert_eq!(token_account.mint, mint_pk);
+    assert_eq!(token_account.owner, owner_pk);
+}
+
+#[test]
+fn spl_mint_info() {
+    let mut svm = LiteSVM::new();
+    let payer_kp = Keypair::new();
+    let payer_pk = payer_kp.pubkey();
+    let mint_kp = Keypair::new();
+    let mint_pk = mint_kp.pubkey();
+    let owner_pk = Pubkey::new_unique();
+    let ata = get_associated_token_address(&owner_pk, &mint_pk);
+
+    svm.airdrop(&payer_pk, 1000000000).unwrap();
+    assert_eq!(svm.get_mint_info(&mint_pk), None);
+
+    let [create_mint, initialize_mint] = create_mint_instructions(&svm, &payer_pk, &mint_pk);
+    let ixs = [
+        create_mint,
+        initialize_mint,
+        create_associated_token_account(&payer_pk, &owner_pk, &mint_pk, &spl_token_interface::ID),
+        spl_token_interface::instruction::mint_to(
+            &spl_token_interface::ID,
+            &mint_pk,
+            &ata,
+            &payer_pk,
+            &[],
+            1_500_000,
+        )
+        .unwrap(),
+    ];
+    svm.send_transaction(Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&payer_pk),
+        &[&payer_kp, &mint_kp],
+        svm.latest_blockhash(),
+    ))
+    .unwrap();
+
+    assert_eq!(svm.get_token_supply(&mint_pk), Some(1_500_000));
+    assert_eq!(
+        svm.get_mint_info(&mint_pk),
+        Some(MintInfo {
+            decimals: 6,
+            supply: 1_500_000,
+            mint_authority: Some(payer_pk),
+            freeze_authority: None,
+        })
+    );
+    assert_eq!(svm.get_mint_info(&ata), None);
+}
+
+#[test]
+fn spl_logs_for_program() {
+    let mut svm = LiteSVM::new();
+    let payer_kp = Keypair::new();
+    let payer_pk = payer_kp.pubkey();
+    let mint_pk = create_mint(&mut svm, &payer_kp);
+    let owner_pk = Pubkey::new_unique();
+    let ata_program_id = spl_associated_token_account_interface::program::ID;
+
+    // the associated token account program creates the account through CPIs to the token program
+    let meta = svm
+        .process_instruction(
+            create_associated_token_account(
+                &payer_pk,
+                &owner_pk,
+                &mint_pk,
+                &spl_token_interface::ID,
+            ),
+            &[&payer_kp],
+        )
+        .unwrap();
+    let token_logs = meta.logs_for_program(&spl_token_interface::ID);
+    let ata_logs = meta.logs_for_program(&ata_program_id);
+    assert!(token_logs
+        .iter()
+        .any(|log| log == "Program log: Instruction: InitializeAccount3"));
+    assert!(token_logs.iter().all(|log| !ata_logs.contains(log)));
+    assert!(ata_logs.iter().any(|log| log == "Program log: Create"));
+    assert!(ata_logs
+        .iter()
+        .all(|log| !log.contains("Instruction: InitializeAccount3")));
+    assert!(meta
+        .logs_for_program(&solana_sdk_ids::system_program::ID)
+        .is_empty());
+}
+
+#[test]
+fn spl_token_balances() {
+    let mut svm = LiteSVM::new().with_token_balance_recording(true);
+    let payer_kp = Keypair::new();
+    let payer_pk = payer_kp.pubkey();
+    let mint_kp = Keypair::new();
+    let mint_pk = mint_kp.pubkey();
+    let receiver_pk = Pubkey::new_unique();
+    let sender_ata = get_associated_token_address(&payer_pk, &mint_pk);
+    let receiver_ata = get_associated_token_address(&receiver_pk, &mint_pk);
+
+    svm.airdrop(&payer_pk, 1000000000).unwrap();
+    let [create_mint, initialize_mint] = create_mint_instructions(&svm, &payer_pk, &mint_pk);
+    let ixs = [
+        create_mint,
+        initialize_mint,
+        create_associated_token_account(&payer_pk, &payer_pk, &mint_pk, &spl_token_interface::ID),
+        create_associated_token_account(
+            &payer_pk,
+            &receiver_pk,
+            &mint_pk,
+            &spl_token_interface::ID,
+        ),
+        spl_token_interface::instruction::mint_to(
+            &spl_token_interface::ID,
+            &mint_pk,
+            &sender_ata,
+            &payer_pk,
+            &[],
+            1_500_000,
+        )
+        .unwrap(),
+    ];
+    let meta = svm
+        .send_transaction(Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&payer_pk),
+            &[&payer_kp, &mint_kp],
+            svm.latest_blockhash(),
+        ))
+        .unwrap();
+    // the token accounts only exist once the transaction has run
+    assert!(meta.pre_token_balances.is_empty());
+    assert_eq!(meta.post_token_balances.len(), 2);
+
+    let transfer = spl_token_interface::instruction::transfer(
+        &spl_token_interface::ID,
+        &sender_ata,
+        &receiver_ata,
+        &payer_pk,
+        &[],
+        500_000,
+    )
+    .unwrap();
+    let account_keys = Message::new(std::slice::from_ref(&transfer), Some(&payer_pk)).account_keys;
+    let index_of = |key: &Pubkey| account_keys.iter().position(|k| k == key).unwrap() as u8;
+    let meta = svm.process_instruction(transfer, &[&payer_kp]).unwrap();
+    let balance =
+        |token_account: &Pubkey, owner: Pubkey, amount: u64, ui_amount: f64| TokenBalance {
+            account_index: index_of(token_account),
+            mint: mint_pk,
+            owner,
+            program_id: spl_token_interface::ID,
+            amount,
+            decimals: 6,
+            ui_amount,
+        };
+    let sorted = |mut balances: Vec<TokenBalance>| {
+        balances.sort_by_key(|balance| balance.account_index);
+        balances
+    };
+    assert_eq!(
+        meta.pre_token_balances,
+        sorted(vec![
+            balance(&sender_ata, payer_pk, 1_500_000, 1.5),
+            balance(&receiver_ata, receiver_pk, 0, 0.0),
+        ])
+    );
+    assert_eq!(
+        meta.post_token_balances,
+        sorted(vec![
+            balance(&sender_ata, payer_pk, 1_000_000, 1.0),
+            balance(&receiver_ata, receiver_pk, 500_000, 0.5),
+        ])
+    );
+}
@@ -1,13 +1,18 @@
 // ported from https://github.com/solana-program/stake/blob/main/program/tests/program_test.rs
 
 use {
-    litesvm::LiteSVM,
+    agave_feature_set::{stake_raise_minimum_delegation_to_1_sol, FeatureSet},
+    litesvm::{
+        types::{StakeActivation, StakeActivationState},
+        LiteSVM,
+    },
     solana_account::{Account, ReadableAccount, WritableAccount},
     solana_clock::Clock,
     solana_epoch_schedule::EpochSchedule,
     solana_hash::Hash,
     solana_instruction::Instruction,
     solana_keypair::Keypair,
+    solana_native_token::LAMPORTS_PER_SOL,
     solana_program_error::{ProgramError, ProgramResult},
     solana_pubkey::Pubkey,
     solana_rent::Rent,
@@ -840,3 +845,66 @@ fn test_stake_delegate() {
         process_instruction(&mut svm, &instruction, &vec![&staker_keypair], &payer).unwrap_err();
     assert_eq!(e, ProgramError::InvalidAccountData);
 }
+
+#[test]
+fn test_get_minimum_delegation() {
+    let svm = LiteSVM::new();
+    assert_eq!(svm.get_minimum_delegation(), LAMPORTS_PER_SOL);
+
+    let mut feature_set = FeatureSet::all_enabled();
+    feature_set.deactivate(&stake_raise_minimum_delegation_to_1_sol::id());
+    let svm = LiteSVM::new().with_feature_set(feature_set);
+    assert_eq!(svm.get_minimum_delegation(), 1);
+}
+
+#[test]
+fn test_get_stake_activation() {
+    let mut svm = LiteSVM::new();
+    let accounts = Accounts::default();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000_000).unwrap();
+    accounts.initialize(&mut svm, &payer);
+
+    let staker = Keypair::new();
+    let authorized = Authorized {
+        staker: staker.pubkey(),
+        withdrawer: staker.pubkey(),
+    };
+    let stake_amount = svm.get_minimum_delegation();
+    let stake = create_independent_stake_account(&mut svm, &authorized, stake_amount, &payer);
+    assert_eq!(
+        svm.get_stake_activation(&stake),
+        Some(StakeActivation {
+            state: StakeActivationState::Inactive,
+            active: 0,
+            inactive: stake_amount,
+        })
+    );
+
+    let instruction =
+        ixn::delegate_stake(&stake, &staker.pubkey(), &accounts.vote_account.pubkey());
+    process_instruction(&mut svm, &instruction, &vec![&staker], &payer).unwrap();
+    assert_eq!(
+        svm.get_stake_activation(&stake),
+        Some(StakeActivation {
+            state: StakeActivationState::Activating,
+            active: 0,
+            inactive: stake_amount,
+        })
+    );
+
+    advance_epoch(&mut svm);
+    assert_eq!(
+        svm.get_stake_activation(&stake),
+        Some(StakeActivation {
+            state: StakeActivationState::Active,
+            active: stake_amount,
+            inactive: 0,
+        })
+    );
+
+    assert_eq!(
+        svm.get_stake_activation(&accounts.vote_account.pubkey()),
+        None
+    );
+}
@@ -0,0 +1,52 @@
+use {
+    common::create_mint_instructions,
+    litesvm::{types::SyscallCosts, LiteSVM},
+    solana_keypair::Keypair,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+    spl_associated_token_account_interface::instruction::create_associated_token_account,
+};
+
+mod common;
+
+/// Creates an associated token account, which has the ATA program derive
+/// its address with the `sol_try_find_program_address` syscall.
+fn create_ata_compute_units(svm: &mut LiteSVM) -> u64 {
+    let payer = Keypair::new();
+    // Fixed addresses so the bump search takes the same number of attempts.
+    let mint = Keypair::new_from_array([7; 32]);
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let [create_mint, initialize_mint] =
+        create_mint_instructions(svm, &payer.pubkey(), &mint.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_mint,
+            initialize_mint,
+            create_associated_token_account(
+                &payer.pubkey(),
+                &Pubkey::new_from_array([9; 32]),
+                &mint.pubkey(),
+                &spl_token_interface::ID,
+            ),
+        ],
+        Some(&payer.pubkey()),
+        &[&payer, &mint],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap().compute_units_consumed
+}
+
+#[test]
+fn syscall_costs_override() {
+    let default_units = create_ata_compute_units(&mut LiteSVM::new());
+
+    let costs = SyscallCosts {
+        create_program_address_units: SyscallCosts::default().create_program_address_units + 10_000,
+        ..Default::default()
+    };
+    let overridden_units = create_ata_compute_units(&mut LiteSVM::new().with_syscall_costs(costs));
+
+    assert!(overridden_units > default_units);
+    assert_eq!((overridden_units - default_units) % 10_000, 0);
+}
@@ -1,12 +1,19 @@
 use {
     litesvm::LiteSVM,
+    solana_account::Account,
+    solana_instruction::{AccountMeta, Instruction},
     solana_keypair::Keypair,
     solana_message::Message,
     solana_native_token::LAMPORTS_PER_SOL,
-    solana_pubkey::Pubkey,
+    solana_pubkey::{pubkey, Pubkey},
     solana_signer::Signer,
-    solana_system_interface::instruction::{allocate, create_account, transfer},
+    solana_system_interface::instruction::{
+        allocate, allocate_with_seed, assign_with_seed, create_account, create_account_with_seed,
+        transfer,
+    },
     solana_transaction::Transaction,
+    solana_transaction_error::TransactionError,
+    std::path::PathBuf,
 };
 
 #[test_log::test]
@@ -35,6 +42,66 @@ fn system_transfer() {
     assert_eq!(to_account.unwrap().lamports, 64);
 }
 
+#[test_log::test]
+fn system_transfer_balances() {
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Pubkey::new_unique();
+
+    let mut svm = LiteSVM::new().with_balance_recording(true);
+    svm.airdrop(&from, LAMPORTS_PER_SOL).unwrap();
+
+    let message = Message::new(&[transfer(&from, &to, 64)], Some(&from));
+    let fee_payer_index = message
+        .account_keys
+        .iter()
+        .position(|key| key == &from)
+        .unwrap();
+    let to_index = message
+        .account_keys
+        .iter()
+        .position(|key| key == &to)
+        .unwrap();
+    let tx = Transaction::new(&[&from_keypair], message, svm.latest_blockhash());
+    let meta = svm.send_transaction(tx).unwrap();
+
+    assert_eq!(meta.pre_balances.len(), 3);
+    assert_eq!(meta.post_balances.len(), 3);
+    assert_eq!(
+        meta.post_balances[fee_payer_index],
+        meta.pre_balances[fee_payer_index] - meta.fee - 64
+    );
+    assert_eq!(meta.pre_balances[to_index], 0);
+    assert_eq!(meta.post_balances[to_index], 64);
+}
+
+#[test_log::test]
+fn system_transfer_nonexistent_fee_payer() {
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Pubkey::new_unique();
+
+    let mut svm = LiteSVM::new();
+
+    // The fee payer is checked before the missing program is looked up.
+    let ixs = [
+        transfer(&from, &to, 64),
+        Instruction::new_with_bytes(Pubkey::new_unique(), &[], vec![]),
+    ];
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&ixs, Some(&from)),
+        svm.latest_blockhash(),
+    );
+    let failed = svm.send_transaction(tx).unwrap_err();
+
+    assert_eq!(failed.err, TransactionError::AccountNotFound);
+    assert!(failed.meta.logs.is_empty());
+    assert_eq!(failed.meta.compute_units_consumed, 0);
+    assert!(svm.get_account(&from).is_none());
+    assert!(svm.get_account(&to).is_none());
+}
+
 #[test_log::test]
 fn system_create_account() {
     let from_keypair = Keypair::new();
@@ -119,3 +186,228 @@ fn test_airdrop_pubkey() {
     svm.airdrop(&recipient2, airdrop_amount).unwrap();
     assert_eq!(svm.get_balance(&recipient2).unwrap(), airdrop_amount);
 }
+
+#[test_log::test]
+fn test_faucet_seed() {
+    assert_ne!(
+        LiteSVM::new().airdrop_pubkey(),
+        LiteSVM::new().airdrop_pubkey()
+    );
+
+    let seed = [7; 32];
+    let mut svm = LiteSVM::new().with_faucet_seed(seed);
+    let other = LiteSVM::new().with_faucet_seed(seed);
+    let faucet = svm.airdrop_pubkey();
+    assert_eq!(faucet, other.airdrop_pubkey());
+    assert_ne!(faucet, LiteSVM::new().airdrop_pubkey());
+    assert_eq!(faucet, Keypair::new_from_array(seed).pubkey());
+
+    let recipient = Pubkey::new_unique();
+    svm.airdrop(&recipient, LAMPORTS_PER_SOL).unwrap();
+    assert_eq!(svm.get_balance(&recipient), Some(LAMPORTS_PER_SOL));
+}
+
+#[test_log::test]
+fn test_preview_airdrop() {
+    let mut svm = LiteSVM::new();
+    let recipient = Pubkey::new_unique();
+
+    let preview = svm.preview_airdrop(&recipient, LAMPORTS_PER_SOL);
+    assert_eq!(preview, LAMPORTS_PER_SOL);
+    assert!(svm.get_account(&recipient).is_none());
+    svm.airdrop(&recipient, LAMPORTS_PER_SOL).unwrap();
+    assert_eq!(svm.get_balance(&recipient), Some(preview));
+
+    svm.expire_blockhash(); // a second identical airdrop would be a duplicate
+    let preview = svm.preview_airdrop(&recipient, LAMPORTS_PER_SOL);
+    assert_eq!(preview, 2 * LAMPORTS_PER_SOL);
+    svm.airdrop(&recipient, LAMPORTS_PER_SOL).unwrap();
+    assert_eq!(svm.get_balance(&recipient), Some(preview));
+
+    // identical to the last airdrop, so it would be rejected as a duplicate
+    assert_eq!(svm.preview_airdrop(&recipient, LAMPORTS_PER_SOL), preview);
+    svm.airdrop(&recipient, LAMPORTS_PER_SOL).unwrap_err();
+    assert_eq!(svm.get_balance(&recipient), Some(preview));
+
+    // more than the faucet holds
+    let faucet_balance = svm.get_balance(&svm.airdrop_pubkey()).unwrap();
+    let preview = svm.preview_airdrop(&recipient, faucet_balance);
+    assert_eq!(preview, 2 * LAMPORTS_PER_SOL);
+    svm.airdrop(&recipient, faucet_balance).unwrap_err();
+    assert_eq!(svm.get_balance(&recipient), Some(preview));
+}
+
+#[test_log::test]
+fn test_preview_airdrop_matches_airdrop() {
+    let mut svm = LiteSVM::new();
+    let rent_exempt_minimum = svm.minimum_balance_for_rent_exemption(64);
+    let rich = Pubkey::new_unique();
+    svm.set_account(
+        rich,
+        Account {
+            lamports: u64::MAX - 10,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let rent_paying = Pubkey::new_unique();
+    svm.set_account(
+        rent_paying,
+        Account {
+            lamports: 10,
+            data: vec![0; 64],
+            owner: Pubkey::new_unique(),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    for (pubkey, lamports) in [
+        // a fresh address gets even a single lamport
+        (Pubkey::new_unique(), 1),
+        // the balance would overflow
+        (rich, 100),
+        // an account with data would stay below its rent-exempt minimum
+        (rent_paying, 1),
+        // an account with data reaches its rent-exempt minimum
+        (rent_paying, rent_exempt_minimum),
+    ] {
+        let preview = svm.preview_airdrop(&pubkey, lamports);
+        let before = svm.get_balance(&pubkey).unwrap_or_default();
+        let result = svm.airdrop(&pubkey, lamports);
+        assert_eq!(result.is_ok(), preview != before);
+        assert_eq!(svm.get_balance(&pubkey).unwrap_or_default(), preview);
+    }
+}
+
+#[test_log::test]
+fn test_airdrop_to_program_owned_account() {
+    let mut svm = LiteSVM::new();
+    let address = Pubkey::new_unique();
+    let account = Account {
+        lamports: LAMPORTS_PER_SOL,
+        data: (0..64).collect(),
+        owner: Pubkey::new_unique(),
+        executable: false,
+        rent_epoch: 0,
+    };
+    svm.set_account(address, account.clone()).unwrap();
+
+    svm.airdrop(&address, LAMPORTS_PER_SOL).unwrap();
+
+    assert_eq!(
+        svm.get_account(&address).unwrap(),
+        Account {
+            lamports: 2 * LAMPORTS_PER_SOL,
+            ..account
+        }
+    );
+}
+
+#[test_log::test]
+fn system_seed_instructions() {
+    let base_keypair = Keypair::new();
+    let base = base_keypair.pubkey();
+    let owner = Pubkey::new_unique();
+
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&base, 10 * LAMPORTS_PER_SOL).unwrap();
+    let space = 42;
+    let rent_amount = svm.minimum_balance_for_rent_exemption(space);
+
+    let created = Pubkey::create_with_seed(&base, "created", &owner).unwrap();
+    let allocated = Pubkey::create_with_seed(&base, "allocated", &owner).unwrap();
+    let assigned = Pubkey::create_with_seed(&base, "assigned", &owner).unwrap();
+    let instructions = [
+        create_account_with_seed(
+            &base,
+            &created,
+            &base,
+            "created",
+            rent_amount,
+            space as u64,
+            &owner,
+        ),
+        transfer(&base, &allocated, rent_amount),
+        allocate_with_seed(&allocated, &base, "allocated", space as u64, &owner),
+        transfer(&base, &assigned, rent_amount),
+        assign_with_seed(&assigned, &base, "assigned", &owner),
+    ];
+    let tx = Transaction::new(
+        &[&base_keypair],
+        Message::new(&instructions, Some(&base)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    for (address, expected_len) in [(created, space), (allocated, space), (assigned, 0)] {
+        let account = svm.get_account(&address).unwrap();
+        assert_eq!(account.owner, owner);
+        assert_eq!(account.data.len(), expected_len);
+        assert_eq!(account.lamports, rent_amount);
+    }
+}
+
+#[test_log::test]
+fn system_create_account_with_seed_cpi() {
+    let program_id = pubkey!("9YRHJmvVXvh7B3NfUiw8wA3zr64HncdzEAjnPgegRV9M");
+    let payer_keypair = Keypair::new();
+    let payer = payer_keypair.pubkey();
+
+    let mut svm = LiteSVM::new();
+    let mut so_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    so_path.push("test_programs/target/deploy/create_with_seed.so");
+    svm.add_program_from_file(program_id, &so_path).unwrap();
+    svm.airdrop(&payer, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let seed = "vault";
+    let space: u64 = 128;
+    let lamports = svm.minimum_balance_for_rent_exemption(space as usize);
+    let new_account = Pubkey::create_with_seed(&payer, seed, &program_id).unwrap();
+    let mut data = lamports.to_le_bytes().to_vec();
+    data.extend_from_slice(&space.to_le_bytes());
+    data.extend_from_slice(seed.as_bytes());
+    let instruction = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(new_account, false),
+            AccountMeta::new_readonly(solana_sdk_ids::system_program::id(), false),
+        ],
+        data,
+    };
+    let tx = Transaction::new(
+        &[&payer_keypair],
+        Message::new(&[instruction], Some(&payer)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let account = svm.get_account(&new_account).unwrap();
+    assert_eq!(account.owner, program_id);
+    assert_eq!(account.data.len(), space as usize);
+    assert_eq!(account.lamports, lamports);
+}
+
+#[test_log::test]
+fn test_transfer_many() {
+    let from_keypair = Keypair::new();
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&from_keypair.pubkey(), 10 * LAMPORTS_PER_SOL)
+        .unwrap();
+
+    let recipients: Vec<(Pubkey, u64)> = (1..=5)
+        .map(|i| (Pubkey::new_unique(), i * LAMPORTS_PER_SOL / 10))
+        .collect();
+    let meta = svm.transfer_many(&from_keypair, &recipients).unwrap();
+    assert_eq!(meta.fee, 5000);
+    for (recipient, lamports) in &recipients {
+        assert_eq!(svm.get_balance(recipient), Some(*lamports));
+    }
+
+    // far too many transfers for one packet
+    let recipients: Vec<(Pubkey, u64)> = (0..100).map(|_| (Pubkey::new_unique(), 1)).collect();
+    let err = svm.transfer_many(&from_keypair, &recipients).unwrap_err();
+    assert_eq!(err.err, TransactionError::SanitizeFailure);
+    assert!(svm.get_balance(&recipients[0].0).is_none());
+}
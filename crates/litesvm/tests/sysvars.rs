@@ -0,0 +1,151 @@
+use {
+    litesvm::LiteSVM, solana_account::ReadableAccount, solana_clock::Clock,
+    solana_epoch_schedule::EpochSchedule, solana_keypair::Keypair, solana_message::Message,
+    solana_pubkey::Pubkey, solana_rent::Rent, solana_signer::Signer,
+    solana_system_interface::instruction::create_account, solana_sysvar_id::SysvarId,
+    solana_transaction::Transaction, solana_transaction_error::TransactionError,
+};
+
+#[test_log::test]
+fn test_set_sysvars_bulk() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+
+    let old_minimum = svm.minimum_balance_for_rent_exemption(100);
+    let clock = Clock {
+        slot: 1_000,
+        epoch: 3,
+        unix_timestamp: 1_700_000_000,
+        ..Default::default()
+    };
+    let rent = Rent {
+        lamports_per_byte_year: Rent::default().lamports_per_byte_year * 2,
+        ..Default::default()
+    };
+    let epoch_schedule = EpochSchedule::without_warmup();
+    svm.set_sysvars_bulk(
+        Some(clock.clone()),
+        Some(rent.clone()),
+        Some(epoch_schedule.clone()),
+    );
+
+    assert_eq!(svm.get_sysvar::<Clock>(), clock);
+    assert_eq!(svm.get_sysvar::<Rent>(), rent);
+    assert_eq!(svm.get_sysvar::<EpochSchedule>(), epoch_schedule);
+    let new_minimum = svm.minimum_balance_for_rent_exemption(100);
+    assert_eq!(new_minimum, rent.minimum_balance(100));
+    assert!(new_minimum > old_minimum);
+
+    // the next transaction is checked against the new rent
+    let create = |svm: &LiteSVM, lamports: u64| {
+        let account = Keypair::new();
+        Transaction::new(
+            &[&payer, &account],
+            Message::new(
+                &[create_account(
+                    &payer.pubkey(),
+                    &account.pubkey(),
+                    lamports,
+                    100,
+                    &Pubkey::new_unique(),
+                )],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        )
+    };
+    let err = svm
+        .send_transaction(create(&svm, old_minimum))
+        .unwrap_err()
+        .err;
+    assert_eq!(
+        err,
+        TransactionError::InsufficientFundsForRent { account_index: 1 }
+    );
+    svm.send_transaction(create(&svm, new_minimum)).unwrap();
+    assert_eq!(
+        svm.get_account_modified_slot(&solana_sdk_ids::sysvar::clock::ID),
+        Some(clock.slot)
+    );
+}
+
+#[test_log::test]
+fn test_get_clock() {
+    let mut svm = LiteSVM::new();
+    assert_eq!(svm.get_clock(), svm.get_sysvar::<Clock>());
+
+    let clock = Clock {
+        slot: 1_000,
+        epoch_start_timestamp: 1_700_000_000,
+        epoch: 2,
+        leader_schedule_epoch: 3,
+        unix_timestamp: 1_700_000_400,
+    };
+    svm.set_clock(&clock);
+    assert_eq!(svm.get_clock(), clock);
+    assert_eq!(svm.get_sysvar::<Clock>(), clock);
+}
+
+#[test_log::test]
+fn test_set_clock_partial() {
+    let mut svm = LiteSVM::new();
+    svm.warp_to_slot(1_000);
+    let before = svm.get_clock();
+
+    svm.set_unix_timestamp(1_700_000_000);
+    let after = svm.get_clock();
+    assert_eq!(after.unix_timestamp, 1_700_000_000);
+    assert_eq!(
+        after,
+        Clock {
+            unix_timestamp: 1_700_000_000,
+            ..before
+        }
+    );
+
+    svm.set_slot(2_000);
+    assert_eq!(
+        svm.get_clock(),
+        Clock {
+            slot: 2_000,
+            ..after
+        }
+    );
+}
+
+#[test_log::test]
+fn test_set_sysvars_bulk_partial() {
+    let mut svm = LiteSVM::new();
+    let clock = svm.get_sysvar::<Clock>();
+    let epoch_schedule = svm.get_sysvar::<EpochSchedule>();
+    let rent = Rent::free();
+    svm.set_sysvars_bulk(None, Some(rent.clone()), None);
+
+    assert_eq!(svm.get_sysvar::<Rent>(), rent);
+    assert_eq!(svm.get_sysvar::<Clock>(), clock);
+    assert_eq!(svm.get_sysvar::<EpochSchedule>(), epoch_schedule);
+}
+
+#[test_log::test]
+fn test_sysvar_accounts() {
+    let mut svm = LiteSVM::new();
+    svm.warp_to_slot(100);
+
+    let sysvars = svm.sysvar_accounts();
+    assert!(sysvars.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert!(sysvars
+        .iter()
+        .all(|(_, account)| account.owner() == &solana_sdk_ids::sysvar::ID));
+    let find = |id| {
+        sysvars
+            .iter()
+            .find(|(address, _)| *address == id)
+            .map(|(_, account)| account.data())
+            .unwrap()
+    };
+    let clock: Clock = bincode::deserialize(find(Clock::id())).unwrap();
+    assert_eq!(clock.slot, 100);
+    let rent: Rent = bincode::deserialize(find(Rent::id())).unwrap();
+    assert_eq!(rent, Rent::default());
+}
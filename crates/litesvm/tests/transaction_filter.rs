@@ -0,0 +1,73 @@
+use {
+    litesvm::LiteSVM,
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::transfer,
+    solana_transaction::{versioned::VersionedTransaction, Transaction},
+    solana_transaction_error::TransactionError,
+};
+
+#[test_log::test]
+fn test_transaction_filter() {
+    let payer = Keypair::new();
+    let blacklisted = Pubkey::new_unique();
+    let allowed = Pubkey::new_unique();
+
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    svm.set_transaction_filter(Box::new(move |tx: &VersionedTransaction| {
+        if tx.message.static_account_keys().contains(&blacklisted) {
+            Err(TransactionError::AccountLoadedTwice)
+        } else {
+            Ok(())
+        }
+    }));
+
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(
+            &[transfer(&payer.pubkey(), &blacklisted, 100)],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    let signature = tx.signatures[0];
+    let metrics = svm.metrics();
+    let err = svm.send_transaction(tx).unwrap_err();
+    assert_eq!(err.err, TransactionError::AccountLoadedTwice);
+    assert_eq!(err.meta.signature, signature);
+    assert!(svm.get_account(&blacklisted).is_none());
+    assert!(svm.get_transaction(&signature).is_none());
+    // rejected transactions are counted but not executed
+    assert_eq!(svm.transaction_count(), metrics.transactions + 1);
+    assert_eq!(svm.metrics().instructions, metrics.instructions);
+    assert_eq!(
+        svm.metrics().compute_units_consumed,
+        metrics.compute_units_consumed
+    );
+
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(
+            &[transfer(&payer.pubkey(), &allowed, 100)],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+    assert_eq!(svm.get_balance(&allowed), Some(100));
+
+    svm.clear_transaction_filter();
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(
+            &[transfer(&payer.pubkey(), &blacklisted, 100)],
+            Some(&payer.pubkey()),
+        ),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+    assert_eq!(svm.get_balance(&blacklisted), Some(100));
+}
@@ -1,6 +1,7 @@
 use {
-    litesvm::LiteSVM, solana_keypair::Keypair, solana_pubkey::Pubkey, solana_signer::Signer,
-    solana_system_interface::instruction::transfer, solana_transaction::Transaction,
+    litesvm::LiteSVM, solana_keypair::Keypair, solana_message::Message, solana_pubkey::Pubkey,
+    solana_signer::Signer, solana_system_interface::instruction::transfer,
+    solana_transaction::Transaction, solana_transaction_error::TransactionError,
 };
 
 #[test]
@@ -67,3 +68,59 @@ fn test_tx_history_disable_later() {
     let result = svm.get_transaction(&tx2.signatures[0]);
     assert!(result.is_none(), "Transaction should not be in history");
 }
+
+#[test]
+fn test_transaction_count() {
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Pubkey::new_unique();
+
+    let mut svm = LiteSVM::new().with_transaction_history(0);
+    assert_eq!(svm.transaction_count(), 0);
+    svm.airdrop(&from, 10_000_000).unwrap();
+    assert_eq!(svm.transaction_count(), 1);
+
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[transfer(&from, &to, 100)], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+    assert_eq!(svm.transaction_count(), 2);
+
+    // more than the payer has, so this one fails
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[transfer(&from, &to, 100_000_000)], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap_err();
+    assert_eq!(svm.transaction_count(), 3);
+}
+
+#[test]
+fn test_transaction_dedup() {
+    for dedup in [true, false] {
+        let from_keypair = Keypair::new();
+        let from = from_keypair.pubkey();
+        let to = Pubkey::new_unique();
+
+        let mut svm = LiteSVM::new().with_transaction_dedup(dedup);
+        svm.airdrop(&from, 10_000_000).unwrap();
+
+        let tx = Transaction::new(
+            &[&from_keypair],
+            Message::new(&[transfer(&from, &to, 100)], Some(&from)),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx.clone()).unwrap();
+        let second = svm.send_transaction(tx);
+        if dedup {
+            assert_eq!(second.unwrap_err().err, TransactionError::AlreadyProcessed);
+            assert_eq!(svm.get_balance(&to), Some(100));
+        } else {
+            assert!(second.is_ok());
+            assert_eq!(svm.get_balance(&to), Some(200));
+        }
+    }
+}
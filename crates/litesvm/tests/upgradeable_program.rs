@@ -0,0 +1,131 @@
+use {
+    litesvm::LiteSVM,
+    solana_account::{state_traits::StateMut, AccountSharedData},
+    solana_instruction::{account_meta::AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_loader_v3_interface::state::UpgradeableLoaderState,
+    solana_message::Message,
+    solana_pubkey::Pubkey,
+    solana_sdk_ids::bpf_loader_upgradeable,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+};
+
+#[test_log::test]
+fn test_deploy_upgradeable_program() {
+    let mut svm = LiteSVM::new();
+    let program_id = Pubkey::new_unique();
+    let programdata_address = Pubkey::new_unique();
+    let upgrade_authority = Pubkey::new_unique();
+    let bytes = include_bytes!("../../node-litesvm/program_bytes/spl_example_logging.so");
+    svm.deploy_upgradeable_program(
+        program_id,
+        programdata_address,
+        bytes,
+        Some(upgrade_authority),
+    )
+    .unwrap();
+
+    let program_account = svm.get_account(&program_id).unwrap();
+    assert!(program_account.executable);
+    assert_eq!(program_account.owner, bpf_loader_upgradeable::id());
+    let program_state: UpgradeableLoaderState =
+        AccountSharedData::from(program_account).state().unwrap();
+    assert_eq!(
+        program_state,
+        UpgradeableLoaderState::Program {
+            programdata_address
+        }
+    );
+    let programdata_account = svm.get_account(&programdata_address).unwrap();
+    assert_eq!(programdata_account.owner, bpf_loader_upgradeable::id());
+    let programdata_state: UpgradeableLoaderState = AccountSharedData::from(programdata_account)
+        .state()
+        .unwrap();
+    assert_eq!(
+        programdata_state,
+        UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address: Some(upgrade_authority),
+        }
+    );
+
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let ix = Instruction {
+        program_id,
+        accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+        data: vec![5, 10, 11, 12, 13, 14],
+    };
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(&[ix], Some(&payer.pubkey())),
+        svm.latest_blockhash(),
+    );
+    let meta = svm.send_transaction(tx).unwrap();
+    assert_eq!(meta.logs[1], "Program log: static string");
+}
+
+#[test_log::test]
+fn test_set_upgradeable_program() {
+    let mut source = LiteSVM::new();
+    let program_id = Pubkey::new_unique();
+    let programdata_address = Pubkey::new_unique();
+    let bytes = include_bytes!("../../node-litesvm/program_bytes/spl_example_logging.so");
+    source
+        .deploy_upgradeable_program(program_id, programdata_address, bytes, None)
+        .unwrap();
+    let program = source.get_account(&program_id).unwrap();
+    let programdata = source.get_account(&programdata_address).unwrap();
+
+    let mut svm = LiteSVM::new();
+    // the programdata account is not a program account
+    assert!(svm
+        .set_upgradeable_program(program_id, programdata.clone(), programdata.clone())
+        .is_err());
+    svm.set_upgradeable_program(program_id, program.clone(), programdata.clone())
+        .unwrap();
+    assert_eq!(svm.get_account(&program_id).unwrap(), program);
+    assert_eq!(svm.get_account(&programdata_address).unwrap(), programdata);
+
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let ix = Instruction {
+        program_id,
+        accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+        data: vec![5, 10, 11, 12, 13, 14],
+    };
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(&[ix], Some(&payer.pubkey())),
+        svm.latest_blockhash(),
+    );
+    let meta = svm.send_transaction(tx).unwrap();
+    assert_eq!(meta.logs[1], "Program log: static string");
+}
+
+#[test_log::test]
+fn test_warm_program_cache() {
+    let mut svm = LiteSVM::new();
+    let program_id = Pubkey::new_unique();
+    let bytes = include_bytes!("../../node-litesvm/program_bytes/spl_example_logging.so");
+    svm.deploy_upgradeable_program(program_id, Pubkey::new_unique(), bytes, None)
+        .unwrap();
+    svm.warm_program_cache().unwrap();
+    // warming is idempotent
+    svm.warm_program_cache().unwrap();
+
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let ix = Instruction {
+        program_id,
+        accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+        data: vec![],
+    };
+    let tx = Transaction::new(
+        &[&payer],
+        Message::new(&[ix], Some(&payer.pubkey())),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+}
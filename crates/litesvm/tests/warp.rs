@@ -0,0 +1,167 @@
+use {
+    litesvm::LiteSVM,
+    solana_clock::Clock,
+    solana_epoch_schedule::EpochSchedule,
+    solana_sdk_ids::sysvar,
+    solana_slot_hashes::{SlotHashes, MAX_ENTRIES},
+};
+
+#[test_log::test]
+fn test_warp_forward_seconds() {
+    let mut svm = LiteSVM::new();
+    let before = svm.get_sysvar::<Clock>();
+
+    svm.warp_forward_seconds(3600);
+
+    let after = svm.get_sysvar::<Clock>();
+    assert_eq!(after.unix_timestamp - before.unix_timestamp, 3600);
+    // 3600s at 400ms per slot
+    assert_eq!(after.slot - before.slot, 9000);
+}
+
+#[test_log::test]
+fn test_warp_forward_seconds_custom_slot_duration() {
+    let mut svm = LiteSVM::new().with_slot_duration_ms(1000);
+    let before = svm.get_sysvar::<Clock>();
+
+    svm.warp_forward_seconds(60);
+
+    let after = svm.get_sysvar::<Clock>();
+    assert_eq!(after.unix_timestamp - before.unix_timestamp, 60);
+    assert_eq!(after.slot - before.slot, 60);
+}
+
+#[test_log::test]
+fn test_warp_forward_seconds_in_small_steps() {
+    let mut svm = LiteSVM::new();
+    let before = svm.get_sysvar::<Clock>();
+
+    for _ in 0..10 {
+        svm.warp_forward_seconds(1);
+    }
+
+    let after = svm.get_sysvar::<Clock>();
+    assert_eq!(after.unix_timestamp - before.unix_timestamp, 10);
+    // the 200ms left over from each step adds up instead of being dropped
+    assert_eq!(after.slot - before.slot, 25);
+}
+
+#[test_log::test]
+fn test_warp_to_slot_derives_timestamp() {
+    let mut svm = LiteSVM::new();
+    let before = svm.get_sysvar::<Clock>();
+
+    svm.warp_to_slot(before.slot + 150);
+
+    let after = svm.get_sysvar::<Clock>();
+    // 150 slots at 400ms per slot
+    assert_eq!(after.unix_timestamp - before.unix_timestamp, 60);
+
+    let mut svm = LiteSVM::new().with_slot_duration_ms(1000);
+    svm.warp_to_slot(before.slot + 150);
+    let after = svm.get_sysvar::<Clock>();
+    assert_eq!(after.unix_timestamp - before.unix_timestamp, 150);
+
+    // an explicitly set clock is left alone
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = 42;
+    svm.set_sysvar(&clock);
+    assert_eq!(svm.get_sysvar::<Clock>().unix_timestamp, 42);
+}
+
+#[test_log::test]
+fn test_warp_to_slot_fills_slot_hashes() {
+    let mut svm = LiteSVM::new();
+    svm.warp_to_slot(1000);
+
+    let slot_hashes = svm.get_sysvar::<SlotHashes>();
+    assert_eq!(slot_hashes.len(), MAX_ENTRIES);
+    assert_eq!(slot_hashes.first().unwrap().0, 999);
+    assert_eq!(slot_hashes.last().unwrap().0, 1000 - MAX_ENTRIES as u64);
+    assert_eq!(
+        slot_hashes.get(&999),
+        Some(&solana_sha256_hasher::hash(&999u64.to_le_bytes()))
+    );
+
+    // the same on every run
+    let mut other = LiteSVM::new();
+    other.warp_to_slot(1000);
+    assert_eq!(other.get_sysvar::<SlotHashes>(), slot_hashes);
+
+    // warping back drops the entries that are now in the future
+    svm.warp_to_slot(900);
+    let slot_hashes = svm.get_sysvar::<SlotHashes>();
+    assert_eq!(slot_hashes.first().unwrap().0, 899);
+    assert!(slot_hashes.get(&999).is_none());
+}
+
+#[test_log::test]
+fn test_warp_derives_clock_epochs() {
+    let mut svm = LiteSVM::new();
+    svm.set_sysvar(&EpochSchedule::custom(100, 100, false));
+
+    svm.warp_to_slot(250);
+    let clock = svm.get_clock();
+    assert_eq!(clock.epoch, 2);
+    assert_eq!(clock.leader_schedule_epoch, 3);
+    // programs reading the sysvar account see the same values
+    let account = svm.get_account(&sysvar::clock::ID).unwrap();
+    assert_eq!(bincode::deserialize::<Clock>(&account.data).unwrap(), clock);
+
+    svm.warp_forward_seconds(40);
+    let clock = svm.get_clock();
+    assert_eq!(clock.slot, 350);
+    assert_eq!(clock.epoch, 3);
+    assert_eq!(clock.leader_schedule_epoch, 4);
+
+    // the default schedule starts with short warmup epochs
+    let mut svm = LiteSVM::new();
+    let epoch_schedule = svm.get_sysvar::<EpochSchedule>();
+    svm.warp_to_slot(1_000);
+    let clock = svm.get_clock();
+    assert_eq!(clock.epoch, epoch_schedule.get_epoch(1_000));
+    assert_eq!(
+        clock.leader_schedule_epoch,
+        epoch_schedule.get_leader_schedule_epoch(1_000)
+    );
+    assert_eq!(clock.leader_schedule_epoch, clock.epoch + 1);
+}
+
+#[test_log::test]
+fn test_reset_clock() {
+    let mut svm = LiteSVM::new();
+    let initial = svm.get_sysvar::<Clock>();
+
+    svm.warp_to_slot(1_000);
+    svm.warp_forward_seconds(3600);
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.epoch = 5;
+    svm.set_sysvar(&clock);
+    assert_ne!(svm.get_sysvar::<Clock>(), initial);
+
+    svm.reset_clock();
+    assert_eq!(svm.get_sysvar::<Clock>(), initial);
+}
+
+#[test_log::test]
+fn test_reset_clock_to_initial_clock() {
+    let initial = Clock {
+        slot: 50,
+        epoch_start_timestamp: 1_700_000_000,
+        epoch: 1,
+        leader_schedule_epoch: 2,
+        unix_timestamp: 1_700_000_020,
+    };
+    let mut svm = LiteSVM::new().with_initial_clock(initial.clone());
+    assert_eq!(svm.get_sysvar::<Clock>(), initial);
+
+    svm.warp_to_slot(500);
+    svm.reset_clock();
+    assert_eq!(svm.get_sysvar::<Clock>(), initial);
+
+    // the starting clock also survives reinstalling the default sysvars
+    let svm = LiteSVM::default()
+        .with_initial_clock(initial.clone())
+        .with_sysvars();
+    assert_eq!(svm.get_sysvar::<Clock>(), initial);
+}
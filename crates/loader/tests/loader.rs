@@ -6,8 +6,10 @@ use {
     solana_instruction::{account_meta::AccountMeta, Instruction},
     solana_keypair::Keypair,
     solana_message::Message,
+    solana_sdk_ids::bpf_loader_upgradeable,
     solana_signer::Signer,
     solana_transaction::Transaction,
+    solana_transaction_error::TransactionError,
 };
 
 mod programs_bytes;
@@ -79,3 +81,33 @@ fn hello_world_with_deploy_upgradeable() {
     )
     .unwrap();
 }
+
+#[test_log::test]
+fn deploy_upgradeable_with_loader_disabled() {
+    for enabled in [true, false] {
+        let mut svm = LiteSVM::default()
+            .with_feature_set(FeatureSet::all_enabled())
+            .with_upgradeable_loader_enabled(enabled)
+            .with_builtins()
+            .with_lamports(1_000_000_000_000_000)
+            .with_sysvars();
+        let payer_kp = Keypair::new();
+        svm.airdrop(&payer_kp.pubkey(), 10000000000).unwrap();
+
+        let program_keypair = Keypair::new();
+        let result =
+            deploy_upgradeable_program(&mut svm, &payer_kp, &program_keypair, HELLO_WORLD_BYTES);
+        if enabled {
+            result.unwrap();
+            assert!(svm.get_account(&program_keypair.pubkey()).is_some());
+        } else {
+            // creating the buffer already invokes the missing loader
+            assert_eq!(
+                result.unwrap_err().err,
+                TransactionError::InvalidProgramForExecution
+            );
+            assert!(svm.get_account(&bpf_loader_upgradeable::id()).is_none());
+            assert!(svm.get_account(&program_keypair.pubkey()).is_none());
+        }
+    }
+}
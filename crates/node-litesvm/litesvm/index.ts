@@ -1,47 +1,81 @@
 import {
 	Account,
+	AccountSummary,
 	AddressAndAccount,
+	AddressLookupTable,
 	Clock,
 	ComputeBudget,
 	EpochRewards,
 	EpochSchedule,
 	FailedTransactionMetadata,
 	FeatureSet,
+	FeeBreakdown,
+	FeeRateGovernor,
+	MintInfo,
+	PrioritizationFee,
 	SimulatedTransactionInfo as SimulatedTransactionInfoInner,
 	LiteSvm as LiteSVMInner,
 	Rent,
+	SerializableSimulationInfo,
 	SlotHash,
 	SlotHistory,
+	StakeActivation,
 	StakeHistory,
+	SvmMetrics,
 	TransactionMetadata,
+	createProgramAddress as createProgramAddressInner,
+	messageHash as messageHashInner,
 } from "./internal";
 export {
 	Account,
+	AccountSummary,
+	AddressLookupTable,
 	Clock,
 	ComputeBudget,
 	EpochRewards,
 	EpochSchedule,
+	ExecutionTrace,
 	FailedTransactionMetadata,
 	FeatureSet,
+	FeeBreakdown,
+	FeeRateGovernor,
 	InnerInstruction,
+	MintInfo,
+	PrioritizationFee,
 	Rent,
+	SerializableSimulationInfo,
 	SlotHash,
 	SlotHistory,
 	SlotHistoryCheck,
+	StakeActivation,
 	StakeHistory,
 	StakeHistoryEntry,
+	SvmMetrics,
+	TokenBalance,
 	TransactionMetadata,
 	TransactionReturnData,
 } from "./internal";
 import {
 	AccountInfo,
+	Keypair,
+	Message,
 	PublicKey,
 	Transaction,
+	TransactionInstruction,
+	VersionedMessage,
 	VersionedTransaction,
 } from "@solana/web3.js";
 
 export type AccountInfoBytes = AccountInfo<Uint8Array>;
 
+export type ProgramKind =
+	| "Builtin"
+	| "BpfLoaderDeprecated"
+	| "BpfLoaderV2"
+	| "BpfLoaderUpgradeable"
+	| "LoaderV4"
+	| "Precompile";
+
 function toAccountInfo(acc: Account): AccountInfoBytes {
 	const owner = new PublicKey(acc.owner());
 	return {
@@ -53,15 +87,18 @@ function toAccountInfo(acc: Account): AccountInfoBytes {
 	};
 }
 
+const U64_MAX = 2n ** 64n - 1n;
+
 function fromAccountInfo(acc: AccountInfoBytes): Account {
 	const maybeRentEpoch = acc.rentEpoch;
-	const rentEpoch = maybeRentEpoch || 0;
+	// u64::MAX, the rent epoch of rent-exempt accounts, rounds up to 2^64 as a number
+	const rentEpoch = BigInt(maybeRentEpoch || 0);
 	return new Account(
 		BigInt(acc.lamports),
 		acc.data,
 		acc.owner.toBytes(),
 		acc.executable,
-		BigInt(rentEpoch),
+		rentEpoch > U64_MAX ? U64_MAX : rentEpoch,
 	);
 }
 
@@ -71,6 +108,40 @@ function convertAddressAndAccount(
 	return [new PublicKey(val.address), val.account()];
 }
 
+/**
+ * Derives a program address from seeds and an explicit bump seed,
+ * exactly like the runtime's `create_program_address`.
+ * @param seeds - The seeds, not including the bump.
+ * @param bump - The bump seed.
+ * @param programId - The program that owns the address.
+ * @returns The derived address. Throws if the seeds and bump produce an address on the ed25519 curve.
+ */
+export function createProgramAddress(
+	seeds: Uint8Array[],
+	bump: number,
+	programId: PublicKey,
+): PublicKey {
+	return new PublicKey(
+		createProgramAddressInner(seeds, bump, programId.toBytes()),
+	);
+}
+
+/**
+ * Returns the hash the runtime identifies a message by, e.g. to recognize
+ * a replayed transaction. Messages differing in any byte hash differently.
+ * @param message - The message, or its serialized form encoded as base64.
+ * @returns The base58-encoded hash, or null if the message is invalid.
+ */
+export function messageHash(
+	message: Message | VersionedMessage | string,
+): string | null {
+	const serialized =
+		typeof message === "string"
+			? Buffer.from(message, "base64")
+			: message.serialize();
+	return messageHashInner(serialized);
+}
+
 export class SimulatedTransactionInfo {
 	constructor(inner: SimulatedTransactionInfoInner) {
 		this.inner = inner;
@@ -82,6 +153,21 @@ export class SimulatedTransactionInfo {
 	postAccounts(): [PublicKey, Account][] {
 		return this.inner.postAccounts().map(convertAddressAndAccount);
 	}
+	/** Compute units consumed by the simulation. */
+	unitsConsumed(): bigint {
+		return this.inner.unitsConsumed();
+	}
+	/**
+	 * The units consumed, logs and return data as a plain object, with
+	 * `unitsConsumed` as a number.
+	 */
+	toSerializable(): SerializableSimulationInfo {
+		return this.inner.toSerializable();
+	}
+	/** Always true, so a simulation result can be checked without `instanceof`. */
+	success(): boolean {
+		return true;
+	}
 }
 
 /**
@@ -105,6 +191,31 @@ export class LiteSVM {
 		return svm;
 	}
 
+	/**
+	 * Create a new LiteSVM instance with standard functionality enabled and
+	 * the clock starting at the given slot. The epoch follows from the epoch
+	 * schedule; the unix timestamp is left unchanged.
+	 * @param slot - The starting slot.
+	 * @returns The new LiteSVM instance
+	 */
+	static createAtSlot(slot: bigint): LiteSVM {
+		const svm = new LiteSVM();
+		svm.inner = LiteSVMInner.createAtSlot(slot);
+		return svm;
+	}
+
+	/**
+	 * Create an independent copy of this instance.
+	 * The copy starts with the same accounts, sysvars and configuration,
+	 * but changes made to either instance do not affect the other.
+	 * @returns The forked LiteSVM instance
+	 */
+	fork(): LiteSVM {
+		const svm = new LiteSVM();
+		svm.inner = this.inner.fork();
+		return svm;
+	}
+
 	/**
 	 * Set the compute budget
 	 * @param budget - The new compute budget
@@ -125,6 +236,18 @@ export class LiteSVM {
 		return this;
 	}
 
+	/**
+	 * Enable or disable signature verification in precompile instructions,
+	 * such as ed25519 and secp256k1. Enabled by default.
+	 * @param enabled - if false, precompile instructions always succeed while
+	 * the rest of the transaction still runs.
+	 * @returns The modified LiteSVM instance
+	 */
+	withSigverifyPrecompiles(enabled: boolean): LiteSVM {
+		this.inner.setSigverifyPrecompiles(enabled);
+		return this;
+	}
+
 	/**
 	 * Enables or disables transaction blockhash checking.
 	 * @param check - If false, the blockhash check will be skipped
@@ -173,6 +296,67 @@ export class LiteSVM {
 		return this;
 	}
 
+	/**
+	 * Derives the faucet keypair from a seed, so its `airdropPubkey()` is the
+	 * same in every instance built with that seed. Lamports already in the faucet
+	 * move to the new faucet account. Without this, the faucet keypair is random.
+	 * @param seed - The 32-byte secret key seed
+	 * @returns The modified LiteSVM instance
+	 */
+	withFaucetSeed(seed: Uint8Array): LiteSVM {
+		this.inner.setFaucetSeed(seed);
+		return this;
+	}
+
+	/**
+	 * Derives everything LiteSVM would otherwise pick on its own from a seed:
+	 * the genesis hash and blockhash chain, the faucet keypair, the slot hashes
+	 * filled in by warps and the program IDs generated by `deployProgram()`.
+	 * Two instances with the same seed that perform the same operations end up
+	 * in identical states, as long as the keypairs used are deterministic too.
+	 * Call this before sending any transactions.
+	 * @param seed - The seed, as a u64.
+	 * @returns The modified LiteSVM instance
+	 */
+	withRngSeed(seed: bigint): LiteSVM {
+		this.inner.setRngSeed(seed);
+		return this;
+	}
+
+	/**
+	 * Changes the base fee charged per signature. Defaults to 5000 lamports.
+	 * @param lamportsPerSignature - The fee per signature, in lamports
+	 * @returns The modified LiteSVM instance
+	 */
+	withLamportsPerSignature(lamportsPerSignature: bigint): LiteSVM {
+		this.inner.setLamportsPerSignature(lamportsPerSignature);
+		return this;
+	}
+
+	/**
+	 * Changes for how many blocks, produced with `finalizeBlock` or by warping
+	 * with `expireBlockhashes`, a blockhash is accepted. Defaults to 150.
+	 * `expireBlockhash` ignores the window.
+	 * @param slots - How many blocks a blockhash is accepted for, counting the block it is the latest blockhash of.
+	 * @returns The modified LiteSVM instance
+	 */
+	withBlockhashExpirySlots(slots: bigint): LiteSVM {
+		this.inner.setBlockhashExpirySlots(slots);
+		return this;
+	}
+
+	/**
+	 * Rejects transactions whose blockhash is more than `slots` blocks old,
+	 * even if it is still within the expiry window set with
+	 * `withBlockhashExpirySlots`. This models a client with a stricter deadline.
+	 * @param slots - How many blocks old a blockhash may be.
+	 * @returns The modified LiteSVM instance
+	 */
+	withMaxBlockhashAge(slots: bigint): LiteSVM {
+		this.inner.setMaxBlockhashAge(slots);
+		return this;
+	}
+
 	/**
 	 * Adds the standard SPL programs.
 	 * @returns The modified LiteSVM instance
@@ -182,6 +366,27 @@ export class LiteSVM {
 		return this;
 	}
 
+	/**
+	 * Adds the SPL Associated Token Account program.
+	 * This is already part of the default programs, but can be added on its own.
+	 * @returns The modified LiteSVM instance
+	 */
+	withSplAssociatedTokenAccountProgram(): LiteSVM {
+		this.inner.setSplAssociatedTokenAccountProgram();
+		return this;
+	}
+
+	/**
+	 * Adds the Config program. It is no longer a builtin, so `withBuiltins`
+	 * does not add it. This is already part of the default programs, but can
+	 * be added on its own.
+	 * @returns The modified LiteSVM instance
+	 */
+	withConfigProgram(): LiteSVM {
+		this.inner.setConfigProgram();
+		return this;
+	}
+
 	/**
 	 * Changes the capacity of the transaction history.
 	 * @param capacity - How many transactions to store in history.
@@ -193,8 +398,22 @@ export class LiteSVM {
 		return this;
 	}
 
+	/**
+	 * Enables or disables rejecting transactions whose signature is already
+	 * in the transaction history. Enabled by default; only applies when
+	 * sigverify is on. Unlike `withTransactionHistory(0n)`, disabling this
+	 * keeps recording transactions in the history.
+	 * @param enabled - Whether to reject duplicate transactions.
+	 * @returns The modified LiteSVM instance
+	 */
+	withTransactionDedup(enabled: boolean): LiteSVM {
+		this.inner.setTransactionDedup(enabled);
+		return this;
+	}
+
 	/**
 	 * Set a limit for transaction logs, beyond which they will be truncated.
+	 * `TransactionMetadata.logsTruncated()` tells whether a transaction hit it.
 	 * @param limit - The limit in bytes. If null, no limit is enforced.
 	 * @returns The modified LiteSVM instance
 	 */
@@ -203,6 +422,90 @@ export class LiteSVM {
 		return this;
 	}
 
+	/**
+	 * Changes how long a slot lasts when converting between time and slots.
+	 * @param slotDurationMs - The slot duration in milliseconds. Defaults to 400.
+	 * @returns The modified LiteSVM instance
+	 */
+	withSlotDurationMs(slotDurationMs: bigint): LiteSVM {
+		this.inner.setSlotDurationMs(slotDurationMs);
+		return this;
+	}
+
+	/**
+	 * Caps how deeply instructions may nest cross-program invocations.
+	 * Invoking past the limit fails with a `CallDepth` instruction error.
+	 * @param depth - The number of nested CPIs allowed below the top-level instruction.
+	 * Defaults to the runtime limit (8 with all features enabled).
+	 * @returns The modified LiteSVM instance
+	 */
+	withMaxCpiDepth(depth: number): LiteSVM {
+		this.inner.setMaxCpiDepth(depth);
+		return this;
+	}
+
+	/**
+	 * Enables or disables rent collection. Disabled by default, as on mainnet.
+	 * When enabled, accounts below the rent-exempt minimum are charged rent
+	 * whenever the clock is warped forward or a transaction writes to them.
+	 * @param enabled - Whether to collect rent.
+	 * @returns The modified LiteSVM instance
+	 */
+	withRentCollection(enabled: boolean): LiteSVM {
+		this.inner.setRentCollection(enabled);
+		return this;
+	}
+
+	/**
+	 * Enables or disables rejecting transactions that leave a newly created or
+	 * resized account below the rent-exempt minimum. Enabled by default, as on
+	 * mainnet. The fee payer must stay rent-exempt either way.
+	 * @param enabled - Whether to require rent exemption.
+	 * @returns The modified LiteSVM instance
+	 */
+	withRequireRentExempt(enabled: boolean): LiteSVM {
+		this.inner.setRequireRentExempt(enabled);
+		return this;
+	}
+
+	/**
+	 * Enables or disables recording the balances of the SPL Token and Token-2022
+	 * accounts a transaction references, before and after it runs, as in the
+	 * `preTokenBalances` and `postTokenBalances` of RPC transaction responses.
+	 * Disabled by default.
+	 * @param enabled - Whether to record token balances.
+	 * @returns The modified LiteSVM instance
+	 */
+	withTokenBalanceRecording(enabled: boolean): LiteSVM {
+		this.inner.setTokenBalanceRecording(enabled);
+		return this;
+	}
+
+	/**
+	 * Enables or disables recording the call graph of each transaction in
+	 * `TransactionMetadata.executionTrace()`, with the program, logs, compute
+	 * units and return data of every instruction, including CPIs.
+	 * Disabled by default.
+	 * @param enabled - Whether to record execution traces.
+	 * @returns The modified LiteSVM instance
+	 */
+	withExecutionTrace(enabled: boolean): LiteSVM {
+		this.inner.setExecutionTrace(enabled);
+		return this;
+	}
+
+	/**
+	 * Enables or disables recording how many compute units were left after
+	 * each top-level instruction in `TransactionMetadata.computeUnitsRemaining()`,
+	 * to see how much headroom a program had. Disabled by default.
+	 * @param enabled - Whether to record the remaining compute units.
+	 * @returns The modified LiteSVM instance
+	 */
+	withCaptureComputeUnitsRemaining(enabled: boolean): LiteSVM {
+		this.inner.setCaptureComputeUnitsRemaining(enabled);
+		return this;
+	}
+
 	/**
 	 * Adds the standard precompiles. Use `withFeatureSet` beforehand to change change what builtins are added.
 	 * @returns The modified LiteSVM instance
@@ -212,6 +515,17 @@ export class LiteSVM {
 		return this;
 	}
 
+	/**
+	 * Returns the precompiles transactions can currently use: those whose
+	 * account is installed and whose feature is active.
+	 * @returns The precompile program IDs.
+	 */
+	getInstalledPrecompiles(): PublicKey[] {
+		return this.inner
+			.getInstalledPrecompiles()
+			.map((programId) => new PublicKey(programId));
+	}
+
 	/**
 	 * Calculates the minimum balance required to make an account with specified data length rent exempt.
 	 * @param dataLen - The number of bytes in the account.
@@ -221,6 +535,15 @@ export class LiteSVM {
 		return this.inner.minimumBalanceForRentExemption(dataLen);
 	}
 
+	/**
+	 * Returns the minimum stake delegation, which depends on whether
+	 * `stake_raise_minimum_delegation_to_1_sol` is active.
+	 * @returns The minimum delegation in lamports.
+	 */
+	getMinimumDelegation(): bigint {
+		return this.inner.getMinimumDelegation();
+	}
+
 	/**
 	 * Return the account at the given address.
 	 * If the account is not found, None is returned.
@@ -232,6 +555,108 @@ export class LiteSVM {
 		return inner === null ? null : toAccountInfo(inner);
 	}
 
+	/**
+	 * Return the account at the given address, or an empty, zero-lamport,
+	 * system-owned account if it is not found.
+	 * A missing account looks the same as a real empty one, so check the
+	 * lamports and owner, or use `getAccount()`, where the difference matters.
+	 * @param address - The account address to look up.
+	 * @returns The account object, never null.
+	 */
+	getAccountOrDefault(address: PublicKey): AccountInfoBytes {
+		return toAccountInfo(this.inner.getAccountOrDefault(address.toBytes()));
+	}
+
+	/**
+	 * Return the lamports, data length, owner and executable flag of the account
+	 * at the given address, without copying its data.
+	 * @param address - The account address to look up.
+	 * @returns The account summary, if the account exists.
+	 */
+	getAccountSummary(address: PublicKey): AccountSummary | null {
+		return this.inner.getAccountSummary(address.toBytes());
+	}
+
+	/**
+	 * Return the length of the data of the account at the given address,
+	 * without copying the data.
+	 * @param address - The account address to look up.
+	 * @returns The data length in bytes, if the account exists.
+	 */
+	getAccountDataLen(address: PublicKey): bigint | null {
+		return this.inner.getAccountDataLen(address.toBytes());
+	}
+
+	/**
+	 * Return whether the account at the given address is executable,
+	 * without copying the account.
+	 * @param address - The account address to look up.
+	 * @returns The executable flag, if the account exists.
+	 */
+	isExecutable(address: PublicKey): boolean | null {
+		return this.inner.isExecutable(address.toBytes());
+	}
+
+	/**
+	 * Return the decimals, supply and authorities of an SPL Token or Token-2022 mint.
+	 * Only the base mint layout is read, so Token-2022 extensions are ignored.
+	 * @param mint - The mint address.
+	 * @returns The mint info, if there is an initialized mint at that address.
+	 */
+	getMintInfo(mint: PublicKey): MintInfo | null {
+		return this.inner.getMintInfo(mint.toBytes());
+	}
+
+	/**
+	 * Return the total supply of an SPL Token or Token-2022 mint.
+	 * @param mint - The mint address.
+	 * @returns The supply, if there is an initialized mint at that address.
+	 */
+	getTokenSupply(mint: PublicKey): bigint | null {
+		return this.inner.getTokenSupply(mint.toBytes());
+	}
+
+	/**
+	 * Return the state of an address lookup table: its authority (null once
+	 * frozen), deactivation slot (null while active) and addresses, in table order.
+	 * @param address - The lookup table address.
+	 * @returns The lookup table, if there is one at that address.
+	 */
+	getAddressLookupTable(address: PublicKey): AddressLookupTable | null {
+		return this.inner.getAddressLookupTable(address.toBytes());
+	}
+
+	/**
+	 * Return the activation of a stake account at the current epoch, computed
+	 * from its delegation and the StakeHistory sysvar.
+	 * The `state` is one of "activating", "active", "deactivating" or
+	 * "inactive", and `active` and `inactive` are in lamports.
+	 * @param stakeAccount - The stake account address.
+	 * @returns The activation, if there is an initialized stake account at that address.
+	 */
+	getStakeActivation(stakeAccount: PublicKey): StakeActivation | null {
+		return this.inner.getStakeActivation(stakeAccount.toBytes());
+	}
+
+	/**
+	 * Return the slot at which the account was last written, either by a
+	 * transaction or by `setAccount()` and friends.
+	 * @param address - The account address to look up.
+	 * @returns The slot, or null if the account does not exist.
+	 */
+	getAccountModifiedSlot(address: PublicKey): bigint | null {
+		return this.inner.getAccountModifiedSlot(address.toBytes());
+	}
+
+	/**
+	 * Return how the program at the given address is executed.
+	 * @param programId - The program address.
+	 * @returns The program kind, or null if there is no executable account there.
+	 */
+	programKind(programId: PublicKey): ProgramKind | null {
+		return this.inner.programKind(programId.toBytes()) as ProgramKind | null;
+	}
+
 	/**
 	 * Create or overwrite an account, subverting normal runtime checks.
 	 *
@@ -247,6 +672,127 @@ export class LiteSVM {
 		this.inner.setAccount(address.toBytes(), fromAccountInfo(account));
 	}
 
+	/**
+	 * Create or overwrite many accounts in one call, as if by `setAccount()`.
+	 *
+	 * Every entry is validated before any is applied, so if one fails nothing
+	 * changes and the error names the failing entry. Programs are loaded as
+	 * their entry is reached, so put program data accounts first.
+	 *
+	 * @param accounts - The addresses and account objects to write, in order.
+	 */
+	setAccounts(accounts: [PublicKey, AccountInfoBytes][]) {
+		this.inner.setAccounts(
+			accounts.map(([address]) => address.toBytes()),
+			accounts.map(([, account]) => fromAccountInfo(account)),
+		);
+	}
+
+	/**
+	 * Create an uninitialized account at a program derived address, to test
+	 * a program's initialize path. The account has `space` zeroed bytes, is
+	 * owned by the program and is funded rent-exempt by the payer, without
+	 * sending a transaction.
+	 *
+	 * Throws if the account already exists, or if the payer can't fund it and
+	 * stay rent-exempt.
+	 *
+	 * @param programId - The program that owns the account.
+	 * @param seeds - The seeds the address is derived from.
+	 * @param space - The data length.
+	 * @param payer - The account that funds it.
+	 * @returns The derived address.
+	 */
+	createPdaAccount(
+		programId: PublicKey,
+		seeds: Uint8Array[],
+		space: number,
+		payer: Keypair,
+	): PublicKey {
+		return new PublicKey(
+			this.inner.createPdaAccount(
+				programId.toBytes(),
+				seeds,
+				space,
+				payer.secretKey,
+			),
+		);
+	}
+
+	/**
+	 * Overwrite part of an existing account's data in place.
+	 *
+	 * Throws if the account does not exist or if the write would go past
+	 * the end of the account data.
+	 *
+	 * @param address - The account address.
+	 * @param offset - The byte offset to start writing at.
+	 * @param bytes - The bytes to write.
+	 */
+	setAccountData(address: PublicKey, offset: bigint, bytes: Uint8Array) {
+		this.inner.setAccountData(address.toBytes(), offset, bytes);
+	}
+
+	/**
+	 * Create an initialized, rent-exempt SPL Token mint with no supply.
+	 *
+	 * Throws an "already exists" error if an account is stored at the mint address.
+	 *
+	 * @param mint - The mint address.
+	 * @param mintAuthority - The mint authority.
+	 * @param decimals - The number of decimals.
+	 */
+	createTokenMint(mint: PublicKey, mintAuthority: PublicKey, decimals: number) {
+		this.inner.createTokenMint(mint.toBytes(), mintAuthority.toBytes(), decimals);
+	}
+
+	/**
+	 * Create an initialized, rent-exempt durable nonce account.
+	 *
+	 * The stored nonce comes from the latest blockhash, so expire the blockhash
+	 * before advancing it. Throws an "already exists" error if an account is
+	 * stored at the nonce address.
+	 *
+	 * @param nonceAccount - The nonce account address.
+	 * @param authority - The nonce authority.
+	 */
+	createNonceAccount(nonceAccount: PublicKey, authority: PublicKey) {
+		this.inner.createNonceAccount(nonceAccount.toBytes(), authority.toBytes());
+	}
+
+	/**
+	 * Create an active, rent-exempt address lookup table whose addresses are usable immediately.
+	 *
+	 * Throws an "already exists" error if an account is stored at the table address.
+	 *
+	 * @param address - The lookup table address.
+	 * @param authority - The lookup table authority.
+	 * @param addresses - The addresses stored in the table.
+	 */
+	createAddressLookupTable(
+		address: PublicKey,
+		authority: PublicKey,
+		addresses: PublicKey[],
+	) {
+		this.inner.createAddressLookupTable(
+			address.toBytes(),
+			authority.toBytes(),
+			addresses.map((a) => a.toBytes()),
+		);
+	}
+
+	/**
+	 * Set the lamports of an existing account, leaving its data and owner untouched.
+	 *
+	 * Throws if the account does not exist. Zero lamports removes the account.
+	 *
+	 * @param address - The account address.
+	 * @param lamports - The new balance in lamports.
+	 */
+	setAccountLamports(address: PublicKey, lamports: bigint) {
+		this.inner.setAccountLamports(address.toBytes(), lamports);
+	}
+
 	/**
 	 * Gets the balance of the provided account address.
 	 * @param address - The account address.
@@ -265,6 +811,178 @@ export class LiteSVM {
 		return this.inner.latestBlockhash();
 	}
 
+	/**
+	 * Gets the latest blockhash along with the last block height at which it
+	 * is still accepted, like the RPC `getLatestBlockhash`. Each `finalizeBlock()`
+	 * brings the block height one step closer to it.
+	 * @returns The latest blockhash and its last valid block height.
+	 */
+	latestBlockhashInfo(): {
+		blockhash: string;
+		lastValidBlockHeight: bigint;
+	} {
+		return {
+			blockhash: this.inner.latestBlockhash(),
+			lastValidBlockHeight: this.inner.latestBlockhashLastValidBlockHeight(),
+		};
+	}
+
+	/**
+	 * Gets the genesis hash, which identifies the cluster.
+	 * Defaults to the SHA-256 hash of "genesis", which is also the initial blockhash.
+	 * @returns The genesis hash.
+	 */
+	getGenesisHash(): string {
+		return this.inner.getGenesisHash();
+	}
+
+	/**
+	 * Sets the genesis hash, e.g. to pin the identity of a specific cluster.
+	 * @param hash - The new genesis hash, base58-encoded.
+	 */
+	setGenesisHash(hash: string) {
+		this.inner.setGenesisHash(hash);
+	}
+
+	/**
+	 * Returns the fee that would be charged for a message, including the
+	 * prioritization fee requested by any ComputeBudget instructions.
+	 * Mirrors the `getFeeForMessage` RPC method.
+	 * @param message - The message, or its serialized form encoded as base64.
+	 * @returns The fee in lamports, or null if the message is invalid.
+	 */
+	getFeeForMessage(
+		message: Message | VersionedMessage | string,
+	): bigint | null {
+		const serialized =
+			typeof message === "string"
+				? Buffer.from(message, "base64")
+				: message.serialize();
+		return this.inner.getFeeForMessage(serialized);
+	}
+
+	/**
+	 * Returns the accounts a transaction would lock for writing and for reading,
+	 * without executing it. Addresses from lookup tables are resolved against
+	 * the current state, and accounts the runtime demotes to readonly, such as
+	 * programs and sysvars, are reported as readonly.
+	 * The transaction does not need to be signed.
+	 * @param tx - The transaction.
+	 * @returns The writable and readonly addresses, or null if the transaction is invalid.
+	 */
+	getAccountLocks(
+		tx: Transaction | VersionedTransaction,
+	): { writable: PublicKey[]; readonly: PublicKey[] } | null {
+		const serialized = tx.serialize({
+			requireAllSignatures: false,
+			verifySignatures: false,
+		});
+		const locks = this.inner.getAccountLocks(serialized);
+		if (locks === null) {
+			return null;
+		}
+		return {
+			writable: locks.writable().map((address) => new PublicKey(address)),
+			readonly: locks.readonly().map((address) => new PublicKey(address)),
+		};
+	}
+
+	/**
+	 * Returns the fee that would be charged for a transaction, split into the
+	 * signature fee and the prioritization fee requested by any ComputeBudget
+	 * instructions, along with how many signatures are charged for.
+	 * The transaction does not need to be signed.
+	 * @param tx - The transaction.
+	 * @returns The fee breakdown, or null if the transaction is invalid.
+	 */
+	getFeeBreakdownForTransaction(
+		tx: Transaction | VersionedTransaction,
+	): FeeBreakdown | null {
+		const serialized =
+			tx instanceof Transaction ? tx.serializeMessage() : tx.message.serialize();
+		return this.inner.getFeeBreakdownForMessage(serialized);
+	}
+
+	/**
+	 * Returns the slot and compute unit price of recently processed transactions
+	 * that set a compute unit price, oldest first, like the
+	 * `getRecentPrioritizationFees` RPC method. As many are kept as the
+	 * transaction history holds.
+	 * @returns The recent prioritization fees.
+	 */
+	getRecentPrioritizationFees(): PrioritizationFee[] {
+		return this.inner.getRecentPrioritizationFees();
+	}
+
+	/**
+	 * Returns how many transactions have been processed, successful or not.
+	 * Unlike the transaction history, this is not bounded by a capacity.
+	 * @returns The number of processed transactions.
+	 */
+	getTransactionCount(): bigint {
+		return this.inner.getTransactionCount();
+	}
+
+	/**
+	 * Returns how many times a program was entered by sent transactions since
+	 * this instance was created, counting both top-level instructions and CPIs.
+	 * Instructions after the one a transaction failed on are not counted.
+	 * @param programId - The program to look up.
+	 * @returns The number of invocations.
+	 */
+	getProgramInvocationCount(programId: PublicKey): bigint {
+		return this.inner.getProgramInvocationCount(programId.toBytes());
+	}
+
+	/**
+	 * Returns cumulative execution metrics, for use in benchmark harnesses.
+	 * @returns The compute units, transactions, instructions and program
+	 * invocations processed so far, and the time spent executing them.
+	 */
+	getMetrics(): SvmMetrics {
+		return this.inner.getMetrics();
+	}
+
+	/**
+	 * Returns the compute budget in effect, i.e. the one set with
+	 * `withComputeBudget` or else the defaults.
+	 * @returns The compute budget.
+	 */
+	currentComputeBudget(): ComputeBudget {
+		return this.inner.currentComputeBudget();
+	}
+
+	/**
+	 * Returns the fee rate governor in effect. LiteSVM never adjusts fees to
+	 * load, so the signature fee is fixed.
+	 * @returns The fee rate governor.
+	 */
+	feeRateGovernor(): FeeRateGovernor {
+		return this.inner.feeRateGovernor();
+	}
+
+	/**
+	 * Returns the full state as deterministic JSON, for golden snapshot tests.
+	 * The object has the keys `latestBlockhash`, `clock` and `accounts`, with
+	 * accounts sorted by address and their data base64-encoded. Integers are
+	 * written as strings so they survive `JSON.parse`.
+	 * @param includeSysvars - Whether to include the sysvar accounts.
+	 * @returns The state, serialized as JSON.
+	 */
+	dumpStateJson(includeSysvars: boolean): string {
+		return this.inner.dumpStateJson(includeSysvars);
+	}
+
+	/**
+	 * Restores the accounts, clock and latest blockhash from a string produced
+	 * by `dumpStateJson`. Accounts missing from it are removed, except sysvars,
+	 * builtin programs and the faucet, and blockhashes older than the restored one are rejected.
+	 * @param json - The state, serialized as JSON.
+	 */
+	loadStateJson(json: string) {
+		this.inner.loadStateJson(json);
+	}
+
 	/**
 	 * Gets a transaction from the transaction history.
 	 * @param signature - The transaction signature bytes
@@ -289,6 +1007,67 @@ export class LiteSVM {
 		return this.inner.airdrop(address.toBytes(), lamports);
 	}
 
+	/**
+	 * Returns the address of the internal airdrop account.
+	 * @returns The airdrop account address.
+	 */
+	airdropPubkey(): PublicKey {
+		return new PublicKey(this.inner.airdropPubkey());
+	}
+
+	/**
+	 * Returns the balance the address would have after `airdrop()`, without
+	 * sending anything. When the airdrop would fail, e.g. because the faucet
+	 * can't cover it or the balance would overflow, the current balance is
+	 * returned.
+	 * @param address - The airdrop recipient.
+	 * @param lamports - The amount to airdrop.
+	 * @returns The balance after the airdrop.
+	 */
+	previewAirdrop(address: PublicKey, lamports: bigint): bigint {
+		return this.inner.previewAirdrop(address.toBytes(), lamports);
+	}
+
+	/**
+	 * Sends lamports to many recipients in a single transaction made of one
+	 * system transfer per recipient. Fails without executing anything if the
+	 * transaction would not fit in a packet.
+	 * @param from - The sender, who also pays the fee.
+	 * @param recipients - Pairs of recipient address and lamport amount.
+	 * @returns The transaction result.
+	 */
+	transferMany(
+		from: Keypair,
+		recipients: [PublicKey, bigint][],
+	): TransactionMetadata | FailedTransactionMetadata {
+		return this.inner.transferMany(
+			from.secretKey,
+			recipients.map(([address]) => address.toBytes()),
+			recipients.map(([, lamports]) => lamports),
+		);
+	}
+
+	/**
+	 * Sends a transaction containing only the given instruction, signed
+	 * against the latest blockhash.
+	 * @param ix - The instruction to process.
+	 * @param signers - The signers of the transaction. The first one pays the fee.
+	 * @returns TransactionMetadata if the transaction succeeds, else FailedTransactionMetadata
+	 */
+	processInstruction(
+		ix: TransactionInstruction,
+		signers: Keypair[],
+	): TransactionMetadata | FailedTransactionMetadata {
+		return this.inner.processInstruction(
+			ix.programId.toBytes(),
+			ix.keys.map((key) => key.pubkey.toBytes()),
+			ix.keys.map((key) => key.isSigner),
+			ix.keys.map((key) => key.isWritable),
+			ix.data,
+			signers.map((signer) => signer.secretKey),
+		);
+	}
+
 	/**
 	 * Adds an SBF program to the test environment from the file specified.
 	 * @param programId - The program ID.
@@ -307,6 +1086,50 @@ export class LiteSVM {
 		return this.inner.addProgram(programId.toBytes(), programBytes);
 	}
 
+	/**
+	 * Adds an SBF program at a freshly generated program ID.
+	 * @param programBytes - The raw bytes of the compiled program, or those bytes encoded as base64.
+	 * @returns The program ID.
+	 */
+	deployProgram(programBytes: Uint8Array | string): PublicKey {
+		const bytes =
+			typeof programBytes === "string"
+				? Buffer.from(programBytes, "base64")
+				: programBytes;
+		return new PublicKey(this.inner.deployProgram(bytes));
+	}
+
+	/**
+	 * Deploys an SBF program under the upgradeable loader.
+	 * This creates both the program account and its programdata account.
+	 * @param programId - The program ID.
+	 * @param programdataAddress - The address of the programdata account.
+	 * @param programBytes - The raw bytes of the compiled program.
+	 * @param upgradeAuthority - The upgrade authority, if any.
+	 */
+	deployUpgradeableProgram(
+		programId: PublicKey,
+		programdataAddress: PublicKey,
+		programBytes: Uint8Array,
+		upgradeAuthority?: PublicKey,
+	) {
+		return this.inner.deployUpgradeableProgram(
+			programId.toBytes(),
+			programdataAddress.toBytes(),
+			programBytes,
+			upgradeAuthority?.toBytes(),
+		);
+	}
+
+	/**
+	 * Makes sure every program has a verified and compiled entry in the
+	 * program cache. Call this before the measured region of a benchmark so
+	 * no invocation pays for loading the program.
+	 */
+	warmProgramCache() {
+		this.inner.warmProgramCache();
+	}
+
 	/**
 	 * Processes a transaction and returns the result.
 	 * @param tx - The transaction to send.
@@ -351,20 +1174,144 @@ export class LiteSVM {
 	}
 
 	/**
-	 * Expires the current blockhash.
+	 * Simulates a transaction after replacing its recent blockhash with the
+	 * latest blockhash, like the RPC `replaceRecentBlockhash` option, so a
+	 * stale or even invalid blockhash doesn't fail the simulation.
+	 * Signatures are not verified, since the replacement invalidates them.
+	 * @param tx The transaction to simulate
+	 * @returns SimulatedTransactionInfo if simulation succeeds, else FailedTransactionMetadata
+	 */
+	simulateTransactionReplaceBlockhash(
+		tx: Transaction | VersionedTransaction,
+	): FailedTransactionMetadata | SimulatedTransactionInfo {
+		const inner = this.inner.simulateTransactionReplaceBlockhash(
+			tx.serialize({ requireAllSignatures: true, verifySignatures: false }),
+		);
+		return inner instanceof FailedTransactionMetadata
+			? inner
+			: new SimulatedTransactionInfo(inner);
+	}
+
+	/**
+	 * Simulates each transaction independently against the current state.
+	 * None of the simulations affect the state or each other.
+	 * @param txs The transactions to simulate
+	 * @returns For each transaction, SimulatedTransactionInfo if simulation succeeds, else FailedTransactionMetadata
+	 */
+	simulateTransactions(
+		txs: (Transaction | VersionedTransaction)[],
+	): (FailedTransactionMetadata | SimulatedTransactionInfo)[] {
+		const internal = this.inner;
+		const serialized = txs.map((tx) =>
+			tx.serialize({
+				requireAllSignatures: true,
+				verifySignatures: internal.getSigverify(),
+			}),
+		);
+		const buf = new Uint8Array(
+			serialized.reduce((total, tx) => total + 4 + tx.length, 0),
+		);
+		const view = new DataView(buf.buffer);
+		let offset = 0;
+		for (const tx of serialized) {
+			view.setUint32(offset, tx.length, true);
+			buf.set(tx, offset + 4);
+			offset += 4 + tx.length;
+		}
+		return internal
+			.simulateTransactions(buf)
+			.map((inner) =>
+				inner instanceof FailedTransactionMetadata
+					? inner
+					: new SimulatedTransactionInfo(inner),
+			);
+	}
+
+	/**
+	 * Returns the log lines of the transaction most recently sent or simulated
+	 * by this instance, including airdrops and failed transactions.
+	 * After `simulateTransactions()`, these are the logs of the last transaction in the batch.
+	 * @returns The log lines, or an empty array if no transaction has been processed yet.
+	 */
+	lastTransactionLogs(): string[] {
+		return this.inner.lastTransactionLogs();
+	}
+
+	/**
+	 * Checks the logs of the transaction most recently sent or simulated by this instance.
+	 * @param needle - The text to look for.
+	 * @returns Whether any log line contains `needle`.
+	 */
+	lastLogsContain(needle: string): boolean {
+		return this.inner.lastLogsContain(needle);
+	}
+
+	/**
+	 * Expires the current blockhash, and with it every earlier one, regardless
+	 * of the expiry window. Use `finalizeBlock()` to keep them valid for the window.
 	 * The return value of `latestBlockhash()` will be different after calling this.
 	 */
 	expireBlockhash() {
 		this.inner.expireBlockhash();
 	}
 
+	/**
+	 * Ends the current block and starts a new one: the blockhash is rotated
+	 * and the block height goes up by one.
+	 * @param advanceSlot - Whether the new block also moves to the next slot. Defaults to true.
+	 */
+	finalizeBlock(advanceSlot = true) {
+		this.inner.finalizeBlock(advanceSlot);
+	}
+
+	/**
+	 * Returns the block height. Unlike the slot, it only moves when a block
+	 * is finalized, so warping the clock leaves it unchanged.
+	 * @returns The current block height.
+	 */
+	getBlockHeight(): bigint {
+		return this.inner.getBlockHeight();
+	}
+
+	/**
+	 * Sets the block height, e.g. to match a cluster where slots were skipped.
+	 * @param height - The new block height.
+	 */
+	setBlockHeight(height: bigint) {
+		this.inner.setBlockHeight(height);
+	}
+
+	/**
+	 * Restores the clock to the one this instance started with, undoing any
+	 * warps or `setClock()` calls made since. For instances made with
+	 * `createAtSlot()`, that is the clock at the starting slot.
+	 * Other sysvars and accounts are left unchanged.
+	 */
+	resetClock() {
+		this.inner.resetClock();
+	}
+
 	/**
 	 * Warps the clock to the specified slot. This is a convenience wrapper
-	 * around `setClock()`.
+	 * around `setClock()`. The unix timestamp advances by the number of slots
+	 * warped times the slot duration; call `setClock()` afterwards to override it.
 	 * @param slot - The new slot.
+	 * @param expireBlockhashes - Whether to also age blockhashes by one block for
+	 * every slot skipped, so blockhashes older than the expiry window stop
+	 * being accepted. Defaults to false.
 	 */
-	warpToSlot(slot: bigint) {
-		this.inner.warpToSlot(slot);
+	warpToSlot(slot: bigint, expireBlockhashes = false) {
+		this.inner.warpToSlotWithBlockhashExpiry(slot, expireBlockhashes);
+	}
+
+	/**
+	 * Moves the clock forward by the given number of seconds.
+	 * The slot is advanced by however many slots fit in that time; time that
+	 * doesn't fill a whole slot carries over to the next call.
+	 * @param seconds - How many seconds to advance.
+	 */
+	warpForwardSeconds(seconds: bigint) {
+		this.inner.warpForwardSeconds(seconds);
 	}
 
 	/**
@@ -383,6 +1330,24 @@ export class LiteSVM {
 		this.inner.setClock(clock);
 	}
 
+	/**
+	 * Set only the clock's unix timestamp, leaving the slot, epoch and
+	 * other fields unchanged.
+	 * @param unixTimestamp - The new unix timestamp, in seconds.
+	 */
+	setUnixTimestamp(unixTimestamp: bigint) {
+		this.inner.setUnixTimestamp(unixTimestamp);
+	}
+
+	/**
+	 * Set only the clock's slot, leaving the timestamp, epoch and other
+	 * fields unchanged. Unlike `warpToSlot()`, the timestamp does not move.
+	 * @param slot - The new slot.
+	 */
+	setSlot(slot: bigint) {
+		this.inner.setSlot(slot);
+	}
+
 	/**
 	 * Get the EpochRewards sysvar.
 	 * @returns the EpochRewards object.
@@ -415,6 +1380,20 @@ export class LiteSVM {
 		this.inner.setEpochSchedule(schedule);
 	}
 
+	/**
+	 * Overwrite any of the clock, rent and epoch schedule sysvars in one step,
+	 * so the next transaction never sees only some of them changed.
+	 * @param sysvars - The sysvars to set. Omitted ones are left unchanged.
+	 */
+	setSysvarsBulk(sysvars: {
+		clock?: Clock;
+		rent?: Rent;
+		epochSchedule?: EpochSchedule;
+	}) {
+		const { clock, rent, epochSchedule } = sysvars;
+		this.inner.setSysvarsBulk(clock, rent, epochSchedule);
+	}
+
 	/**
 	 * Get the last restart slot sysvar.
 	 * @returns the last restart slot.
@@ -10,11 +10,38 @@ export declare class Account {
   toString(): string
 }
 
+/** The accounts a transaction locks for writing and for reading. */
+export declare class AccountLocks {
+  writable(): Array<Uint8Array>
+  readonly(): Array<Uint8Array>
+  toString(): string
+}
+
+/** The header fields of an account, without its data. */
+export declare class AccountSummary {
+  lamports(): bigint
+  dataLen(): bigint
+  owner(): Uint8Array
+  executable(): boolean
+  toString(): string
+}
+
 export declare class AddressAndAccount {
   address: Uint8Array
   account(): Account
 }
 
+/** The state of an address lookup table. */
+export declare class AddressLookupTable {
+  /** The authority, or null once the table is frozen. */
+  authority(): Uint8Array | null
+  /** The slot the table was deactivated in, or null while it is active. */
+  deactivationSlot(): bigint | null
+  /** The stored addresses, in table order. */
+  addresses(): Array<Uint8Array>
+  toString(): string
+}
+
 /**
  * A representation of network time.
  *
@@ -240,9 +267,30 @@ export declare class EpochSchedule {
   set firstNormalSlot(val: bigint)
 }
 
+export declare class ExecutionTrace {
+  programId(): Uint8Array
+  /**
+   * The index among the top-level instructions, or among the instructions
+   * invoked by the parent.
+   */
+  instructionIndex(): number
+  innerInstructions(): Array<ExecutionTrace>
+  /** The lines this instruction logged itself. */
+  logs(): Array<string>
+  /**
+   * Compute units consumed, including by the instructions it invoked.
+   * Builtin programs do not report their usage and are left at 0.
+   */
+  computeUnitsConsumed(): bigint
+  returnData(): Uint8Array | null
+  toString(): string
+}
+
 export declare class FailedTransactionMetadata {
   err(): TransactionErrorFieldless | TransactionErrorInstructionError | TransactionErrorDuplicateInstruction | TransactionErrorInsufficientFundsForRent | TransactionErrorProgramExecutionTemporarilyRestricted
   meta(): TransactionMetadata
+  /** Always false, so a send result can be checked without `instanceof`. */
+  success(): boolean
   toString(): string
 }
 
@@ -254,6 +302,16 @@ export declare class FeatureSet {
   toString(): string
 }
 
+/** The parts of a transaction fee. */
+export declare class FeeBreakdown {
+  /** The signatures charged for, including those verified by precompiles. */
+  signatureCount(): bigint
+  signatureFee(): bigint
+  prioritizationFee(): bigint
+  totalFee(): bigint
+  toString(): string
+}
+
 export declare class InnerInstruction {
   instruction(): CompiledInstruction
   stackHeight(): number
@@ -271,13 +329,29 @@ export declare class InstructionErrorCustom {
   toString(): string
 }
 
+/**
+ * Wraps the test environment along with the logs of the last transaction
+ * it sent or simulated.
+ */
 export declare class LiteSvm {
   /** Creates the basic test environment. */
   constructor()
   static default(): LiteSvm
+  /**
+   * Creates the basic test environment with the clock starting at `slot`.
+   * The epoch is derived from the epoch schedule; the unix timestamp is unchanged.
+   */
+  static createAtSlot(slot: bigint): LiteSvm
+  /**
+   * Creates an independent copy of this instance, including all accounts,
+   * sysvars and configuration.
+   */
+  fork(): LiteSvm
   setComputeBudget(budget: ComputeBudget): void
   /** Enables or disables sigverify */
   setSigverify(sigverify: boolean): void
+  /** Enables or disables signature verification in precompile instructions */
+  setSigverifyPrecompiles(enabled: boolean): void
   /** Enables or disables the blockhash check */
   setBlockhashCheck(check: boolean): void
   /** Includes the default sysvars */
@@ -288,45 +362,272 @@ export declare class LiteSvm {
   setBuiltins(): void
   /** Changes the initial lamports in LiteSVM's airdrop account */
   setLamports(lamports: bigint): void
+  /** Derives the faucet keypair from the given 32-byte seed */
+  setFaucetSeed(seed: Uint8Array): void
+  /**
+   * Derives the genesis hash, faucet keypair, warped slot hashes and
+   * generated program IDs from the given seed
+   */
+  setRngSeed(seed: bigint): void
+  /** Changes the base fee charged per signature */
+  setLamportsPerSignature(lamportsPerSignature: bigint): void
+  /** Changes for how many finalized blocks a blockhash is accepted. */
+  setBlockhashExpirySlots(slots: bigint): void
+  /**
+   * Rejects transactions whose blockhash is more than `slots` blocks old,
+   * even if it is still within the expiry window.
+   */
+  setMaxBlockhashAge(slots: bigint): void
   /** Includes the standard SPL programs */
   setDefaultPrograms(): void
+  /** Includes the SPL Associated Token Account program */
+  setSplAssociatedTokenAccountProgram(): void
+  /** Includes the Config program */
+  setConfigProgram(): void
   /**
    * Changes the capacity of the transaction history.
    * Set this to 0 to disable transaction history and allow duplicate transactions.
    */
   setTransactionHistory(capacity: bigint): void
+  /** Enables or disables rejecting duplicate transactions with `AlreadyProcessed`. */
+  setTransactionDedup(enabled: boolean): void
   setLogBytesLimit(limit?: bigint | undefined | null): void
+  /** Changes how long a slot lasts when converting between time and slots. */
+  setSlotDurationMs(slotDurationMs: bigint): void
+  /** Caps how deeply instructions may nest cross-program invocations. */
+  setMaxCpiDepth(depth: number): void
+  /** Enables or disables rent collection */
+  setRentCollection(enabled: boolean): void
+  /** Enables or disables rejecting accounts left below the rent-exempt minimum */
+  setRequireRentExempt(enabled: boolean): void
+  /** Enables or disables recording token account balances before and after each transaction */
+  setTokenBalanceRecording(enabled: boolean): void
+  /** Enables or disables recording the call graph of each transaction */
+  setExecutionTrace(enabled: boolean): void
+  /** Enables or disables recording the compute units left after each top-level instruction */
+  setCaptureComputeUnitsRemaining(enabled: boolean): void
   setPrecompiles(): void
+  /** Returns the ids of the precompiles whose account is installed and whose feature is active. */
+  getInstalledPrecompiles(): Array<Uint8Array>
   /** Returns minimum balance required to make an account with specified data length rent exempt. */
   minimumBalanceForRentExemption(dataLen: bigint): bigint
+  /** Returns the minimum stake delegation for the configured feature set. */
+  getMinimumDelegation(): bigint
   /** Returns all information associated with the account of the provided pubkey. */
   getAccount(pubkey: Uint8Array): Account | null
+  /**
+   * Like `get_account`, but returns an empty, zero-lamport, system-owned
+   * account when there is none.
+   */
+  getAccountOrDefault(pubkey: Uint8Array): Account
+  /**
+   * Returns the lamports, data length, owner and executable flag of an account
+   * without copying its data.
+   */
+  getAccountSummary(pubkey: Uint8Array): AccountSummary | null
+  /** Returns the length of an account's data without copying it. */
+  getAccountDataLen(pubkey: Uint8Array): bigint | null
+  /** Returns whether an account is executable without copying it. */
+  isExecutable(pubkey: Uint8Array): boolean | null
+  /** Returns the decimals, supply and authorities of an SPL Token or Token-2022 mint. */
+  getMintInfo(mint: Uint8Array): MintInfo | null
+  /** Returns the total supply of an SPL Token or Token-2022 mint. */
+  getTokenSupply(mint: Uint8Array): bigint | null
+  /**
+   * Returns the authority, deactivation slot and addresses of an address
+   * lookup table, or null if there is no lookup table at that address.
+   */
+  getAddressLookupTable(address: Uint8Array): AddressLookupTable | null
+  /**
+   * Returns the activation of a stake account at the current epoch,
+   * or null if there is no initialized stake account at that address.
+   */
+  getStakeActivation(stakeAccount: Uint8Array): StakeActivation | null
+  /**
+   * Returns how the program is executed, e.g. "Builtin" or "BpfLoaderUpgradeable",
+   * or null if there is no executable account at that address.
+   */
+  programKind(programId: Uint8Array): string | null
+  /** Returns the slot at which the account was last written. */
+  getAccountModifiedSlot(pubkey: Uint8Array): bigint | null
+  /**
+   * Creates an uninitialized, rent-exempt account owned by `program_id` at the
+   * program derived address of `seeds`, funded by the payer. Returns the address.
+   */
+  createPdaAccount(programId: Uint8Array, seeds: Array<Uint8Array>, space: number, payerSecretKey: Uint8Array): Uint8Array
   /** Sets all information associated with the account of the provided pubkey. */
   setAccount(pubkey: Uint8Array, data: Account): void
+  /**
+   * Sets many accounts in one call, pairing `addresses` with `accounts` by index.
+   * Nothing is applied if any entry fails.
+   */
+  setAccounts(addresses: Array<Uint8Array>, accounts: Array<Account>): void
+  /** Makes sure every executable account has a compiled entry in the program cache. */
+  warmProgramCache(): void
+  /** Overwrites a slice of an existing account's data, starting at `offset`. */
+  setAccountData(pubkey: Uint8Array, offset: bigint, bytes: Uint8Array): void
+  /** Creates an initialized, rent-exempt SPL Token mint. Fails if the account already exists. */
+  createTokenMint(mint: Uint8Array, mintAuthority: Uint8Array, decimals: number): void
+  /** Creates an initialized, rent-exempt durable nonce account. Fails if the account already exists. */
+  createNonceAccount(nonceAccount: Uint8Array, authority: Uint8Array): void
+  /**
+   * Creates an active, rent-exempt address lookup table holding `addresses`.
+   * Fails if the account already exists.
+   */
+  createAddressLookupTable(address: Uint8Array, authority: Uint8Array, addresses: Array<Uint8Array>): void
+  /** Sets the lamports of an existing account, leaving the rest of it untouched. */
+  setAccountLamports(pubkey: Uint8Array, lamports: bigint): void
   /** Gets the balance of the provided account pubkey. */
   getBalance(pubkey: Uint8Array): bigint | null
   /** Gets the latest blockhash. */
   latestBlockhash(): string
+  /** Returns the last block height at which the latest blockhash is accepted. */
+  latestBlockhashLastValidBlockHeight(): bigint
+  /** Gets the genesis hash. */
+  getGenesisHash(): string
+  /** Sets the genesis hash. */
+  setGenesisHash(hash: string): void
+  /**
+   * Returns the hash the runtime identifies a serialized message by,
+   * or null if the message is invalid.
+   */
+  /** Returns the fee for a serialized message, or null if the message is invalid. */
+  getFeeForMessage(messageBytes: Uint8Array): bigint | null
+  /**
+   * Returns the accounts a serialized transaction would lock, with lookup
+   * tables resolved, or null if the transaction is invalid.
+   */
+  getAccountLocks(txBytes: Uint8Array): AccountLocks | null
+  /**
+   * Returns the signature count and fee parts for a serialized message,
+   * or null if the message is invalid.
+   */
+  getFeeBreakdownForMessage(messageBytes: Uint8Array): FeeBreakdown | null
+  /**
+   * Returns the slot and compute unit price of recently processed
+   * transactions that set a compute unit price, oldest first.
+   */
+  getRecentPrioritizationFees(): Array<PrioritizationFee>
+  /** Returns how many transactions have been processed, successful or not. */
+  getTransactionCount(): bigint
+  /**
+   * Returns how many times the program was entered by sent transactions,
+   * counting both top-level instructions and CPIs.
+   */
+  getProgramInvocationCount(programId: Uint8Array): bigint
+  /** Returns cumulative execution metrics. */
+  getMetrics(): SvmMetrics
+  /**
+   * Returns every account, sorted by address, plus the clock and latest blockhash
+   * as a deterministic JSON string.
+   */
+  dumpStateJson(includeSysvars: boolean): string
+  /**
+   * Sets any of the clock, rent and epoch schedule sysvars in one step.
+   * Omitted ones are left unchanged.
+   */
+  setSysvarsBulk(clock?: Clock | undefined | null, rent?: Rent | undefined | null, epochSchedule?: EpochSchedule | undefined | null): void
+  /**
+   * Restores the accounts, clock and latest blockhash from a string produced by
+   * `dump_state_json`. Accounts missing from it are removed, except sysvars, builtins and the faucet.
+   */
+  loadStateJson(json: string): void
   /** Gets a transaction from the transaction history. */
   getTransaction(signature: Uint8Array): TransactionMetadata | FailedTransactionMetadata | null
   /** Airdrops the account with the lamports specified. */
   airdrop(pubkey: Uint8Array, lamports: bigint): TransactionMetadata | FailedTransactionMetadata | null
+  /** Returns the pubkey of the internal airdrop account. */
+  airdropPubkey(): Uint8Array
+  /**
+   * Returns the balance the address would have after an airdrop of
+   * `lamports`, without sending anything.
+   */
+  previewAirdrop(pubkey: Uint8Array, lamports: bigint): bigint
+  /**
+   * Sends lamports from `from` to every recipient in a single transaction.
+   * `recipients` and `lamports` are parallel arrays.
+   */
+  transferMany(fromSecretKey: Uint8Array, recipients: Array<Uint8Array>, lamports: Array<bigint>): TransactionMetadata | FailedTransactionMetadata
+  /**
+   * Sends a transaction containing only the instruction built from `program_id`,
+   * the account metas given as parallel `keys`, `is_signer` and `is_writable`
+   * arrays, and `data`. The first signer pays the fee.
+   */
+  processInstruction(programId: Uint8Array, keys: Array<Uint8Array>, isSigner: Array<boolean>, isWritable: Array<boolean>, data: Uint8Array, signerSecretKeys: Array<Uint8Array>): TransactionMetadata | FailedTransactionMetadata
   /** Adds am SBF program to the test environment from the file specified. */
   addProgramFromFile(programId: Uint8Array, path: string): void
   /** Adds am SBF program to the test environment. */
   addProgram(programId: Uint8Array, programBytes: Uint8Array): void
+  /** Adds an SBF program at a freshly generated program ID and returns the ID. */
+  deployProgram(programBytes: Uint8Array): Uint8Array
+  /**
+   * Deploys an SBF program under the upgradeable loader, creating both the
+   * program account and its programdata account.
+   */
+  deployUpgradeableProgram(programId: Uint8Array, programdataAddress: Uint8Array, programBytes: Uint8Array, upgradeAuthority?: Uint8Array | undefined | null): void
   sendLegacyTransaction(txBytes: Uint8Array): TransactionMetadata | FailedTransactionMetadata
   sendVersionedTransaction(txBytes: Uint8Array): TransactionMetadata | FailedTransactionMetadata
   simulateLegacyTransaction(txBytes: Uint8Array): SimulatedTransactionInfo | FailedTransactionMetadata
   simulateVersionedTransaction(txBytes: Uint8Array): SimulatedTransactionInfo | FailedTransactionMetadata
-  /** Expires the current blockhash */
+  /**
+   * Simulates a transaction after replacing its recent blockhash with the
+   * latest blockhash. Signatures are not verified.
+   */
+  simulateTransactionReplaceBlockhash(txBytes: Uint8Array): SimulatedTransactionInfo | FailedTransactionMetadata
+  /**
+   * Simulates each transaction independently against the current state.
+   * The serialized transactions are concatenated, each prefixed with its
+   * length as a little-endian u32.
+   */
+  simulateTransactions(txsBytes: Uint8Array): Array<SimulatedTransactionInfo | FailedTransactionMetadata>
+  /**
+   * Returns the log lines of the transaction most recently sent or
+   * simulated by this instance. After `simulate_transactions`, these are
+   * the logs of the last transaction in the batch.
+   */
+  lastTransactionLogs(): Array<string>
+  /**
+   * Whether any log line of the transaction most recently sent or
+   * simulated by this instance contains `needle`.
+   */
+  lastLogsContain(needle: string): boolean
+  /** Expires the current blockhash and every earlier one */
   expireBlockhash(): void
+  /**
+   * Ends the current block: rotates the blockhash, increments the block
+   * height and, if `advance_slot` is set, moves to the next slot.
+   */
+  finalizeBlock(advanceSlot: boolean): void
+  /** Returns the block height, which only moves with `finalize_block`. */
+  getBlockHeight(): bigint
+  /** Sets the block height. */
+  setBlockHeight(height: bigint): void
+  /** Restores the clock to the one the instance started with. */
+  resetClock(): void
   /** Warps the clock to the specified slot */
   warpToSlot(slot: bigint): void
+  /**
+   * Warps the clock to the specified slot, aging blockhashes by one block for
+   * every slot skipped if `expire_blockhashes` is set.
+   */
+  warpToSlotWithBlockhashExpiry(slot: bigint, expireBlockhashes: boolean): void
+  /** Moves the clock forward by the given number of seconds */
+  warpForwardSeconds(seconds: bigint): void
   getComputeBudget(): ComputeBudget | null
+  /**
+   * Returns the compute budget in effect: the one set with
+   * `set_compute_budget`, or else the defaults.
+   */
+  currentComputeBudget(): ComputeBudget
+  /** Returns the fee rate governor in effect. */
+  feeRateGovernor(): FeeRateGovernor
   getSigverify(): boolean
   getClock(): Clock
   setClock(clock: Clock): void
+  /** Sets only the clock's unix timestamp, leaving the other fields as they are. */
+  setUnixTimestamp(unixTimestamp: bigint): void
+  /** Sets only the clock's slot, leaving the other fields as they are. */
+  setSlot(slot: bigint): void
   getRent(): Rent
   setRent(rent: Rent): void
   getEpochRewards(): EpochRewards
@@ -344,6 +645,23 @@ export declare class LiteSvm {
 }
 
 /** Configuration of network rent. */
+/** The base fields of an SPL Token or Token-2022 mint. */
+export declare class MintInfo {
+  decimals(): number
+  supply(): bigint
+  mintAuthority(): Uint8Array | null
+  freezeAuthority(): Uint8Array | null
+  toString(): string
+}
+
+/** The compute unit price a recent transaction paid in a given slot. */
+export declare class PrioritizationFee {
+  slot(): bigint
+  /** The compute unit price, in micro-lamports per compute unit. */
+  prioritizationFee(): bigint
+  toString(): string
+}
+
 export declare class Rent {
   /**
    * @param lamportsPerByteYear - Rental rate in lamports/byte-year.
@@ -421,6 +739,10 @@ export declare class Rent {
 export declare class SimulatedTransactionInfo {
   meta(): TransactionMetadata
   postAccounts(): Array<AddressAndAccount>
+  /** Compute units consumed by the simulation. */
+  unitsConsumed(): bigint
+  /** Returns the units consumed, logs and return data as a plain object. */
+  toSerializable(): SerializableSimulationInfo
   toString(): string
 }
 
@@ -472,6 +794,21 @@ export declare class StakeHistoryEntry {
   toString(): string
 }
 
+export declare class TokenBalance {
+  /** The index of the token account in the transaction's account keys. */
+  accountIndex(): number
+  mint(): Uint8Array
+  owner(): Uint8Array
+  /** The token program that owns the account. */
+  programId(): Uint8Array
+  /** The balance in base units. */
+  amount(): bigint
+  decimals(): number
+  /** The amount scaled down by the mint's decimals. */
+  uiAmount(): number
+  toString(): string
+}
+
 export declare class TransactionErrorDuplicateInstruction {
   index: number
   toString(): string
@@ -496,10 +833,44 @@ export declare class TransactionErrorProgramExecutionTemporarilyRestricted {
 export declare class TransactionMetadata {
   signature(): Uint8Array
   logs(): Array<string>
+  /** Whether the logs hit the log bytes limit, so later messages were dropped. */
+  logsTruncated(): boolean
   innerInstructions(): Array<Array<InnerInstruction>>
   computeUnitsConsumed(): bigint
   returnData(): TransactionReturnData
+  fee(): bigint
+  computeUnitLimit(): bigint
+  /** Whether the transaction used up its whole compute unit limit. */
+  hitComputeLimit(): boolean
   prettyLogs(): string
+  /**
+   * The lines the given program logged itself, including when it was
+   * reached through CPI. Lines logged by its callers or callees and the
+   * runtime's invoke and completion lines are left out.
+   */
+  logsForProgram(programId: Uint8Array): Array<string>
+  /**
+   * Token account balances before the transaction.
+   * Only recorded with token balance recording enabled.
+   */
+  preTokenBalances(): Array<TokenBalance>
+  /**
+   * Token account balances after the transaction.
+   * Only recorded with token balance recording enabled.
+   */
+  postTokenBalances(): Array<TokenBalance>
+  /**
+   * The call graph of the transaction, one tree per top-level instruction
+   * that ran. Only recorded with execution tracing enabled.
+   */
+  executionTrace(): Array<ExecutionTrace>
+  /**
+   * Compute units left after each top-level instruction that completed.
+   * Only recorded with compute units remaining capture enabled.
+   */
+  computeUnitsRemaining(): Array<bigint>
+  /** Always true, so a send result can be checked without `instanceof`. */
+  success(): boolean
   toString(): string
 }
 
@@ -509,6 +880,19 @@ export declare class TransactionReturnData {
   toString(): string
 }
 
+/**
+ * Derives a program address from seeds, a bump seed and a program id.
+ *
+ * Fails if the resulting address lies on the ed25519 curve.
+ */
+export declare function createProgramAddress(seeds: Array<Uint8Array>, bump: number, programId: Uint8Array): Uint8Array
+
+/**
+ * Returns the hash the runtime identifies a serialized message by,
+ * or null if the message is invalid.
+ */
+export declare function messageHash(messageBytes: Uint8Array): string | null
+
 export declare const enum InstructionErrorFieldless {
   GenericError = 0,
   InvalidArgument = 1,
@@ -565,6 +949,53 @@ export declare const enum InstructionErrorFieldless {
   BorshIoError = 52
 }
 
+/** The parts of a successful simulation most tests look at, as a plain object. */
+export interface SerializableSimulationInfo {
+  /** Compute units consumed. This never exceeds the compute unit limit, so it fits a number. */
+  unitsConsumed: number
+  logs: Array<string>
+  /** The data set with `set_return_data`, if any. */
+  returnData?: Uint8Array
+}
+
+/** The activation of a stake account at the current epoch. */
+export interface StakeActivation {
+  /** One of "activating", "active", "deactivating" or "inactive". */
+  state: string
+  /** The effective stake, in lamports. */
+  active: bigint
+  /** The stake that is not yet effective, excluding the rent-exempt reserve. */
+  inactive: bigint
+}
+
+/** Cumulative execution counters, useful for tracking performance regressions. */
+/**
+ * The fee rate governor in effect. LiteSVM never adjusts fees to load,
+ * so the signature fee is fixed.
+ */
+export interface FeeRateGovernor {
+  lamportsPerSignature: bigint
+  targetLamportsPerSignature: bigint
+  targetSignaturesPerSlot: bigint
+  minLamportsPerSignature: bigint
+  maxLamportsPerSignature: bigint
+  /** The percentage of fees burned rather than paid to the validator. */
+  burnPercent: number
+}
+
+export interface SvmMetrics {
+  /** Total compute units consumed by all transactions. */
+  computeUnitsConsumed: bigint
+  /** Total number of transactions processed, successful or not. */
+  transactions: bigint
+  /** Total number of top-level instructions executed. */
+  instructions: bigint
+  /** Total number of program invocations, including CPIs. */
+  programInvocations: bigint
+  /** Wall-clock time spent executing transactions, in nanoseconds. */
+  executionTimeNanos: bigint
+}
+
 export declare const enum SlotHistoryCheck {
   Future = 0,
   TooOld = 1,
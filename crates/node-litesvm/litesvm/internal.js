@@ -573,6 +573,7 @@ if (!nativeBinding) {
 
 module.exports = nativeBinding
 module.exports.Account = nativeBinding.Account
+module.exports.AccountSummary = nativeBinding.AccountSummary
 module.exports.AddressAndAccount = nativeBinding.AddressAndAccount
 module.exports.Clock = nativeBinding.Clock
 module.exports.CompiledInstruction = nativeBinding.CompiledInstruction
@@ -598,6 +599,7 @@ module.exports.TransactionErrorInsufficientFundsForRent = nativeBinding.Transact
 module.exports.TransactionErrorProgramExecutionTemporarilyRestricted = nativeBinding.TransactionErrorProgramExecutionTemporarilyRestricted
 module.exports.TransactionMetadata = nativeBinding.TransactionMetadata
 module.exports.TransactionReturnData = nativeBinding.TransactionReturnData
+module.exports.createProgramAddress = nativeBinding.createProgramAddress
 module.exports.InstructionErrorFieldless = nativeBinding.InstructionErrorFieldless
 module.exports.SlotHistoryCheck = nativeBinding.SlotHistoryCheck
 module.exports.TransactionErrorFieldless = nativeBinding.TransactionErrorFieldless
@@ -1,5 +1,10 @@
 use {
     crate::{to_string_js, util::bigint_to_u64},
+    litesvm::types::{
+        AccountLocks as AccountLocksOriginal, AccountSummary as AccountSummaryOriginal,
+        AddressLookupTableAccount as AddressLookupTableOriginal, MintInfo as MintInfoOriginal,
+        StakeActivation as StakeActivationOriginal, StakeActivationState,
+    },
     napi::bindgen_prelude::*,
     solana_account::Account as AccountOriginal,
     solana_pubkey::Pubkey,
@@ -61,3 +66,156 @@ impl Account {
 }
 
 to_string_js!(Account);
+
+/// The header fields of an account, without its data.
+#[derive(Debug, Clone)]
+#[napi]
+pub struct AccountSummary(pub(crate) AccountSummaryOriginal);
+
+#[napi]
+impl AccountSummary {
+    #[napi]
+    pub fn lamports(&self) -> u64 {
+        self.0.lamports
+    }
+
+    #[napi]
+    pub fn data_len(&self) -> u64 {
+        self.0.data_len as u64
+    }
+
+    #[napi]
+    pub fn owner(&self) -> Uint8Array {
+        Uint8Array::new(self.0.owner.to_bytes().to_vec())
+    }
+
+    #[napi]
+    pub fn executable(&self) -> bool {
+        self.0.executable
+    }
+}
+
+to_string_js!(AccountSummary);
+
+/// The base fields of an SPL Token or Token-2022 mint.
+#[derive(Debug, Clone)]
+#[napi]
+pub struct MintInfo(pub(crate) MintInfoOriginal);
+
+#[napi]
+impl MintInfo {
+    #[napi]
+    pub fn decimals(&self) -> u8 {
+        self.0.decimals
+    }
+
+    #[napi]
+    pub fn supply(&self) -> u64 {
+        self.0.supply
+    }
+
+    #[napi]
+    pub fn mint_authority(&self) -> Option<Uint8Array> {
+        self.0
+            .mint_authority
+            .map(|authority| Uint8Array::new(authority.to_bytes().to_vec()))
+    }
+
+    #[napi]
+    pub fn freeze_authority(&self) -> Option<Uint8Array> {
+        self.0
+            .freeze_authority
+            .map(|authority| Uint8Array::new(authority.to_bytes().to_vec()))
+    }
+}
+
+to_string_js!(MintInfo);
+
+/// The state of an address lookup table.
+#[derive(Debug, Clone)]
+#[napi]
+pub struct AddressLookupTable(pub(crate) AddressLookupTableOriginal);
+
+#[napi]
+impl AddressLookupTable {
+    #[napi]
+    /// The authority, or null once the table is frozen.
+    pub fn authority(&self) -> Option<Uint8Array> {
+        self.0
+            .authority
+            .map(|authority| Uint8Array::new(authority.to_bytes().to_vec()))
+    }
+
+    #[napi]
+    /// The slot the table was deactivated in, or null while it is active.
+    pub fn deactivation_slot(&self) -> Option<u64> {
+        (self.0.deactivation_slot != u64::MAX).then_some(self.0.deactivation_slot)
+    }
+
+    #[napi]
+    /// The stored addresses, in table order.
+    pub fn addresses(&self) -> Vec<Uint8Array> {
+        self.0
+            .addresses
+            .iter()
+            .map(|address| Uint8Array::new(address.to_bytes().to_vec()))
+            .collect()
+    }
+}
+
+to_string_js!(AddressLookupTable);
+
+/// The activation of a stake account at the current epoch.
+#[napi(object)]
+pub struct StakeActivation {
+    /// One of "activating", "active", "deactivating" or "inactive".
+    pub state: String,
+    /// The effective stake, in lamports.
+    pub active: BigInt,
+    /// The stake that is not yet effective, excluding the rent-exempt reserve.
+    pub inactive: BigInt,
+}
+
+impl From<StakeActivationOriginal> for StakeActivation {
+    fn from(activation: StakeActivationOriginal) -> Self {
+        let state = match activation.state {
+            StakeActivationState::Activating => "activating",
+            StakeActivationState::Active => "active",
+            StakeActivationState::Deactivating => "deactivating",
+            StakeActivationState::Inactive => "inactive",
+        };
+        Self {
+            state: state.to_string(),
+            active: activation.active.into(),
+            inactive: activation.inactive.into(),
+        }
+    }
+}
+
+/// The accounts a transaction locks for writing and for reading.
+#[derive(Debug, Clone)]
+#[napi]
+pub struct AccountLocks(pub(crate) AccountLocksOriginal);
+
+#[napi]
+impl AccountLocks {
+    #[napi]
+    pub fn writable(&self) -> Vec<Uint8Array> {
+        self.0
+            .writable
+            .iter()
+            .map(|address| Uint8Array::new(address.to_bytes().to_vec()))
+            .collect()
+    }
+
+    #[napi]
+    pub fn readonly(&self) -> Vec<Uint8Array> {
+        self.0
+            .readonly
+            .iter()
+            .map(|address| Uint8Array::new(address.to_bytes().to_vec()))
+            .collect()
+    }
+}
+
+to_string_js!(AccountLocks);
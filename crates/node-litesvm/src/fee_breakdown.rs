@@ -0,0 +1,61 @@
+use {
+    crate::to_string_js, litesvm::types::FeeBreakdown as FeeBreakdownOriginal,
+    napi::bindgen_prelude::*, solana_fee_calculator::FeeRateGovernor as FeeRateGovernorOriginal,
+};
+
+/// The parts of a transaction fee.
+#[derive(Debug, Clone)]
+#[napi]
+pub struct FeeBreakdown(pub(crate) FeeBreakdownOriginal);
+
+#[napi]
+impl FeeBreakdown {
+    #[napi]
+    /// The signatures charged for, including those verified by precompiles.
+    pub fn signature_count(&self) -> u64 {
+        self.0.signature_count
+    }
+
+    #[napi]
+    pub fn signature_fee(&self) -> u64 {
+        self.0.signature_fee
+    }
+
+    #[napi]
+    pub fn prioritization_fee(&self) -> u64 {
+        self.0.prioritization_fee
+    }
+
+    #[napi]
+    pub fn total_fee(&self) -> u64 {
+        self.0.total_fee
+    }
+}
+
+to_string_js!(FeeBreakdown);
+
+/// The fee rate governor in effect. LiteSVM never adjusts fees to load,
+/// so the signature fee is fixed.
+#[napi(object)]
+pub struct FeeRateGovernor {
+    pub lamports_per_signature: BigInt,
+    pub target_lamports_per_signature: BigInt,
+    pub target_signatures_per_slot: BigInt,
+    pub min_lamports_per_signature: BigInt,
+    pub max_lamports_per_signature: BigInt,
+    /// The percentage of fees burned rather than paid to the validator.
+    pub burn_percent: u8,
+}
+
+impl From<FeeRateGovernorOriginal> for FeeRateGovernor {
+    fn from(governor: FeeRateGovernorOriginal) -> Self {
+        Self {
+            lamports_per_signature: governor.lamports_per_signature.into(),
+            target_lamports_per_signature: governor.target_lamports_per_signature.into(),
+            target_signatures_per_slot: governor.target_signatures_per_slot.into(),
+            min_lamports_per_signature: governor.min_lamports_per_signature.into(),
+            max_lamports_per_signature: governor.max_lamports_per_signature.into(),
+            burn_percent: governor.burn_percent,
+        }
+    }
+}
@@ -2,9 +2,14 @@
 #![allow(clippy::new_without_default, clippy::unit_arg)]
 use {
     crate::{
-        account::Account,
+        account::{
+            Account, AccountLocks, AccountSummary, AddressLookupTable, MintInfo, StakeActivation,
+        },
         compute_budget::ComputeBudget,
         feature_set::FeatureSet,
+        fee_breakdown::{FeeBreakdown, FeeRateGovernor},
+        metrics::SvmMetrics,
+        prioritization_fee::PrioritizationFee,
         sysvar::{
             clock::Clock, epoch_rewards::EpochRewards, epoch_schedule::EpochSchedule, rent::Rent,
             slot_hashes::SlotHash, slot_history::SlotHistory, stake_history::StakeHistory,
@@ -25,21 +30,28 @@ use {
         LiteSVM as LiteSVMOriginal,
     },
     napi::bindgen_prelude::*,
-    solana_clock::Clock as ClockOriginal,
     solana_epoch_rewards::EpochRewards as EpochRewardsOriginal,
     solana_epoch_schedule::EpochSchedule as EpochScheduleOriginal,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::Keypair,
     solana_last_restart_slot::LastRestartSlot,
+    solana_message::VersionedMessage,
+    solana_pubkey::Pubkey,
     solana_rent::Rent as RentOriginal,
     solana_signature::Signature,
     solana_slot_hashes::SlotHashes,
     solana_slot_history::SlotHistory as SlotHistoryOriginal,
     solana_stake_interface::stake_history::StakeHistory as StakeHistoryOriginal,
     solana_transaction::{versioned::VersionedTransaction, Transaction},
-    util::{bigint_to_u64, bigint_to_usize},
+    util::{bigint_to_i64, bigint_to_u64, bigint_to_usize},
 };
 mod account;
 mod compute_budget;
 mod feature_set;
+mod fee_breakdown;
+mod metrics;
+mod prioritization_fee;
+mod state_json;
 mod sysvar;
 mod transaction_error;
 mod transaction_metadata;
@@ -84,20 +96,98 @@ fn convert_sim_result(
     }
 }
 
+/// Derives a program address from seeds, a bump seed and a program id.
+///
+/// Fails if the resulting address lies on the ed25519 curve.
+#[napi]
+pub fn create_program_address(
+    seeds: Vec<Uint8Array>,
+    bump: u8,
+    program_id: &[u8],
+) -> Result<Uint8Array> {
+    let bump = [bump];
+    let mut seeds: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_ref()).collect();
+    seeds.push(&bump);
+    Pubkey::create_program_address(&seeds, &convert_pubkey(program_id))
+        .map(Uint8Array::with_data_copied)
+        .map_err(|e| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to create program address: {e}"),
+            )
+        })
+}
+
+/// Returns the hash the runtime identifies a serialized message by,
+/// or null if the message is invalid.
 #[napi]
-pub struct LiteSvm(LiteSVMOriginal);
+pub fn message_hash(message_bytes: &[u8]) -> Option<String> {
+    let message: VersionedMessage = deserialize(message_bytes).ok()?;
+    Some(message.hash().to_string())
+}
+
+/// Wraps the test environment along with the logs of the last transaction
+/// it sent or simulated.
+#[napi]
+pub struct LiteSvm(LiteSVMOriginal, Vec<String>);
+
+impl LiteSvm {
+    fn record_result(&mut self, res: TransactionResultOriginal) -> TransactionResult {
+        self.1 = match &res {
+            Ok(meta) => meta.logs.clone(),
+            Err(failed) => failed.meta.logs.clone(),
+        };
+        convert_transaction_result(res)
+    }
+
+    fn record_sim_result(
+        &mut self,
+        res: std::result::Result<
+            SimulatedTransactionInfoOriginal,
+            FailedTransactionMetadataOriginal,
+        >,
+    ) -> SimulateResult {
+        self.1 = match &res {
+            Ok(info) => info.meta.logs.clone(),
+            Err(failed) => failed.meta.logs.clone(),
+        };
+        convert_sim_result(res)
+    }
+}
 
 #[napi]
 impl LiteSvm {
     /// Creates the basic test environment.
     #[napi(constructor)]
     pub fn new() -> Self {
-        Self(LiteSVMOriginal::new())
+        Self(LiteSVMOriginal::new(), Vec::new())
     }
 
     #[napi(factory, js_name = "default")]
     pub fn new_default() -> Self {
-        Self(LiteSVMOriginal::default())
+        Self(LiteSVMOriginal::default(), Vec::new())
+    }
+
+    /// Creates the basic test environment with the clock starting at `slot`.
+    /// The epoch is derived from the epoch schedule; the unix timestamp is unchanged.
+    #[napi(factory)]
+    pub fn create_at_slot(slot: BigInt) -> Result<Self> {
+        let slot = bigint_to_u64(&slot)?;
+        let mut svm = LiteSVMOriginal::new();
+        let epoch_schedule = svm.get_sysvar::<EpochScheduleOriginal>();
+        let mut clock = svm.get_clock();
+        clock.slot = slot;
+        clock.epoch = epoch_schedule.get_epoch(slot);
+        clock.leader_schedule_epoch = epoch_schedule.get_leader_schedule_epoch(slot);
+        svm.set_initial_clock(clock);
+        Ok(Self(svm, Vec::new()))
+    }
+
+    #[napi]
+    /// Creates an independent copy of this instance, including all accounts,
+    /// sysvars and configuration.
+    pub fn fork(&self) -> Self {
+        Self(self.0.clone(), self.1.clone())
     }
 
     #[napi]
@@ -111,6 +201,12 @@ impl LiteSvm {
         self.0.set_sigverify(sigverify);
     }
 
+    #[napi]
+    /// Enables or disables signature verification in precompile instructions
+    pub fn set_sigverify_precompiles(&mut self, enabled: bool) {
+        self.0.set_sigverify_precompiles(enabled);
+    }
+
     #[napi]
     /// Enables or disables the blockhash check
     pub fn set_blockhash_check(&mut self, check: bool) {
@@ -141,12 +237,66 @@ impl LiteSvm {
         Ok(self.0.set_lamports(bigint_to_u64(&lamports)?))
     }
 
+    #[napi]
+    /// Derives the faucet keypair from the given 32-byte seed
+    pub fn set_faucet_seed(&mut self, seed: &[u8]) -> Result<()> {
+        let seed: [u8; 32] = seed.try_into().map_err(|_| {
+            Error::new(
+                Status::InvalidArg,
+                format!("Faucet seed must be 32 bytes, got {}", seed.len()),
+            )
+        })?;
+        self.0.set_faucet_seed(seed);
+        Ok(())
+    }
+
+    #[napi]
+    /// Derives the genesis hash, faucet keypair, warped slot hashes and
+    /// generated program IDs from the given seed
+    pub fn set_rng_seed(&mut self, seed: BigInt) -> Result<()> {
+        self.0.set_rng_seed(bigint_to_u64(&seed)?);
+        Ok(())
+    }
+
+    #[napi]
+    /// Changes the base fee charged per signature
+    pub fn set_lamports_per_signature(&mut self, lamports_per_signature: BigInt) -> Result<()> {
+        Ok(self
+            .0
+            .set_lamports_per_signature(bigint_to_u64(&lamports_per_signature)?))
+    }
+
+    #[napi]
+    /// Changes for how many finalized blocks a blockhash is accepted.
+    pub fn set_blockhash_expiry_slots(&mut self, slots: BigInt) -> Result<()> {
+        Ok(self.0.set_blockhash_expiry_slots(bigint_to_u64(&slots)?))
+    }
+
+    #[napi]
+    /// Rejects transactions whose blockhash is more than `slots` blocks old,
+    /// even if it is still within the expiry window.
+    pub fn set_max_blockhash_age(&mut self, slots: BigInt) -> Result<()> {
+        Ok(self.0.set_max_blockhash_age(bigint_to_u64(&slots)?))
+    }
+
     #[napi]
     /// Includes the standard SPL programs
     pub fn set_default_programs(&mut self) {
         self.0.set_default_programs();
     }
 
+    #[napi]
+    /// Includes the SPL Associated Token Account program
+    pub fn set_spl_associated_token_account_program(&mut self) {
+        self.0.set_spl_associated_token_account_program();
+    }
+
+    #[napi]
+    /// Includes the Config program
+    pub fn set_config_program(&mut self) {
+        self.0.set_config_program();
+    }
+
     #[napi]
     /// Changes the capacity of the transaction history.
     /// Set this to 0 to disable transaction history and allow duplicate transactions.
@@ -154,6 +304,12 @@ impl LiteSvm {
         Ok(self.0.set_transaction_history(bigint_to_usize(&capacity)?))
     }
 
+    #[napi]
+    /// Enables or disables rejecting duplicate transactions with `AlreadyProcessed`.
+    pub fn set_transaction_dedup(&mut self, enabled: bool) {
+        self.0.set_transaction_dedup(enabled);
+    }
+
     #[napi]
     pub fn set_log_bytes_limit(&mut self, limit: Option<BigInt>) -> Result<()> {
         Ok(match limit {
@@ -165,11 +321,65 @@ impl LiteSvm {
         })
     }
 
+    #[napi]
+    /// Changes how long a slot lasts when converting between time and slots.
+    pub fn set_slot_duration_ms(&mut self, slot_duration_ms: BigInt) -> Result<()> {
+        Ok(self
+            .0
+            .set_slot_duration_ms(bigint_to_u64(&slot_duration_ms)?))
+    }
+
+    #[napi]
+    /// Caps how deeply instructions may nest cross-program invocations.
+    pub fn set_max_cpi_depth(&mut self, depth: u32) {
+        self.0.set_max_cpi_depth(depth as usize);
+    }
+
+    #[napi]
+    /// Enables or disables rent collection
+    pub fn set_rent_collection(&mut self, enabled: bool) {
+        self.0.set_rent_collection(enabled);
+    }
+
+    #[napi]
+    /// Enables or disables rejecting accounts left below the rent-exempt minimum
+    pub fn set_require_rent_exempt(&mut self, enabled: bool) {
+        self.0.set_require_rent_exempt(enabled);
+    }
+
+    #[napi]
+    /// Enables or disables recording token account balances before and after each transaction
+    pub fn set_token_balance_recording(&mut self, enabled: bool) {
+        self.0.set_token_balance_recording(enabled);
+    }
+
+    #[napi]
+    /// Enables or disables recording the call graph of each transaction
+    pub fn set_execution_trace(&mut self, enabled: bool) {
+        self.0.set_execution_trace(enabled);
+    }
+
+    #[napi]
+    /// Enables or disables recording the compute units left after each top-level instruction
+    pub fn set_capture_compute_units_remaining(&mut self, enabled: bool) {
+        self.0.set_capture_compute_units_remaining(enabled);
+    }
+
     #[napi]
     pub fn set_precompiles(&mut self) {
         self.0.set_precompiles();
     }
 
+    #[napi]
+    /// Returns the ids of the precompiles whose account is installed and whose feature is active.
+    pub fn get_installed_precompiles(&self) -> Vec<Uint8Array> {
+        self.0
+            .installed_precompiles()
+            .into_iter()
+            .map(Uint8Array::with_data_copied)
+            .collect()
+    }
+
     #[napi]
     /// Returns minimum balance required to make an account with specified data length rent exempt.
     pub fn minimum_balance_for_rent_exemption(&self, data_len: BigInt) -> Result<u64> {
@@ -178,12 +388,116 @@ impl LiteSvm {
             .minimum_balance_for_rent_exemption(bigint_to_usize(&data_len)?))
     }
 
+    #[napi]
+    /// Returns the minimum stake delegation for the configured feature set.
+    pub fn get_minimum_delegation(&self) -> u64 {
+        self.0.get_minimum_delegation()
+    }
+
     #[napi]
     /// Returns all information associated with the account of the provided pubkey.
     pub fn get_account(&self, pubkey: &[u8]) -> Option<Account> {
         self.0.get_account(&convert_pubkey(pubkey)).map(Account)
     }
 
+    #[napi]
+    /// Like `get_account`, but returns an empty, zero-lamport, system-owned
+    /// account when there is none.
+    pub fn get_account_or_default(&self, pubkey: &[u8]) -> Account {
+        Account(
+            self.0
+                .get_account_or_default(&convert_pubkey(pubkey))
+                .into(),
+        )
+    }
+
+    #[napi]
+    /// Returns the lamports, data length, owner and executable flag of an account
+    /// without copying its data.
+    pub fn get_account_summary(&self, pubkey: &[u8]) -> Option<AccountSummary> {
+        self.0
+            .get_account_summary(&convert_pubkey(pubkey))
+            .map(AccountSummary)
+    }
+
+    #[napi]
+    /// Returns the length of an account's data without copying it.
+    pub fn get_account_data_len(&self, pubkey: &[u8]) -> Option<u64> {
+        self.0
+            .get_account_data_len(&convert_pubkey(pubkey))
+            .map(|len| len as u64)
+    }
+
+    #[napi]
+    /// Returns whether an account is executable without copying it.
+    pub fn is_executable(&self, pubkey: &[u8]) -> Option<bool> {
+        self.0.is_executable(&convert_pubkey(pubkey))
+    }
+
+    #[napi]
+    /// Returns the decimals, supply and authorities of an SPL Token or Token-2022 mint.
+    pub fn get_mint_info(&self, mint: &[u8]) -> Option<MintInfo> {
+        self.0.get_mint_info(&convert_pubkey(mint)).map(MintInfo)
+    }
+
+    #[napi]
+    /// Returns the total supply of an SPL Token or Token-2022 mint.
+    pub fn get_token_supply(&self, mint: &[u8]) -> Option<u64> {
+        self.0.get_token_supply(&convert_pubkey(mint))
+    }
+
+    #[napi]
+    /// Returns the authority, deactivation slot and addresses of an address
+    /// lookup table, or null if there is no lookup table at that address.
+    pub fn get_address_lookup_table(&self, address: &[u8]) -> Option<AddressLookupTable> {
+        self.0
+            .get_address_lookup_table(&convert_pubkey(address))
+            .map(AddressLookupTable)
+    }
+
+    #[napi]
+    /// Returns the activation of a stake account at the current epoch,
+    /// or null if there is no initialized stake account at that address.
+    pub fn get_stake_activation(&self, stake_account: &[u8]) -> Option<StakeActivation> {
+        self.0
+            .get_stake_activation(&convert_pubkey(stake_account))
+            .map(StakeActivation::from)
+    }
+
+    #[napi]
+    /// Returns how the program is executed, e.g. "Builtin" or "BpfLoaderUpgradeable",
+    /// or null if there is no executable account at that address.
+    pub fn program_kind(&self, program_id: &[u8]) -> Option<String> {
+        self.0
+            .program_kind(&convert_pubkey(program_id))
+            .map(|kind| format!("{kind:?}"))
+    }
+
+    #[napi]
+    /// Returns the slot at which the account was last written.
+    pub fn get_account_modified_slot(&self, pubkey: &[u8]) -> Option<u64> {
+        self.0.get_account_modified_slot(&convert_pubkey(pubkey))
+    }
+
+    #[napi]
+    /// Creates an uninitialized, rent-exempt account owned by `program_id` at the
+    /// program derived address of `seeds`, funded by the payer. Returns the address.
+    pub fn create_pda_account(
+        &mut self,
+        program_id: &[u8],
+        seeds: Vec<Uint8Array>,
+        space: u32,
+        payer_secret_key: &[u8],
+    ) -> Result<Uint8Array> {
+        let payer = Keypair::try_from(payer_secret_key)
+            .map_err(|e| Error::new(Status::InvalidArg, format!("Invalid keypair: {e}")))?;
+        let seeds: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_ref()).collect();
+        self.0
+            .create_pda_account(&convert_pubkey(program_id), &seeds, space as usize, &payer)
+            .map(Uint8Array::with_data_copied)
+            .map_err(|e| to_js_error(e, "Failed to create PDA account"))
+    }
+
     #[napi]
     /// Sets all information associated with the account of the provided pubkey.
     pub fn set_account(&mut self, pubkey: &[u8], data: &Account) -> Result<()> {
@@ -192,6 +506,98 @@ impl LiteSvm {
             .map_err(|e| to_js_error(e, "Failed to set account"))
     }
 
+    #[napi]
+    /// Sets many accounts in one call, pairing `addresses` with `accounts` by index.
+    /// Nothing is applied if any entry fails.
+    pub fn set_accounts(
+        &mut self,
+        addresses: Vec<Uint8Array>,
+        accounts: Vec<ClassInstance<Account>>,
+    ) -> Result<()> {
+        if addresses.len() != accounts.len() {
+            return Err(Error::new(
+                Status::InvalidArg,
+                "addresses and accounts must have the same length",
+            ));
+        }
+        let entries: Vec<_> = addresses
+            .iter()
+            .zip(&accounts)
+            .map(|(address, account)| (convert_pubkey(address), account.0.clone().into()))
+            .collect();
+        self.0
+            .set_accounts(&entries)
+            .map_err(|e| to_js_error(e, "Failed to set accounts"))
+    }
+
+    #[napi]
+    /// Makes sure every executable account has a compiled entry in the program cache.
+    pub fn warm_program_cache(&mut self) -> Result<()> {
+        self.0
+            .warm_program_cache()
+            .map_err(|e| to_js_error(e, "Failed to warm program cache"))
+    }
+
+    #[napi]
+    /// Overwrites a slice of an existing account's data, starting at `offset`.
+    pub fn set_account_data(&mut self, pubkey: &[u8], offset: BigInt, bytes: &[u8]) -> Result<()> {
+        self.0
+            .set_account_data(&convert_pubkey(pubkey), bigint_to_usize(&offset)?, bytes)
+            .map_err(|e| to_js_error(e, "Failed to set account data"))
+    }
+
+    #[napi]
+    /// Creates an initialized, rent-exempt SPL Token mint. Fails if the account already exists.
+    pub fn create_token_mint(
+        &mut self,
+        mint: &[u8],
+        mint_authority: &[u8],
+        decimals: u8,
+    ) -> Result<()> {
+        self.0
+            .create_token_mint(
+                &convert_pubkey(mint),
+                &convert_pubkey(mint_authority),
+                decimals,
+            )
+            .map_err(|e| to_js_error(e, "Failed to create token mint"))
+    }
+
+    #[napi]
+    /// Creates an initialized, rent-exempt durable nonce account. Fails if the account already exists.
+    pub fn create_nonce_account(&mut self, nonce_account: &[u8], authority: &[u8]) -> Result<()> {
+        self.0
+            .create_nonce_account(&convert_pubkey(nonce_account), &convert_pubkey(authority))
+            .map_err(|e| to_js_error(e, "Failed to create nonce account"))
+    }
+
+    #[napi]
+    /// Creates an active, rent-exempt address lookup table holding `addresses`.
+    /// Fails if the account already exists.
+    pub fn create_address_lookup_table(
+        &mut self,
+        address: &[u8],
+        authority: &[u8],
+        addresses: Vec<Uint8Array>,
+    ) -> Result<()> {
+        let addresses: Vec<Pubkey> = addresses.iter().map(|a| convert_pubkey(a)).collect();
+        self.0
+            .create_address_lookup_table(
+                &convert_pubkey(address),
+                &convert_pubkey(authority),
+                &addresses,
+            )
+            .map_err(|e| to_js_error(e, "Failed to create address lookup table"))
+    }
+
+    #[napi]
+    /// Sets the lamports of an existing account, leaving the rest of it untouched.
+    pub fn set_account_lamports(&mut self, pubkey: &[u8], lamports: BigInt) -> Result<()> {
+        self.0
+            .set_account_lamports(&convert_pubkey(pubkey), bigint_to_u64(&lamports)?)
+            .map_err(|e| to_js_error(e, "Failed to set account lamports"))
+    }
+
     #[napi]
     /// Gets the balance of the provided account pubkey.
     pub fn get_balance(&self, pubkey: &[u8]) -> Option<u64> {
@@ -204,6 +610,114 @@ impl LiteSvm {
         self.0.latest_blockhash().to_string()
     }
 
+    #[napi]
+    /// Returns the last block height at which the latest blockhash is accepted.
+    pub fn latest_blockhash_last_valid_block_height(&self) -> u64 {
+        self.0.latest_blockhash_info().last_valid_block_height
+    }
+
+    #[napi]
+    /// Gets the genesis hash.
+    pub fn get_genesis_hash(&self) -> String {
+        self.0.genesis_hash().to_string()
+    }
+
+    #[napi]
+    /// Sets the genesis hash.
+    pub fn set_genesis_hash(&mut self, hash: String) -> Result<()> {
+        self.0.set_genesis_hash(try_parse_hash(&hash)?);
+        Ok(())
+    }
+
+    #[napi]
+    /// Returns the fee for a serialized message, or null if the message is invalid.
+    pub fn get_fee_for_message(&self, message_bytes: &[u8]) -> Option<u64> {
+        let message: VersionedMessage = deserialize(message_bytes).ok()?;
+        self.0.get_fee_for_message(message).ok()
+    }
+
+    #[napi]
+    /// Returns the accounts a serialized transaction would lock, with lookup
+    /// tables resolved, or null if the transaction is invalid.
+    pub fn get_account_locks(&self, tx_bytes: &[u8]) -> Option<AccountLocks> {
+        let tx: VersionedTransaction = deserialize(tx_bytes).ok()?;
+        self.0.get_account_locks(tx).ok().map(AccountLocks)
+    }
+
+    #[napi]
+    /// Returns the signature count and fee parts for a serialized message,
+    /// or null if the message is invalid.
+    pub fn get_fee_breakdown_for_message(&self, message_bytes: &[u8]) -> Option<FeeBreakdown> {
+        let message: VersionedMessage = deserialize(message_bytes).ok()?;
+        self.0
+            .get_fee_breakdown_for_message(message)
+            .ok()
+            .map(FeeBreakdown)
+    }
+
+    #[napi]
+    /// Returns the slot and compute unit price of recently processed
+    /// transactions that set a compute unit price, oldest first.
+    pub fn get_recent_prioritization_fees(&self) -> Vec<PrioritizationFee> {
+        self.0
+            .recent_prioritization_fees()
+            .into_iter()
+            .map(|(slot, prioritization_fee)| PrioritizationFee {
+                slot,
+                prioritization_fee,
+            })
+            .collect()
+    }
+
+    #[napi]
+    /// Returns how many transactions have been processed, successful or not.
+    pub fn get_transaction_count(&self) -> u64 {
+        self.0.transaction_count()
+    }
+
+    #[napi]
+    /// Returns how many times the program was entered by sent transactions,
+    /// counting both top-level instructions and CPIs.
+    pub fn get_program_invocation_count(&self, program_id: &[u8]) -> u64 {
+        self.0.program_invocation_count(&convert_pubkey(program_id))
+    }
+
+    #[napi]
+    /// Returns cumulative execution metrics.
+    pub fn get_metrics(&self) -> SvmMetrics {
+        self.0.metrics().into()
+    }
+
+    #[napi]
+    /// Returns every account, sorted by address, plus the clock and latest blockhash
+    /// as a deterministic JSON string.
+    pub fn dump_state_json(&self, include_sysvars: bool) -> String {
+        state_json::dump_state(&self.0, include_sysvars)
+    }
+
+    #[napi]
+    /// Sets any of the clock, rent and epoch schedule sysvars in one step.
+    /// Omitted ones are left unchanged.
+    pub fn set_sysvars_bulk(
+        &mut self,
+        clock: Option<&Clock>,
+        rent: Option<&Rent>,
+        epoch_schedule: Option<&EpochSchedule>,
+    ) {
+        self.0.set_sysvars_bulk(
+            clock.map(|clock| clock.0.clone()),
+            rent.map(|rent| rent.0.clone()),
+            epoch_schedule.map(|schedule| schedule.0.clone()),
+        )
+    }
+
+    #[napi]
+    /// Restores the accounts, clock and latest blockhash from a string produced by
+    /// `dump_state_json`. Accounts missing from it are removed, except sysvars, builtins and the faucet.
+    pub fn load_state_json(&mut self, json: String) -> Result<()> {
+        state_json::load_state(&mut self.0, &json)
+    }
+
     #[napi(ts_return_type = "TransactionMetadata | FailedTransactionMetadata | null")]
     /// Gets a transaction from the transaction history.
     pub fn get_transaction(&self, signature: &[u8]) -> Option<TransactionResult> {
@@ -215,10 +729,103 @@ impl LiteSvm {
     #[napi(ts_return_type = "TransactionMetadata | FailedTransactionMetadata | null")]
     /// Airdrops the account with the lamports specified.
     pub fn airdrop(&mut self, pubkey: &[u8], lamports: BigInt) -> Result<TransactionResult> {
-        Ok(convert_transaction_result(self.0.airdrop(
-            &convert_pubkey(pubkey),
-            bigint_to_u64(&lamports)?,
-        )))
+        let res = self
+            .0
+            .airdrop(&convert_pubkey(pubkey), bigint_to_u64(&lamports)?);
+        Ok(self.record_result(res))
+    }
+
+    #[napi]
+    /// Returns the pubkey of the internal airdrop account.
+    pub fn airdrop_pubkey(&self) -> Uint8Array {
+        Uint8Array::with_data_copied(self.0.airdrop_pubkey())
+    }
+
+    #[napi]
+    /// Returns the balance the address would have after an airdrop of
+    /// `lamports`, without sending anything.
+    pub fn preview_airdrop(&self, pubkey: &[u8], lamports: BigInt) -> Result<u64> {
+        Ok(self
+            .0
+            .preview_airdrop(&convert_pubkey(pubkey), bigint_to_u64(&lamports)?))
+    }
+
+    #[napi(ts_return_type = "TransactionMetadata | FailedTransactionMetadata")]
+    /// Sends lamports from `from` to every recipient in a single transaction.
+    /// `recipients` and `lamports` are parallel arrays.
+    pub fn transfer_many(
+        &mut self,
+        from_secret_key: &[u8],
+        recipients: Vec<Uint8Array>,
+        lamports: Vec<BigInt>,
+    ) -> Result<TransactionResult> {
+        let from = Keypair::try_from(from_secret_key)
+            .map_err(|e| Error::new(Status::InvalidArg, format!("Invalid keypair: {e}")))?;
+        if recipients.len() != lamports.len() {
+            return Err(Error::new(
+                Status::InvalidArg,
+                "recipients and lamports must have the same length",
+            ));
+        }
+        let transfers = recipients
+            .iter()
+            .zip(lamports.iter())
+            .map(|(recipient, lamports)| Ok((convert_pubkey(recipient), bigint_to_u64(lamports)?)))
+            .collect::<Result<Vec<_>>>()?;
+        let res = self.0.transfer_many(&from, &transfers);
+        Ok(self.record_result(res))
+    }
+
+    #[napi(ts_return_type = "TransactionMetadata | FailedTransactionMetadata")]
+    /// Sends a transaction containing only the instruction built from `program_id`,
+    /// the account metas given as parallel `keys`, `is_signer` and `is_writable`
+    /// arrays, and `data`. The first signer pays the fee.
+    pub fn process_instruction(
+        &mut self,
+        program_id: &[u8],
+        keys: Vec<Uint8Array>,
+        is_signer: Vec<bool>,
+        is_writable: Vec<bool>,
+        data: &[u8],
+        signer_secret_keys: Vec<Uint8Array>,
+    ) -> Result<TransactionResult> {
+        if keys.len() != is_signer.len() || keys.len() != is_writable.len() {
+            return Err(Error::new(
+                Status::InvalidArg,
+                "keys, isSigner and isWritable must have the same length",
+            ));
+        }
+        let accounts = keys
+            .iter()
+            .zip(is_signer)
+            .zip(is_writable)
+            .map(|((pubkey, is_signer), is_writable)| AccountMeta {
+                pubkey: convert_pubkey(pubkey),
+                is_signer,
+                is_writable,
+            })
+            .collect();
+        let instruction = Instruction {
+            program_id: convert_pubkey(program_id),
+            accounts,
+            data: data.to_vec(),
+        };
+        let signers = signer_secret_keys
+            .iter()
+            .map(|secret_key| {
+                Keypair::try_from(secret_key.as_ref())
+                    .map_err(|e| Error::new(Status::InvalidArg, format!("Invalid keypair: {e}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if signers.is_empty() {
+            return Err(Error::new(
+                Status::InvalidArg,
+                "At least one signer is required",
+            ));
+        }
+        let signer_refs: Vec<&Keypair> = signers.iter().collect();
+        let res = self.0.process_instruction(instruction, &signer_refs);
+        Ok(self.record_result(res))
     }
 
     #[napi]
@@ -247,51 +854,190 @@ impl LiteSvm {
             })
     }
 
+    #[napi]
+    /// Adds an SBF program at a freshly generated program ID and returns the ID.
+    pub fn deploy_program(&mut self, program_bytes: &[u8]) -> Result<Uint8Array> {
+        let program_id = self.0.next_unique_pubkey();
+        self.0
+            .add_program(program_id, program_bytes)
+            .map_err(|e| to_js_error(e, "Failed to add program"))?;
+        Ok(Uint8Array::with_data_copied(program_id))
+    }
+
+    #[napi]
+    /// Deploys an SBF program under the upgradeable loader, creating both the
+    /// program account and its programdata account.
+    pub fn deploy_upgradeable_program(
+        &mut self,
+        program_id: &[u8],
+        programdata_address: &[u8],
+        program_bytes: &[u8],
+        upgrade_authority: Option<Uint8Array>,
+    ) -> Result<()> {
+        self.0
+            .deploy_upgradeable_program(
+                convert_pubkey(program_id),
+                convert_pubkey(programdata_address),
+                program_bytes,
+                upgrade_authority.map(|x| convert_pubkey(&x)),
+            )
+            .map_err(|e| to_js_error(e, "Failed to deploy program"))
+    }
+
     #[napi(ts_return_type = "TransactionMetadata | FailedTransactionMetadata")]
     pub fn send_legacy_transaction(&mut self, tx_bytes: &[u8]) -> TransactionResult {
         let tx: Transaction = deserialize(tx_bytes).unwrap();
         let res = self.0.send_transaction(tx);
-        convert_transaction_result(res)
+        self.record_result(res)
     }
 
     #[napi(ts_return_type = "TransactionMetadata | FailedTransactionMetadata")]
     pub fn send_versioned_transaction(&mut self, tx_bytes: &[u8]) -> TransactionResult {
         let tx: VersionedTransaction = deserialize(tx_bytes).unwrap();
         let res = self.0.send_transaction(tx);
-        convert_transaction_result(res)
+        self.record_result(res)
     }
 
     #[napi(ts_return_type = "SimulatedTransactionInfo | FailedTransactionMetadata")]
     pub fn simulate_legacy_transaction(&mut self, tx_bytes: &[u8]) -> SimulateResult {
         let tx: Transaction = deserialize(tx_bytes).unwrap();
         let res = self.0.simulate_transaction(tx);
-        convert_sim_result(res)
+        self.record_sim_result(res)
     }
 
     #[napi(ts_return_type = "SimulatedTransactionInfo | FailedTransactionMetadata")]
     pub fn simulate_versioned_transaction(&mut self, tx_bytes: &[u8]) -> SimulateResult {
         let tx: VersionedTransaction = deserialize(tx_bytes).unwrap();
         let res = self.0.simulate_transaction(tx);
-        convert_sim_result(res)
+        self.record_sim_result(res)
+    }
+
+    #[napi(ts_return_type = "SimulatedTransactionInfo | FailedTransactionMetadata")]
+    /// Simulates a transaction after replacing its recent blockhash with the
+    /// latest blockhash. Signatures are not verified.
+    pub fn simulate_transaction_replace_blockhash(&mut self, tx_bytes: &[u8]) -> SimulateResult {
+        let tx: VersionedTransaction = deserialize(tx_bytes).unwrap();
+        let res = self.0.simulate_transaction_replace_blockhash(tx);
+        self.record_sim_result(res)
+    }
+
+    #[napi(ts_return_type = "Array<SimulatedTransactionInfo | FailedTransactionMetadata>")]
+    /// Simulates each transaction independently against the current state.
+    /// The serialized transactions are concatenated, each prefixed with its
+    /// length as a little-endian u32.
+    pub fn simulate_transactions(&mut self, txs_bytes: &[u8]) -> Result<Vec<SimulateResult>> {
+        let mut txs = Vec::new();
+        let mut rest = txs_bytes;
+        while !rest.is_empty() {
+            let (len, tail) = rest.split_first_chunk::<4>().ok_or_else(|| {
+                Error::new(Status::InvalidArg, "Truncated transaction length prefix")
+            })?;
+            let len = u32::from_le_bytes(*len) as usize;
+            if tail.len() < len {
+                return Err(Error::new(Status::InvalidArg, "Truncated transaction"));
+            }
+            let (tx_bytes, tail) = tail.split_at(len);
+            let tx: VersionedTransaction = deserialize(tx_bytes)
+                .map_err(|e| Error::new(Status::InvalidArg, format!("Invalid transaction: {e}")))?;
+            txs.push(tx);
+            rest = tail;
+        }
+        let results = self.0.simulate_transactions(&txs);
+        Ok(results
+            .into_iter()
+            .map(|res| self.record_sim_result(res))
+            .collect())
     }
 
     #[napi]
-    /// Expires the current blockhash
+    /// Returns the log lines of the transaction most recently sent or
+    /// simulated by this instance. After `simulate_transactions`, these are
+    /// the logs of the last transaction in the batch.
+    pub fn last_transaction_logs(&self) -> Vec<String> {
+        self.1.clone()
+    }
+
+    #[napi]
+    /// Whether any log line of the transaction most recently sent or
+    /// simulated by this instance contains `needle`.
+    pub fn last_logs_contain(&self, needle: String) -> bool {
+        self.1.iter().any(|log| log.contains(&needle))
+    }
+
+    #[napi]
+    /// Expires the current blockhash and every earlier one
     pub fn expire_blockhash(&mut self) {
         self.0.expire_blockhash()
     }
 
+    #[napi]
+    /// Ends the current block: rotates the blockhash, increments the block
+    /// height and, if `advance_slot` is set, moves to the next slot.
+    pub fn finalize_block(&mut self, advance_slot: bool) {
+        self.0.finalize_block(advance_slot)
+    }
+
+    #[napi]
+    /// Returns the block height, which only moves with `finalize_block`.
+    pub fn get_block_height(&self) -> u64 {
+        self.0.block_height()
+    }
+
+    #[napi]
+    /// Sets the block height.
+    pub fn set_block_height(&mut self, height: BigInt) -> Result<()> {
+        Ok(self.0.set_block_height(bigint_to_u64(&height)?))
+    }
+
+    #[napi]
+    /// Restores the clock to the one the instance started with.
+    pub fn reset_clock(&mut self) {
+        self.0.reset_clock()
+    }
+
     #[napi]
     /// Warps the clock to the specified slot
     pub fn warp_to_slot(&mut self, slot: BigInt) -> Result<()> {
         Ok(self.0.warp_to_slot(bigint_to_u64(&slot)?))
     }
 
+    #[napi]
+    /// Warps the clock to the specified slot, aging blockhashes by one block for
+    /// every slot skipped if `expire_blockhashes` is set.
+    pub fn warp_to_slot_with_blockhash_expiry(
+        &mut self,
+        slot: BigInt,
+        expire_blockhashes: bool,
+    ) -> Result<()> {
+        Ok(self
+            .0
+            .warp_to_slot_with_blockhash_expiry(bigint_to_u64(&slot)?, expire_blockhashes))
+    }
+
+    #[napi]
+    /// Moves the clock forward by the given number of seconds
+    pub fn warp_forward_seconds(&mut self, seconds: BigInt) -> Result<()> {
+        Ok(self.0.warp_forward_seconds(bigint_to_i64(&seconds)?))
+    }
+
     #[napi]
     pub fn get_compute_budget(&self) -> Option<ComputeBudget> {
         self.0.get_compute_budget().map(ComputeBudget)
     }
 
+    #[napi]
+    /// Returns the compute budget in effect: the one set with
+    /// `set_compute_budget`, or else the defaults.
+    pub fn current_compute_budget(&self) -> ComputeBudget {
+        ComputeBudget(self.0.current_compute_budget())
+    }
+
+    #[napi]
+    /// Returns the fee rate governor in effect.
+    pub fn fee_rate_governor(&self) -> FeeRateGovernor {
+        self.0.fee_rate_governor().into()
+    }
+
     #[napi]
     pub fn get_sigverify(&self) -> bool {
         self.0.get_sigverify()
@@ -299,12 +1045,24 @@ impl LiteSvm {
 
     #[napi]
     pub fn get_clock(&self) -> Clock {
-        Clock(self.0.get_sysvar::<ClockOriginal>())
+        Clock(self.0.get_clock())
     }
 
     #[napi]
     pub fn set_clock(&mut self, clock: &Clock) {
-        self.0.set_sysvar(&clock.0)
+        self.0.set_clock(&clock.0)
+    }
+
+    #[napi]
+    /// Sets only the clock's unix timestamp, leaving the other fields as they are.
+    pub fn set_unix_timestamp(&mut self, unix_timestamp: BigInt) -> Result<()> {
+        Ok(self.0.set_unix_timestamp(bigint_to_i64(&unix_timestamp)?))
+    }
+
+    #[napi]
+    /// Sets only the clock's slot, leaving the other fields as they are.
+    pub fn set_slot(&mut self, slot: BigInt) -> Result<()> {
+        Ok(self.0.set_slot(bigint_to_u64(&slot)?))
     }
 
     #[napi]
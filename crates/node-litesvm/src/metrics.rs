@@ -0,0 +1,28 @@
+use {litesvm::types::SvmMetrics as SvmMetricsOriginal, napi::bindgen_prelude::*};
+
+/// Cumulative execution counters, useful for tracking performance regressions.
+#[napi(object)]
+pub struct SvmMetrics {
+    /// Total compute units consumed by all transactions.
+    pub compute_units_consumed: BigInt,
+    /// Total number of transactions processed, successful or not.
+    pub transactions: BigInt,
+    /// Total number of top-level instructions executed.
+    pub instructions: BigInt,
+    /// Total number of program invocations, including CPIs.
+    pub program_invocations: BigInt,
+    /// Wall-clock time spent executing transactions, in nanoseconds.
+    pub execution_time_nanos: BigInt,
+}
+
+impl From<SvmMetricsOriginal> for SvmMetrics {
+    fn from(metrics: SvmMetricsOriginal) -> Self {
+        Self {
+            compute_units_consumed: metrics.compute_units_consumed.into(),
+            transactions: metrics.transactions.into(),
+            instructions: metrics.instructions.into(),
+            program_invocations: metrics.program_invocations.into(),
+            execution_time_nanos: metrics.execution_time.as_nanos().into(),
+        }
+    }
+}
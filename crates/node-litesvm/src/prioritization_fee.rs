@@ -0,0 +1,25 @@
+use crate::to_string_js;
+
+/// The compute unit price a recent transaction paid in a given slot.
+#[derive(Debug, Clone)]
+#[napi]
+pub struct PrioritizationFee {
+    pub(crate) slot: u64,
+    pub(crate) prioritization_fee: u64,
+}
+
+#[napi]
+impl PrioritizationFee {
+    #[napi]
+    pub fn slot(&self) -> u64 {
+        self.slot
+    }
+
+    #[napi]
+    /// The compute unit price, in micro-lamports per compute unit.
+    pub fn prioritization_fee(&self) -> u64 {
+        self.prioritization_fee
+    }
+}
+
+to_string_js!(PrioritizationFee);
@@ -0,0 +1,153 @@
+use {
+    base64::{engine::general_purpose::STANDARD, Engine},
+    litesvm::LiteSVM as LiteSVMOriginal,
+    napi::bindgen_prelude::*,
+    serde::{Deserialize, Serialize},
+    solana_account::{Account, ReadableAccount},
+    solana_clock::Clock,
+    solana_hash::Hash,
+    solana_pubkey::Pubkey,
+    std::str::FromStr,
+};
+
+// Integers are written as strings so they survive JSON.parse in JS.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StateSnapshot {
+    latest_blockhash: String,
+    clock: ClockSnapshot,
+    accounts: Vec<AccountSnapshot>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClockSnapshot {
+    slot: String,
+    epoch_start_timestamp: String,
+    epoch: String,
+    leader_schedule_epoch: String,
+    unix_timestamp: String,
+}
+
+impl ClockSnapshot {
+    fn to_clock(&self) -> Result<Clock> {
+        Ok(Clock {
+            slot: parse(&self.slot)?,
+            epoch_start_timestamp: parse(&self.epoch_start_timestamp)?,
+            epoch: parse(&self.epoch)?,
+            leader_schedule_epoch: parse(&self.leader_schedule_epoch)?,
+            unix_timestamp: parse(&self.unix_timestamp)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountSnapshot {
+    address: String,
+    lamports: String,
+    owner: String,
+    executable: bool,
+    rent_epoch: String,
+    data: String,
+}
+
+impl AccountSnapshot {
+    fn to_account(&self) -> Result<(Pubkey, Account)> {
+        Ok((
+            parse(&self.address)?,
+            Account {
+                lamports: parse(&self.lamports)?,
+                data: STANDARD.decode(&self.data).map_err(invalid_state)?,
+                owner: parse(&self.owner)?,
+                executable: self.executable,
+                rent_epoch: parse(&self.rent_epoch)?,
+            },
+        ))
+    }
+}
+
+fn invalid_state(msg: impl std::fmt::Display) -> Error {
+    Error::new(Status::GenericFailure, format!("Invalid state JSON: {msg}"))
+}
+
+fn parse<T: FromStr>(raw: &str) -> Result<T>
+where
+    T::Err: std::fmt::Display,
+{
+    raw.parse().map_err(invalid_state)
+}
+
+pub(crate) fn dump_state(svm: &LiteSVMOriginal, include_sysvars: bool) -> String {
+    let clock = svm.get_sysvar::<Clock>();
+    let mut accounts: Vec<_> = svm
+        .accounts_db()
+        .inner
+        .iter()
+        .filter(|(_, account)| account.owner() != &solana_sdk_ids::sysvar::ID)
+        .map(|(address, account)| (*address, account.clone()))
+        .collect();
+    if include_sysvars {
+        accounts.extend(svm.sysvar_accounts());
+    }
+    accounts.sort_unstable_by_key(|(address, _)| *address);
+    let snapshot = StateSnapshot {
+        latest_blockhash: svm.latest_blockhash().to_string(),
+        clock: ClockSnapshot {
+            slot: clock.slot.to_string(),
+            epoch_start_timestamp: clock.epoch_start_timestamp.to_string(),
+            epoch: clock.epoch.to_string(),
+            leader_schedule_epoch: clock.leader_schedule_epoch.to_string(),
+            unix_timestamp: clock.unix_timestamp.to_string(),
+        },
+        accounts: accounts
+            .into_iter()
+            .map(|(address, account)| AccountSnapshot {
+                address: address.to_string(),
+                lamports: account.lamports().to_string(),
+                owner: account.owner().to_string(),
+                executable: account.executable(),
+                rent_epoch: account.rent_epoch().to_string(),
+                data: STANDARD.encode(account.data()),
+            })
+            .collect(),
+    };
+    serde_json::to_string(&snapshot).unwrap()
+}
+
+pub(crate) fn load_state(svm: &mut LiteSVMOriginal, json: &str) -> Result<()> {
+    let snapshot: StateSnapshot = serde_json::from_str(json).map_err(invalid_state)?;
+    let latest_blockhash: Hash = parse(&snapshot.latest_blockhash)?;
+    let clock = snapshot.clock.to_clock()?;
+    let mut accounts = snapshot
+        .accounts
+        .iter()
+        .map(AccountSnapshot::to_account)
+        .collect::<Result<Vec<_>>>()?;
+    // Sysvars, builtins and the faucet are left in place, since a dump may leave them out.
+    let faucet = svm.airdrop_pubkey();
+    let stale: Vec<Pubkey> = svm
+        .accounts_db()
+        .inner
+        .iter()
+        .filter(|(address, account)| {
+            **address != faucet
+                && account.owner() != &solana_sdk_ids::sysvar::ID
+                && account.owner() != &solana_sdk_ids::native_loader::ID
+        })
+        .map(|(address, _)| *address)
+        .collect();
+    for address in stale {
+        svm.set_account(address, Account::default())
+            .map_err(|e| invalid_state(format!("account {address}: {e}")))?;
+    }
+    // Programs are loaded after the accounts they depend on, such as program data.
+    accounts.sort_by_key(|(_, account)| account.executable);
+    for (address, account) in accounts {
+        svm.set_account(address, account)
+            .map_err(|e| invalid_state(format!("account {address}: {e}")))?;
+    }
+    svm.set_sysvar(&clock);
+    svm.set_latest_blockhash(latest_blockhash);
+    Ok(())
+}
@@ -3,11 +3,13 @@ use {
         account::Account,
         to_string_js,
         transaction_error::{convert_transaction_error, TransactionError},
+        util::convert_pubkey,
     },
     litesvm::types::{
+        ExecutionTrace as ExecutionTraceOriginal,
         FailedTransactionMetadata as FailedTransactionMetadataOriginal,
         SimulatedTransactionInfo as SimulatedTransactionInfoOriginal,
-        TransactionMetadata as TransactionMetadataOriginal,
+        TokenBalance as TokenBalanceOriginal, TransactionMetadata as TransactionMetadataOriginal,
     },
     napi::bindgen_prelude::*,
     solana_account::Account as AccountOriginal,
@@ -89,6 +91,103 @@ impl TransactionReturnData {
 
 to_string_js!(TransactionReturnData);
 
+#[derive(Debug, Clone)]
+#[napi]
+pub struct TokenBalance(TokenBalanceOriginal);
+
+#[napi]
+impl TokenBalance {
+    #[napi]
+    /// The index of the token account in the transaction's account keys.
+    pub fn account_index(&self) -> u8 {
+        self.0.account_index
+    }
+
+    #[napi]
+    pub fn mint(&self) -> Uint8Array {
+        Uint8Array::with_data_copied(self.0.mint)
+    }
+
+    #[napi]
+    pub fn owner(&self) -> Uint8Array {
+        Uint8Array::with_data_copied(self.0.owner)
+    }
+
+    #[napi]
+    /// The token program that owns the account.
+    pub fn program_id(&self) -> Uint8Array {
+        Uint8Array::with_data_copied(self.0.program_id)
+    }
+
+    #[napi]
+    /// The balance in base units.
+    pub fn amount(&self) -> u64 {
+        self.0.amount
+    }
+
+    #[napi]
+    pub fn decimals(&self) -> u8 {
+        self.0.decimals
+    }
+
+    #[napi]
+    /// The amount scaled down by the mint's decimals.
+    pub fn ui_amount(&self) -> f64 {
+        self.0.ui_amount
+    }
+}
+
+to_string_js!(TokenBalance);
+
+#[derive(Debug, Clone)]
+#[napi]
+pub struct ExecutionTrace(ExecutionTraceOriginal);
+
+#[napi]
+impl ExecutionTrace {
+    #[napi]
+    pub fn program_id(&self) -> Uint8Array {
+        Uint8Array::with_data_copied(self.0.program_id)
+    }
+
+    #[napi]
+    /// The index among the top-level instructions, or among the instructions
+    /// invoked by the parent.
+    pub fn instruction_index(&self) -> u32 {
+        self.0.instruction_index as u32
+    }
+
+    #[napi]
+    pub fn inner_instructions(&self) -> Vec<ExecutionTrace> {
+        self.0
+            .inner_instructions
+            .iter()
+            .cloned()
+            .map(ExecutionTrace)
+            .collect()
+    }
+
+    #[napi]
+    /// The lines this instruction logged itself.
+    pub fn logs(&self) -> Vec<String> {
+        self.0.logs.clone()
+    }
+
+    #[napi]
+    /// Compute units consumed, including by the instructions it invoked.
+    /// Builtin programs do not report their usage and are left at 0.
+    pub fn compute_units_consumed(&self) -> u64 {
+        self.0.compute_units_consumed
+    }
+
+    #[napi]
+    pub fn return_data(&self) -> Option<Uint8Array> {
+        self.0.return_data.clone().map(Uint8Array::new)
+    }
+}
+
+to_string_js!(ExecutionTrace);
+
 #[derive(Debug, Clone)]
 #[napi]
 pub struct TransactionMetadata(pub(crate) TransactionMetadataOriginal);
@@ -105,6 +204,12 @@ impl TransactionMetadata {
         self.0.logs.clone()
     }
 
+    #[napi]
+    /// Whether the logs hit the log bytes limit, so later messages were dropped.
+    pub fn logs_truncated(&self) -> bool {
+        self.0.logs_truncated
+    }
+
     #[napi]
     pub fn inner_instructions(&self) -> Vec<Vec<InnerInstruction>> {
         self.0
@@ -125,10 +230,83 @@ impl TransactionMetadata {
         TransactionReturnData(self.0.return_data.clone())
     }
 
+    #[napi]
+    pub fn fee(&self) -> u64 {
+        self.0.fee
+    }
+
+    #[napi]
+    pub fn compute_unit_limit(&self) -> u64 {
+        self.0.compute_unit_limit
+    }
+
+    #[napi]
+    /// Whether the transaction used up its whole compute unit limit.
+    pub fn hit_compute_limit(&self) -> bool {
+        self.0.hit_compute_limit()
+    }
+
     #[napi]
     pub fn pretty_logs(&self) -> String {
         self.0.pretty_logs()
     }
+
+    #[napi]
+    /// The lines the given program logged itself, including when it was
+    /// reached through CPI. Lines logged by its callers or callees and the
+    /// runtime's invoke and completion lines are left out.
+    pub fn logs_for_program(&self, program_id: &[u8]) -> Vec<String> {
+        self.0.logs_for_program(&convert_pubkey(program_id))
+    }
+
+    #[napi]
+    /// Token account balances before the transaction.
+    /// Only recorded with token balance recording enabled.
+    pub fn pre_token_balances(&self) -> Vec<TokenBalance> {
+        self.0
+            .pre_token_balances
+            .iter()
+            .copied()
+            .map(TokenBalance)
+            .collect()
+    }
+
+    #[napi]
+    /// Token account balances after the transaction.
+    /// Only recorded with token balance recording enabled.
+    pub fn post_token_balances(&self) -> Vec<TokenBalance> {
+        self.0
+            .post_token_balances
+            .iter()
+            .copied()
+            .map(TokenBalance)
+            .collect()
+    }
+
+    #[napi]
+    /// The call graph of the transaction, one tree per top-level instruction
+    /// that ran. Only recorded with execution tracing enabled.
+    pub fn execution_trace(&self) -> Vec<ExecutionTrace> {
+        self.0
+            .execution_trace
+            .iter()
+            .cloned()
+            .map(ExecutionTrace)
+            .collect()
+    }
+
+    #[napi]
+    /// Compute units left after each top-level instruction that completed.
+    /// Only recorded with compute units remaining capture enabled.
+    pub fn compute_units_remaining(&self) -> Vec<u64> {
+        self.0.compute_units_remaining.clone()
+    }
+
+    #[napi]
+    /// Always true, so a send result can be checked without `instanceof`.
+    pub fn success(&self) -> bool {
+        true
+    }
 }
 
 to_string_js!(TransactionMetadata);
@@ -150,6 +328,12 @@ impl FailedTransactionMetadata {
     pub fn meta(&self) -> TransactionMetadata {
         TransactionMetadata(self.0.meta.clone())
     }
+
+    #[napi]
+    /// Always false, so a send result can be checked without `instanceof`.
+    pub fn success(&self) -> bool {
+        false
+    }
 }
 
 to_string_js!(FailedTransactionMetadata);
@@ -191,6 +375,39 @@ impl SimulatedTransactionInfo {
             })
             .collect()
     }
+
+    #[napi]
+    /// Compute units consumed by the simulation.
+    pub fn units_consumed(&self) -> u64 {
+        self.0.meta.compute_units_consumed
+    }
+
+    #[napi]
+    /// Returns the units consumed, logs and return data as a plain object.
+    pub fn to_serializable(&self) -> SerializableSimulationInfo {
+        SerializableSimulationInfo::from(&self.0)
+    }
 }
 
 to_string_js!(SimulatedTransactionInfo);
+
+/// The parts of a successful simulation most tests look at, as a plain object.
+#[napi(object)]
+pub struct SerializableSimulationInfo {
+    /// Compute units consumed. This never exceeds the compute unit limit, so it fits a number.
+    pub units_consumed: u32,
+    pub logs: Vec<String>,
+    /// The data set with `set_return_data`, if any.
+    pub return_data: Option<Uint8Array>,
+}
+
+impl From<&SimulatedTransactionInfoOriginal> for SerializableSimulationInfo {
+    fn from(info: &SimulatedTransactionInfoOriginal) -> Self {
+        let return_data = &info.meta.return_data.data;
+        Self {
+            units_consumed: u32::try_from(info.meta.compute_units_consumed).unwrap_or(u32::MAX),
+            logs: info.meta.logs.clone(),
+            return_data: (!return_data.is_empty()).then(|| Uint8Array::new(return_data.clone())),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import { PublicKey } from "@solana/web3.js";
+
+test("create helpers reject existing accounts", () => {
+	const svm = new LiteSVM();
+	const authority = PublicKey.unique();
+
+	const mint = PublicKey.unique();
+	svm.createTokenMint(mint, authority, 6);
+	// the decimals byte follows the authority option and the supply
+	assert.strictEqual(svm.getAccount(mint)?.data[44], 6);
+	assert.throws(() => svm.createTokenMint(mint, authority, 6), {
+		message: new RegExp(`Account ${mint.toBase58()} already exists`),
+	});
+
+	const nonce = PublicKey.unique();
+	svm.createNonceAccount(nonce, authority);
+	assert.throws(() => svm.createNonceAccount(nonce, authority), {
+		message: new RegExp(`Account ${nonce.toBase58()} already exists`),
+	});
+
+	const table = PublicKey.unique();
+	const addresses = [PublicKey.unique(), PublicKey.unique()];
+	svm.createAddressLookupTable(table, authority, addresses);
+	assert.throws(
+		() => svm.createAddressLookupTable(table, authority, addresses),
+		{ message: new RegExp(`Account ${table.toBase58()} already exists`) },
+	);
+});
@@ -0,0 +1,25 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, TransactionMetadata } from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	SystemProgram,
+} from "@solana/web3.js";
+
+test("account data len", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	const account = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	svm.airdrop(account.publicKey, BigInt(LAMPORTS_PER_SOL));
+	assert.strictEqual(svm.getAccountDataLen(account.publicKey), 0n);
+	const res = svm.processInstruction(
+		SystemProgram.allocate({ accountPubkey: account.publicKey, space: 1_234 }),
+		[payer, account],
+	);
+	assert.ok(res instanceof TransactionMetadata);
+	assert.strictEqual(svm.getAccountDataLen(account.publicKey), 1_234n);
+	assert.strictEqual(svm.getAccountDataLen(PublicKey.unique()), null);
+});
@@ -0,0 +1,78 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import {
+	AddressLookupTableAccount,
+	AddressLookupTableProgram,
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	Transaction,
+	TransactionInstruction,
+	TransactionMessage,
+	VersionedTransaction,
+} from "@solana/web3.js";
+
+test("account locks with a lookup table", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const programId = PublicKey.unique();
+	const staticWritable = PublicKey.unique();
+	const tableWritable = PublicKey.unique();
+	const tableReadonly = PublicKey.unique();
+
+	const [createIx, tableAddress] = AddressLookupTableProgram.createLookupTable({
+		authority: payer.publicKey,
+		payer: payer.publicKey,
+		recentSlot: 0,
+	});
+	const extendIx = AddressLookupTableProgram.extendLookupTable({
+		lookupTable: tableAddress,
+		authority: payer.publicKey,
+		payer: payer.publicKey,
+		addresses: [tableWritable, tableReadonly],
+	});
+	const tableTx = new Transaction();
+	tableTx.recentBlockhash = svm.latestBlockhash();
+	tableTx.add(createIx, extendIx);
+	tableTx.sign(payer);
+	svm.sendTransaction(tableTx);
+	svm.warpToSlot(1n); // can't use the lookup table in the same slot
+
+	const tableAccount = svm.getAccount(tableAddress);
+	assert.ok(tableAccount !== null);
+	const lookupTable = new AddressLookupTableAccount({
+		key: tableAddress,
+		state: AddressLookupTableAccount.deserialize(tableAccount.data),
+	});
+	const message = new TransactionMessage({
+		payerKey: payer.publicKey,
+		recentBlockhash: svm.latestBlockhash(),
+		instructions: [
+			new TransactionInstruction({
+				programId,
+				keys: [
+					{ pubkey: staticWritable, isSigner: false, isWritable: true },
+					{ pubkey: tableWritable, isSigner: false, isWritable: true },
+					{ pubkey: tableReadonly, isSigner: false, isWritable: false },
+				],
+				data: Buffer.alloc(0),
+			}),
+		],
+	}).compileToV0Message([lookupTable]);
+	assert.strictEqual(message.addressTableLookups.length, 1);
+
+	const locks = svm.getAccountLocks(new VersionedTransaction(message));
+	assert.ok(locks !== null);
+	assert.deepStrictEqual(
+		locks.writable.map((address) => address.toBase58()),
+		[payer.publicKey, staticWritable, tableWritable].map((address) =>
+			address.toBase58(),
+		),
+	);
+	assert.deepStrictEqual(
+		locks.readonly.map((address) => address.toBase58()).sort(),
+		[programId, tableReadonly].map((address) => address.toBase58()).sort(),
+	);
+});
@@ -0,0 +1,30 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import { PublicKey } from "@solana/web3.js";
+
+const NATIVE_LOADER = new PublicKey("NativeLoader1111111111111111111111111111111");
+
+test("executable and rent epoch round-trip", () => {
+	const svm = new LiteSVM();
+	const address = PublicKey.unique();
+	svm.setAccount(address, {
+		lamports: 1_000_000,
+		data: new Uint8Array([1, 2, 3]),
+		owner: NATIVE_LOADER,
+		executable: true,
+		rentEpoch: 42,
+	});
+	const account = svm.getAccount(address);
+	assert.strictEqual(account.executable, true);
+	assert.strictEqual(account.rentEpoch, 42);
+	assert.ok(account.owner.equals(NATIVE_LOADER));
+
+	// u64::MAX comes back as 2^64 but can still be written again
+	const exempt = PublicKey.unique();
+	svm.setAccount(exempt, { ...account, rentEpoch: 2 ** 64 - 1 });
+	const readBack = svm.getAccount(exempt);
+	assert.strictEqual(readBack.rentEpoch, 2 ** 64);
+	svm.setAccount(exempt, readBack);
+	assert.strictEqual(svm.getAccount(exempt).rentEpoch, 2 ** 64);
+});
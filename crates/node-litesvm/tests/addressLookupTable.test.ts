@@ -0,0 +1,46 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import {
+	AddressLookupTableProgram,
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	Transaction,
+} from "@solana/web3.js";
+
+test("get address lookup table", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const addresses = [PublicKey.unique(), PublicKey.unique(), PublicKey.unique()];
+
+	const [createIx, tableAddress] = AddressLookupTableProgram.createLookupTable({
+		authority: payer.publicKey,
+		payer: payer.publicKey,
+		recentSlot: 0,
+	});
+	const extendIx = AddressLookupTableProgram.extendLookupTable({
+		lookupTable: tableAddress,
+		authority: payer.publicKey,
+		payer: payer.publicKey,
+		addresses,
+	});
+	const tx = new Transaction();
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.add(createIx, extendIx);
+	tx.sign(payer);
+	svm.sendTransaction(tx);
+
+	const table = svm.getAddressLookupTable(tableAddress);
+	assert.ok(table !== null);
+	const authority = table.authority();
+	assert.ok(authority !== null);
+	assert.ok(new PublicKey(authority).equals(payer.publicKey));
+	assert.strictEqual(table.deactivationSlot(), null);
+	assert.deepStrictEqual(
+		table.addresses().map((address) => new PublicKey(address).toBase58()),
+		addresses.map((address) => address.toBase58()),
+	);
+	assert.strictEqual(svm.getAddressLookupTable(payer.publicKey), null);
+});
@@ -0,0 +1,139 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, TransactionMetadata } from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+import {
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	AccountLayout,
+	MINT_SIZE,
+	TOKEN_PROGRAM_ID,
+	createAssociatedTokenAccountInstruction,
+	createInitializeMint2Instruction,
+	getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+
+test("create associated token account", () => {
+	const svm = new LiteSVM().withSplAssociatedTokenAccountProgram();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const mint = new Keypair();
+	const owner = PublicKey.unique();
+	const ata = getAssociatedTokenAddressSync(mint.publicKey, owner);
+	const tx = new Transaction();
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.add(
+		SystemProgram.createAccount({
+			fromPubkey: payer.publicKey,
+			newAccountPubkey: mint.publicKey,
+			lamports: Number(svm.minimumBalanceForRentExemption(BigInt(MINT_SIZE))),
+			space: MINT_SIZE,
+			programId: TOKEN_PROGRAM_ID,
+		}),
+		createInitializeMint2Instruction(mint.publicKey, 8, payer.publicKey, null),
+		createAssociatedTokenAccountInstruction(
+			payer.publicKey,
+			ata,
+			owner,
+			mint.publicKey,
+		),
+	);
+	tx.sign(payer, mint);
+	const res = svm.sendTransaction(tx);
+	assert.ok(res instanceof TransactionMetadata);
+	const ataAcc = svm.getAccount(ata);
+	assert.ok(ataAcc !== null);
+	assert.ok(ataAcc.owner.equals(TOKEN_PROGRAM_ID));
+	const decoded = AccountLayout.decode(ataAcc.data);
+	assert.ok(decoded.mint.equals(mint.publicKey));
+	assert.ok(decoded.owner.equals(owner));
+});
+
+test("logs for program", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const mint = new Keypair();
+	const owner = PublicKey.unique();
+	const ata = getAssociatedTokenAddressSync(mint.publicKey, owner);
+	const tx = new Transaction();
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.add(
+		SystemProgram.createAccount({
+			fromPubkey: payer.publicKey,
+			newAccountPubkey: mint.publicKey,
+			lamports: Number(svm.minimumBalanceForRentExemption(BigInt(MINT_SIZE))),
+			space: MINT_SIZE,
+			programId: TOKEN_PROGRAM_ID,
+		}),
+		createInitializeMint2Instruction(mint.publicKey, 8, payer.publicKey, null),
+	);
+	tx.sign(payer, mint);
+	svm.sendTransaction(tx);
+	// the associated token account program CPIs into the token program
+	const res = svm.processInstruction(
+		createAssociatedTokenAccountInstruction(
+			payer.publicKey,
+			ata,
+			owner,
+			mint.publicKey,
+		),
+		[payer],
+	);
+	assert.ok(res instanceof TransactionMetadata);
+	const tokenLogs = res.logsForProgram(TOKEN_PROGRAM_ID.toBytes());
+	const ataLogs = res.logsForProgram(ASSOCIATED_TOKEN_PROGRAM_ID.toBytes());
+	assert.ok(tokenLogs.includes("Program log: Instruction: InitializeAccount3"));
+	assert.ok(ataLogs.includes("Program log: Create"));
+	assert.ok(tokenLogs.every((log) => !ataLogs.includes(log)));
+});
+
+test("program invocation count", () => {
+	const svm = new LiteSVM()
+		.withSplAssociatedTokenAccountProgram()
+		.withExecutionTrace(true);
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const mint = new Keypair();
+	const owner = PublicKey.unique();
+	const tx = new Transaction();
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.add(
+		SystemProgram.createAccount({
+			fromPubkey: payer.publicKey,
+			newAccountPubkey: mint.publicKey,
+			lamports: Number(svm.minimumBalanceForRentExemption(BigInt(MINT_SIZE))),
+			space: MINT_SIZE,
+			programId: TOKEN_PROGRAM_ID,
+		}),
+		createInitializeMint2Instruction(mint.publicKey, 8, payer.publicKey, null),
+		createAssociatedTokenAccountInstruction(
+			payer.publicKey,
+			getAssociatedTokenAddressSync(mint.publicKey, owner),
+			owner,
+			mint.publicKey,
+		),
+	);
+	tx.sign(payer, mint);
+	const res = svm.sendTransaction(tx);
+	assert.ok(res instanceof TransactionMetadata);
+	const tokenCpis = res
+		.executionTrace()[2]
+		.innerInstructions()
+		.filter((inner) => new PublicKey(inner.programId()).equals(TOKEN_PROGRAM_ID))
+		.length;
+	assert.ok(tokenCpis > 0);
+	assert.strictEqual(
+		svm.getProgramInvocationCount(ASSOCIATED_TOKEN_PROGRAM_ID),
+		1n,
+	);
+	assert.strictEqual(
+		svm.getProgramInvocationCount(TOKEN_PROGRAM_ID),
+		BigInt(1 + tokenCpis),
+	);
+});
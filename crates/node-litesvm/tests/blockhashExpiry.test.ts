@@ -0,0 +1,172 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import {
+	FailedTransactionMetadata,
+	LiteSVM,
+	TransactionMetadata,
+} from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+import { TransactionErrorFieldless } from "internal";
+
+test("blockhash expiry slots", () => {
+	const svm = new LiteSVM().withBlockhashExpirySlots(2n);
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const blockhash = svm.latestBlockhash();
+	const transferTx = (lamports: number) => {
+		const tx = new Transaction();
+		tx.recentBlockhash = blockhash;
+		tx.add(
+			SystemProgram.transfer({
+				fromPubkey: payer.publicKey,
+				toPubkey: PublicKey.unique(),
+				lamports,
+			}),
+		);
+		tx.sign(payer);
+		return tx;
+	};
+	svm.finalizeBlock();
+	assert.ok(svm.sendTransaction(transferTx(1_000_000)) instanceof TransactionMetadata);
+	svm.finalizeBlock();
+	const res = svm.sendTransaction(transferTx(2_000_000));
+	assert.ok(res instanceof FailedTransactionMetadata);
+	assert.strictEqual(res.err(), TransactionErrorFieldless.BlockhashNotFound);
+});
+
+test("expire blockhash ignores the expiry window", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const blockhash = svm.latestBlockhash();
+	svm.expireBlockhash();
+	const tx = new Transaction();
+	tx.recentBlockhash = blockhash;
+	tx.add(
+		SystemProgram.transfer({
+			fromPubkey: payer.publicKey,
+			toPubkey: PublicKey.unique(),
+			lamports: 1_000_000,
+		}),
+	);
+	tx.sign(payer);
+	const res = svm.sendTransaction(tx);
+	assert.ok(res instanceof FailedTransactionMetadata);
+	assert.strictEqual(res.err(), TransactionErrorFieldless.BlockhashNotFound);
+});
+
+test("warp to slot expiring blockhashes", () => {
+	for (const expireBlockhashes of [false, true]) {
+		const svm = new LiteSVM().withBlockhashExpirySlots(10n);
+		const payer = new Keypair();
+		svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+		const blockhash = svm.latestBlockhash();
+		svm.warpToSlot(100n, expireBlockhashes);
+		const tx = new Transaction();
+		tx.recentBlockhash = blockhash;
+		tx.add(
+			SystemProgram.transfer({
+				fromPubkey: payer.publicKey,
+				toPubkey: PublicKey.unique(),
+				lamports: 1_000_000,
+			}),
+		);
+		tx.sign(payer);
+		const res = svm.sendTransaction(tx);
+		if (expireBlockhashes) {
+			assert.ok(res instanceof FailedTransactionMetadata);
+			assert.strictEqual(res.err(), TransactionErrorFieldless.BlockhashNotFound);
+		} else {
+			assert.ok(res instanceof TransactionMetadata);
+		}
+	}
+});
+
+test("finalize block", () => {
+	const svm = new LiteSVM();
+	const blockhash = svm.latestBlockhash();
+	svm.finalizeBlock();
+	assert.notStrictEqual(svm.latestBlockhash(), blockhash);
+	assert.strictEqual(svm.getClock().slot, 1n);
+	const nextBlockhash = svm.latestBlockhash();
+	svm.finalizeBlock(false);
+	assert.notStrictEqual(svm.latestBlockhash(), nextBlockhash);
+	assert.strictEqual(svm.getClock().slot, 1n);
+});
+
+test("block height", () => {
+	const svm = new LiteSVM();
+	assert.strictEqual(svm.getBlockHeight(), 0n);
+	svm.warpToSlot(100n);
+	assert.strictEqual(svm.getBlockHeight(), 0n);
+	svm.finalizeBlock();
+	assert.strictEqual(svm.getBlockHeight(), 1n);
+	assert.strictEqual(svm.getClock().slot, 101n);
+	svm.setBlockHeight(50n);
+	svm.finalizeBlock(false);
+	assert.strictEqual(svm.getBlockHeight(), 51n);
+});
+
+test("latest blockhash info", () => {
+	const svm = new LiteSVM().withBlockhashExpirySlots(10n);
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const { blockhash, lastValidBlockHeight } = svm.latestBlockhashInfo();
+	assert.strictEqual(blockhash, svm.latestBlockhash());
+	assert.strictEqual(lastValidBlockHeight, svm.getBlockHeight() + 9n);
+	const transferTx = (lamports: number) => {
+		const tx = new Transaction();
+		tx.recentBlockhash = blockhash;
+		tx.add(
+			SystemProgram.transfer({
+				fromPubkey: payer.publicKey,
+				toPubkey: PublicKey.unique(),
+				lamports,
+			}),
+		);
+		tx.sign(payer);
+		return tx;
+	};
+	while (svm.getBlockHeight() < lastValidBlockHeight) {
+		svm.finalizeBlock();
+	}
+	assert.ok(svm.sendTransaction(transferTx(1_000_000)) instanceof TransactionMetadata);
+	svm.finalizeBlock();
+	const res = svm.sendTransaction(transferTx(2_000_000));
+	assert.ok(res instanceof FailedTransactionMetadata);
+	assert.strictEqual(res.err(), TransactionErrorFieldless.BlockhashNotFound);
+});
+
+test("max blockhash age", () => {
+	const svm = new LiteSVM()
+		.withBlockhashExpirySlots(10n)
+		.withMaxBlockhashAge(1n);
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const blockhash = svm.latestBlockhash();
+	const transferTx = (lamports: number) => {
+		const tx = new Transaction();
+		tx.recentBlockhash = blockhash;
+		tx.add(
+			SystemProgram.transfer({
+				fromPubkey: payer.publicKey,
+				toPubkey: PublicKey.unique(),
+				lamports,
+			}),
+		);
+		tx.sign(payer);
+		return tx;
+	};
+	svm.finalizeBlock();
+	assert.ok(svm.sendTransaction(transferTx(1_000_000)) instanceof TransactionMetadata);
+	svm.finalizeBlock();
+	const res = svm.sendTransaction(transferTx(2_000_000));
+	assert.ok(res instanceof FailedTransactionMetadata);
+	assert.strictEqual(res.err(), TransactionErrorFieldless.BlockhashNotFound);
+});
@@ -65,3 +65,44 @@ test("clock", () => {
 	assert.ok(success instanceof TransactionMetadata);
 	console.log("Finished clock test");
 });
+
+test("reset clock", () => {
+	const programId = PublicKey.unique();
+	const svm = new LiteSVM();
+	svm.addProgramFromFile(programId, "program_bytes/litesvm_clock_example.so");
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const initialClock = svm.getClock();
+	const send = (data: string) => {
+		const tx = new Transaction();
+		tx.recentBlockhash = svm.latestBlockhash();
+		tx.add(
+			new TransactionInstruction({ keys: [], programId, data: Buffer.from(data) }),
+		);
+		tx.sign(payer);
+		return svm.sendTransaction(tx);
+	};
+	// the program requires a unix timestamp before 100
+	svm.warpForwardSeconds(3600n);
+	assert.ok(send("warped") instanceof FailedTransactionMetadata);
+	svm.resetClock();
+	const clock = svm.getClock();
+	assert.strictEqual(clock.slot, initialClock.slot);
+	assert.strictEqual(clock.unixTimestamp, initialClock.unixTimestamp);
+	assert.ok(send("reset") instanceof TransactionMetadata);
+});
+
+test("partial clock update", () => {
+	const svm = new LiteSVM();
+	svm.warpToSlot(1000n);
+	const before = svm.getClock();
+	svm.setUnixTimestamp(1735689600n);
+	const after = svm.getClock();
+	assert.strictEqual(after.unixTimestamp, 1735689600n);
+	assert.strictEqual(after.slot, before.slot);
+	assert.strictEqual(after.epoch, before.epoch);
+	svm.setSlot(2000n);
+	const last = svm.getClock();
+	assert.strictEqual(last.slot, 2000n);
+	assert.strictEqual(last.unixTimestamp, 1735689600n);
+});
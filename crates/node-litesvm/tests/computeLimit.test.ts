@@ -1,7 +1,13 @@
 import { test } from "node:test";
 import assert from "node:assert/strict";
-import { FailedTransactionMetadata } from "litesvm";
 import {
+	ComputeBudget,
+	FailedTransactionMetadata,
+	LiteSVM,
+	TransactionMetadata,
+} from "litesvm";
+import {
+	ComputeBudgetProgram,
 	LAMPORTS_PER_SOL,
 	Transaction,
 	TransactionInstruction,
@@ -37,3 +43,41 @@ test("compute limit", () => {
 		throw new Error("Expected transaction failure");
 	}
 });
+
+test("hit compute limit", () => {
+	const [svm, programId, greetedPubkey] = helloworldProgram();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const sendWithLimit = (units: number) => {
+		const tx = new Transaction();
+		tx.recentBlockhash = svm.latestBlockhash();
+		tx.add(
+			ComputeBudgetProgram.setComputeUnitLimit({ units }),
+			new TransactionInstruction({
+				keys: [{ pubkey: greetedPubkey, isSigner: false, isWritable: true }],
+				programId,
+				data: Buffer.from([0]),
+			}),
+		);
+		tx.sign(payer);
+		return svm.sendTransaction(tx);
+	};
+	const ok = sendWithLimit(200_000);
+	assert.ok(ok instanceof TransactionMetadata);
+	assert.strictEqual(ok.computeUnitLimit(), 200_000n);
+	assert.strictEqual(ok.hitComputeLimit(), false);
+	const exhausted = sendWithLimit(200);
+	assert.ok(exhausted instanceof FailedTransactionMetadata);
+	assert.strictEqual(exhausted.meta().hitComputeLimit(), true);
+});
+
+test("current compute budget", () => {
+	const svm = new LiteSVM();
+	assert.strictEqual(svm.currentComputeBudget().computeUnitLimit, 1_400_000n);
+	const computeBudget = new ComputeBudget();
+	computeBudget.computeUnitLimit = 10_000n;
+	svm.withComputeBudget(computeBudget);
+	const budget = svm.currentComputeBudget();
+	assert.strictEqual(budget.computeUnitLimit, 10_000n);
+	assert.strictEqual(budget.heapSize, 32 * 1024);
+});
@@ -0,0 +1,34 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, TransactionMetadata } from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	Transaction,
+	TransactionInstruction,
+} from "@solana/web3.js";
+
+test("compute units remaining", () => {
+	const programId = PublicKey.unique();
+	const svm = new LiteSVM().withCaptureComputeUnitsRemaining(true);
+	svm.addProgramFromFile(programId, "program_bytes/spl_example_logging.so");
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const tx = new Transaction();
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.add(
+		new TransactionInstruction({
+			programId,
+			keys: [
+				{ pubkey: PublicKey.unique(), isSigner: false, isWritable: false },
+			],
+		}),
+	);
+	tx.sign(payer);
+	const res = svm.sendTransaction(tx);
+	assert.ok(res instanceof TransactionMetadata);
+	const remaining = res.computeUnitsRemaining();
+	assert.strictEqual(remaining.length, 1);
+	assert.ok(remaining[0] > 0n);
+});
@@ -0,0 +1,61 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import {
+	FailedTransactionMetadata,
+	LiteSVM,
+	TransactionMetadata,
+} from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+import {
+	MINT_SIZE,
+	TOKEN_PROGRAM_ID,
+	createAssociatedTokenAccountInstruction,
+	createInitializeMint2Instruction,
+	getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+
+test("max cpi depth", () => {
+	// creating an associated token account invokes the token program one level deep
+	const createAta = (svm: LiteSVM) => {
+		const payer = new Keypair();
+		svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+		const mint = new Keypair();
+		const owner = PublicKey.unique();
+		const tx = new Transaction();
+		tx.recentBlockhash = svm.latestBlockhash();
+		tx.add(
+			SystemProgram.createAccount({
+				fromPubkey: payer.publicKey,
+				newAccountPubkey: mint.publicKey,
+				lamports: Number(svm.minimumBalanceForRentExemption(BigInt(MINT_SIZE))),
+				space: MINT_SIZE,
+				programId: TOKEN_PROGRAM_ID,
+			}),
+			createInitializeMint2Instruction(mint.publicKey, 8, payer.publicKey, null),
+		);
+		tx.sign(payer, mint);
+		svm.sendTransaction(tx);
+		return svm.processInstruction(
+			createAssociatedTokenAccountInstruction(
+				payer.publicKey,
+				getAssociatedTokenAddressSync(mint.publicKey, owner),
+				owner,
+				mint.publicKey,
+			),
+			[payer],
+		);
+	};
+	assert.ok(createAta(new LiteSVM()) instanceof TransactionMetadata);
+	assert.ok(
+		createAta(new LiteSVM().withMaxCpiDepth(1)) instanceof TransactionMetadata,
+	);
+	const res = createAta(new LiteSVM().withMaxCpiDepth(0));
+	assert.ok(res instanceof FailedTransactionMetadata);
+	assert.match(res.err().toString(), /CallDepth/);
+});
@@ -0,0 +1,25 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+
+test("create pda account", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const programId = PublicKey.unique();
+	const seeds = [new TextEncoder().encode("vault")];
+	const pda = svm.createPdaAccount(programId, seeds, 100, payer);
+	assert.ok(pda.equals(PublicKey.findProgramAddressSync(seeds, programId)[0]));
+	const account = svm.getAccount(pda);
+	assert.ok(account !== null);
+	assert.ok(account.owner.equals(programId));
+	assert.strictEqual(account.data.length, 100);
+	assert.strictEqual(
+		BigInt(account.lamports),
+		svm.minimumBalanceForRentExemption(100n),
+	);
+	assert.throws(() => svm.createPdaAccount(programId, seeds, 100, payer), {
+		message: new RegExp(`Account ${pda.toBase58()} already exists`),
+	});
+});
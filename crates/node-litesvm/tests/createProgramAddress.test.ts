@@ -0,0 +1,12 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { createProgramAddress } from "litesvm";
+import { PublicKey } from "@solana/web3.js";
+
+test("create program address", () => {
+	const programId = PublicKey.unique();
+	const seeds = [Buffer.from("counter"), PublicKey.unique().toBytes()];
+	const [expected, bump] = PublicKey.findProgramAddressSync(seeds, programId);
+	const address = createProgramAddress(seeds, bump, programId);
+	assert.ok(address.equals(expected));
+});
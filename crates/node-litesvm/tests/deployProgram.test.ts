@@ -0,0 +1,31 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, TransactionMetadata } from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	TransactionInstruction,
+} from "@solana/web3.js";
+import { readFileSync } from "node:fs";
+
+test("deploy program", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const programBytes = readFileSync("program_bytes/spl_example_logging.so");
+	const programId = svm.deployProgram(programBytes.toString("base64"));
+	assert.ok(svm.getAccount(programId)?.executable);
+	assert.ok(!svm.deployProgram(programBytes).equals(programId));
+
+	const res = svm.processInstruction(
+		new TransactionInstruction({
+			programId,
+			keys: [{ pubkey: PublicKey.unique(), isSigner: false, isWritable: true }],
+			data: Buffer.from([5, 10, 11, 12, 13, 14]),
+		}),
+		[payer],
+	);
+	assert.ok(res instanceof TransactionMetadata);
+	assert.strictEqual(res.logs()[1], "Program log: static string");
+});
@@ -0,0 +1,39 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, TransactionMetadata } from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	Transaction,
+	TransactionInstruction,
+} from "@solana/web3.js";
+
+test("execution trace", () => {
+	const programId = PublicKey.unique();
+	const svm = new LiteSVM().withExecutionTrace(true);
+	svm.addProgramFromFile(programId, "program_bytes/spl_example_logging.so");
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const tx = new Transaction();
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.add(
+		new TransactionInstruction({
+			programId,
+			keys: [
+				{ pubkey: PublicKey.unique(), isSigner: false, isWritable: false },
+			],
+		}),
+	);
+	tx.sign(payer);
+	const res = svm.sendTransaction(tx);
+	assert.ok(res instanceof TransactionMetadata);
+	const trace = res.executionTrace();
+	assert.strictEqual(trace.length, 1);
+	assert.ok(new PublicKey(trace[0].programId()).equals(programId));
+	assert.strictEqual(trace[0].instructionIndex(), 0);
+	assert.deepStrictEqual(trace[0].innerInstructions(), []);
+	assert.ok(trace[0].logs().includes("Program log: static string"));
+	assert.strictEqual(trace[0].computeUnitsConsumed(), res.computeUnitsConsumed());
+	assert.strictEqual(trace[0].returnData(), null);
+});
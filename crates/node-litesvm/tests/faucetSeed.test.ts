@@ -0,0 +1,19 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+
+test("faucet seed", () => {
+	assert.ok(!new LiteSVM().airdropPubkey().equals(new LiteSVM().airdropPubkey()));
+
+	const seed = new Uint8Array(32).fill(7);
+	const svm = new LiteSVM().withFaucetSeed(seed);
+	const other = new LiteSVM().withFaucetSeed(seed);
+	assert.ok(svm.airdropPubkey().equals(other.airdropPubkey()));
+	assert.ok(!svm.airdropPubkey().equals(new LiteSVM().airdropPubkey()));
+
+	const recipient = PublicKey.unique();
+	const lamports = BigInt(LAMPORTS_PER_SOL);
+	svm.airdrop(recipient, lamports);
+	assert.strictEqual(svm.getBalance(recipient), lamports);
+});
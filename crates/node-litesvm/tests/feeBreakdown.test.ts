@@ -0,0 +1,41 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import {
+	ComputeBudgetProgram,
+	Keypair,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+
+test("fee breakdown for a prioritized transaction", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	const otherSigner = new Keypair();
+	const to = PublicKey.unique();
+	const tx = new Transaction();
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.feePayer = payer.publicKey;
+	// priority fee = 1_000_000 * 10_000 / 1_000_000 = 10_000 lamports
+	tx.add(
+		ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 1_000_000 }),
+		ComputeBudgetProgram.setComputeUnitLimit({ units: 10_000 }),
+		SystemProgram.transfer({
+			fromPubkey: payer.publicKey,
+			toPubkey: to,
+			lamports: 100,
+		}),
+		SystemProgram.transfer({
+			fromPubkey: otherSigner.publicKey,
+			toPubkey: to,
+			lamports: 100,
+		}),
+	);
+	const breakdown = svm.getFeeBreakdownForTransaction(tx);
+	assert.ok(breakdown !== null);
+	assert.strictEqual(breakdown.signatureCount(), 2n);
+	assert.strictEqual(breakdown.signatureFee(), 10_000n);
+	assert.strictEqual(breakdown.prioritizationFee(), 10_000n);
+	assert.strictEqual(breakdown.totalFee(), 20_000n);
+});
@@ -0,0 +1,46 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import {
+	ComputeBudgetProgram,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+
+test("fee for message", () => {
+	const svm = new LiteSVM();
+	const payer = PublicKey.unique();
+	const tx = new Transaction();
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.feePayer = payer;
+	tx.add(
+		SystemProgram.transfer({
+			fromPubkey: payer,
+			toPubkey: PublicKey.unique(),
+			lamports: 100,
+		}),
+	);
+	assert.strictEqual(svm.getFeeForMessage(tx.compileMessage()), 5000n);
+});
+
+test("fee for message with prioritization fee", () => {
+	const svm = new LiteSVM();
+	const payer = PublicKey.unique();
+	const tx = new Transaction();
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.feePayer = payer;
+	tx.add(
+		ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 1_000_000 }),
+		ComputeBudgetProgram.setComputeUnitLimit({ units: 10_000 }),
+		SystemProgram.transfer({
+			fromPubkey: payer,
+			toPubkey: PublicKey.unique(),
+			lamports: 100,
+		}),
+	);
+	const messageB64 = tx.compileMessage().serialize().toString("base64");
+	// 5000 base fee + 1_000_000 * 10_000 / 1_000_000 prioritization fee
+	assert.strictEqual(svm.getFeeForMessage(messageB64), 15_000n);
+	assert.strictEqual(svm.getFeeForMessage("AAAA"), null);
+});
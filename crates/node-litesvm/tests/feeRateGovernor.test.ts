@@ -0,0 +1,15 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+
+test("fee rate governor", () => {
+	const defaults = new LiteSVM().feeRateGovernor();
+	assert.strictEqual(defaults.lamportsPerSignature, 5000n);
+	assert.strictEqual(defaults.burnPercent, 50);
+
+	const svm = new LiteSVM().withLamportsPerSignature(7000n);
+	const governor = svm.feeRateGovernor();
+	assert.strictEqual(governor.lamportsPerSignature, 7000n);
+	assert.strictEqual(governor.targetLamportsPerSignature, 7000n);
+	assert.strictEqual(governor.targetSignaturesPerSlot, 0n);
+});
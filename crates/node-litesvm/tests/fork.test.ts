@@ -0,0 +1,17 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import { PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+
+test("fork", () => {
+	const svm = new LiteSVM();
+	const address = PublicKey.unique();
+	svm.airdrop(address, BigInt(LAMPORTS_PER_SOL));
+	const forked = svm.fork();
+	forked.airdrop(address, BigInt(2 * LAMPORTS_PER_SOL));
+	forked.warpToSlot(1000n);
+	assert.strictEqual(forked.getBalance(address), BigInt(3 * LAMPORTS_PER_SOL));
+	assert.strictEqual(svm.getBalance(address), BigInt(LAMPORTS_PER_SOL));
+	assert.strictEqual(forked.getClock().slot, 1000n);
+	assert.strictEqual(svm.getClock().slot, 0n);
+});
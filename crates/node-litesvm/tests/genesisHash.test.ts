@@ -0,0 +1,12 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import { Keypair } from "@solana/web3.js";
+
+test("genesis hash", () => {
+	const svm = new LiteSVM();
+	assert.strictEqual(svm.getGenesisHash(), svm.latestBlockhash());
+	const genesisHash = new Keypair().publicKey.toBase58();
+	svm.setGenesisHash(genesisHash);
+	assert.strictEqual(svm.getGenesisHash(), genesisHash);
+});
@@ -0,0 +1,20 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import { LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+
+test("get account or default", () => {
+	const svm = new LiteSVM();
+	const missing = svm.getAccountOrDefault(PublicKey.unique());
+	assert.strictEqual(missing.lamports, 0);
+	assert.ok(missing.owner.equals(SystemProgram.programId));
+	assert.strictEqual(missing.data.length, 0);
+	assert.strictEqual(missing.executable, false);
+
+	const address = PublicKey.unique();
+	svm.airdrop(address, BigInt(LAMPORTS_PER_SOL));
+	assert.strictEqual(
+		svm.getAccountOrDefault(address).lamports,
+		LAMPORTS_PER_SOL,
+	);
+});
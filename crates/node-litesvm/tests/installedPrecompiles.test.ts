@@ -0,0 +1,27 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import {
+	Ed25519Program,
+	PublicKey,
+	Secp256k1Program,
+} from "@solana/web3.js";
+
+test("installed precompiles", () => {
+	const installed = (svm: LiteSVM) =>
+		svm.getInstalledPrecompiles().map((programId) => programId.toBase58());
+	const all = installed(new LiteSVM());
+	assert.ok(all.includes(Ed25519Program.programId.toBase58()));
+	assert.ok(all.includes(Secp256k1Program.programId.toBase58()));
+
+	// install only ed25519
+	const svm = LiteSVM.default().withBuiltins().withSysvars();
+	assert.deepStrictEqual(installed(svm), []);
+	svm.setAccount(Ed25519Program.programId, {
+		executable: true,
+		owner: new PublicKey("NativeLoader1111111111111111111111111111111"),
+		lamports: 1,
+		data: new Uint8Array(),
+	});
+	assert.deepStrictEqual(installed(svm), [Ed25519Program.programId.toBase58()]);
+});
@@ -0,0 +1,15 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+
+test("is executable", () => {
+	const programId = PublicKey.unique();
+	const svm = new LiteSVM();
+	svm.addProgramFromFile(programId, "program_bytes/spl_example_logging.so");
+	assert.strictEqual(svm.isExecutable(programId), true);
+	const dataAccount = PublicKey.unique();
+	svm.airdrop(dataAccount, BigInt(LAMPORTS_PER_SOL));
+	assert.strictEqual(svm.isExecutable(dataAccount), false);
+	assert.strictEqual(svm.isExecutable(PublicKey.unique()), null);
+});
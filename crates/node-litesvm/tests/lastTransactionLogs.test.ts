@@ -0,0 +1,59 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	Transaction,
+	TransactionInstruction,
+} from "@solana/web3.js";
+
+const MEMO_PROGRAM_ID = new PublicKey(
+	"MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr",
+);
+
+test("last transaction logs", () => {
+	const svm = new LiteSVM();
+	assert.deepStrictEqual(svm.lastTransactionLogs(), []);
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const memo = (text: string) =>
+		new TransactionInstruction({
+			programId: MEMO_PROGRAM_ID,
+			keys: [],
+			data: Buffer.from(text),
+		});
+
+	svm.processInstruction(memo("sent"), [payer]);
+	assert.ok(
+		svm
+			.lastTransactionLogs()
+			.some((log) => log.includes('Memo (len 4): "sent"')),
+	);
+
+	const tx = new Transaction();
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.add(memo("simulated"));
+	tx.sign(payer);
+	svm.simulateTransaction(tx);
+	const logs = svm.lastTransactionLogs();
+	assert.ok(logs.some((log) => log.includes('Memo (len 9): "simulated"')));
+	assert.ok(!logs.some((log) => log.includes('"sent"')));
+});
+
+test("last logs contain", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	svm.processInstruction(
+		new TransactionInstruction({
+			programId: MEMO_PROGRAM_ID,
+			keys: [],
+			data: Buffer.from("checksum 42"),
+		}),
+		[payer],
+	);
+	assert.ok(svm.lastLogsContain('Memo (len 11): "checksum 42"'));
+	assert.ok(!svm.lastLogsContain("checksum 43"));
+});
@@ -0,0 +1,41 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, TransactionMetadata } from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	Transaction,
+	TransactionInstruction,
+} from "@solana/web3.js";
+
+test("logs truncated", () => {
+	const limit = 200;
+	const programId = PublicKey.unique();
+	const svm = new LiteSVM().withLogBytesLimit(BigInt(limit));
+	svm.addProgramFromFile(programId, "program_bytes/spl_example_logging.so");
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const tx = new Transaction();
+	tx.recentBlockhash = svm.latestBlockhash();
+	for (let i = 0; i < 5; i++) {
+		tx.add(
+			new TransactionInstruction({
+				programId,
+				keys: [
+					{ pubkey: PublicKey.unique(), isSigner: false, isWritable: false },
+				],
+			}),
+		);
+	}
+	tx.sign(payer);
+	const res = svm.sendTransaction(tx);
+	assert.ok(res instanceof TransactionMetadata);
+	assert.ok(res.logsTruncated());
+	const logs = res.logs();
+	assert.strictEqual(logs[logs.length - 1], "Log truncated");
+	const bytes = logs
+		.slice(0, -1)
+		.reduce((total, line) => total + line.length, 0);
+	assert.ok(bytes < limit);
+});
@@ -0,0 +1,24 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, messageHash } from "litesvm";
+import { PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+
+test("message hash", () => {
+	const svm = new LiteSVM();
+	const payer = PublicKey.unique();
+	const to = PublicKey.unique();
+	const message = (lamports: number) => {
+		const tx = new Transaction();
+		tx.recentBlockhash = svm.latestBlockhash();
+		tx.feePayer = payer;
+		tx.add(SystemProgram.transfer({ fromPubkey: payer, toPubkey: to, lamports }));
+		return tx.compileMessage();
+	};
+	const hash = messageHash(message(100));
+	assert.ok(hash !== null);
+	assert.strictEqual(messageHash(message(100)), hash);
+	const changed = message(100).serialize();
+	changed[changed.length - 1] ^= 1;
+	assert.notStrictEqual(messageHash(changed.toString("base64")), hash);
+	assert.strictEqual(messageHash("AAAA"), null);
+});
@@ -0,0 +1,39 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, TransactionMetadata } from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+
+test("metrics", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const before = svm.getMetrics();
+
+	const tx = new Transaction();
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.add(
+		SystemProgram.transfer({
+			fromPubkey: payer.publicKey,
+			toPubkey: PublicKey.unique(),
+			lamports: 1_000_000,
+		}),
+	);
+	tx.sign(payer);
+	const res = svm.sendTransaction(tx);
+	assert.ok(res instanceof TransactionMetadata);
+
+	const after = svm.getMetrics();
+	assert.strictEqual(after.transactions - before.transactions, 1n);
+	assert.strictEqual(after.instructions - before.instructions, 1n);
+	assert.strictEqual(
+		after.computeUnitsConsumed - before.computeUnitsConsumed,
+		res.computeUnitsConsumed(),
+	);
+	assert.strictEqual(typeof after.executionTimeNanos, "bigint");
+});
@@ -0,0 +1,55 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, TransactionMetadata } from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+import {
+	MINT_SIZE,
+	TOKEN_PROGRAM_ID,
+	createAssociatedTokenAccountInstruction,
+	createInitializeMint2Instruction,
+	createMintToInstruction,
+	getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+
+test("mint info", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const mint = new Keypair();
+	const owner = PublicKey.unique();
+	const ata = getAssociatedTokenAddressSync(mint.publicKey, owner);
+	assert.strictEqual(svm.getMintInfo(mint.publicKey), null);
+	const tx = new Transaction();
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.add(
+		SystemProgram.createAccount({
+			fromPubkey: payer.publicKey,
+			newAccountPubkey: mint.publicKey,
+			lamports: Number(svm.minimumBalanceForRentExemption(BigInt(MINT_SIZE))),
+			space: MINT_SIZE,
+			programId: TOKEN_PROGRAM_ID,
+		}),
+		createInitializeMint2Instruction(mint.publicKey, 6, payer.publicKey, null),
+		createAssociatedTokenAccountInstruction(
+			payer.publicKey,
+			ata,
+			owner,
+			mint.publicKey,
+		),
+		createMintToInstruction(mint.publicKey, ata, payer.publicKey, 1_500_000),
+	);
+	tx.sign(payer, mint);
+	assert.ok(svm.sendTransaction(tx) instanceof TransactionMetadata);
+	assert.strictEqual(svm.getTokenSupply(mint.publicKey), 1_500_000n);
+	const info = svm.getMintInfo(mint.publicKey);
+	assert.strictEqual(info.decimals(), 6);
+	assert.strictEqual(info.supply(), 1_500_000n);
+	assert.ok(new PublicKey(info.mintAuthority()).equals(payer.publicKey));
+	assert.strictEqual(info.freezeAuthority(), null);
+});
@@ -0,0 +1,28 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import {
+	Keypair,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+import { TransactionErrorFieldless } from "internal";
+
+test("non-existent fee payer", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	const tx = new Transaction().add(
+		SystemProgram.transfer({
+			fromPubkey: payer.publicKey,
+			toPubkey: PublicKey.unique(),
+			lamports: 64,
+		}),
+	);
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.sign(payer);
+	const res = svm.sendTransaction(tx);
+	assert.ok(res instanceof FailedTransactionMetadata);
+	assert.strictEqual(res.err(), TransactionErrorFieldless.AccountNotFound);
+	assert.deepStrictEqual(res.meta().logs(), []);
+});
@@ -23,6 +23,7 @@ test("non-existent program", () => {
 	tx.recentBlockhash = svm.latestBlockhash();
 	tx.sign(payer);
 	const res = svm.sendTransaction(tx);
+	assert.strictEqual(res.success(), false);
 	if (res instanceof FailedTransactionMetadata) {
 		const err = res.err();
 		assert.strictEqual(
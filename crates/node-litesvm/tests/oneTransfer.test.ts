@@ -1,6 +1,6 @@
 import { test } from "node:test";
 import assert from "node:assert/strict";
-import { LiteSVM } from "litesvm";
+import { LiteSVM, TransactionMetadata } from "litesvm";
 import {
 	PublicKey,
 	Transaction,
@@ -27,7 +27,10 @@ test("one transfer", () => {
 	tx.recentBlockhash = blockhash;
 	tx.add(...ixs);
 	tx.sign(payer);
-	svm.sendTransaction(tx);
+	const res = svm.sendTransaction(tx);
 	const balanceAfter = svm.getBalance(receiver);
 	assert.strictEqual(balanceAfter, transferLamports);
+	assert.ok(res.success());
+	assert.ok(res instanceof TransactionMetadata);
+	assert.strictEqual(res.fee(), 5000n);
 });
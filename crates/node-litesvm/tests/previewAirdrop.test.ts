@@ -0,0 +1,16 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+
+test("preview airdrop", () => {
+	const svm = new LiteSVM();
+	const recipient = PublicKey.unique();
+	const lamports = BigInt(LAMPORTS_PER_SOL);
+	const preview = svm.previewAirdrop(recipient, lamports);
+	assert.strictEqual(preview, lamports);
+	assert.strictEqual(svm.getAccount(recipient), null);
+	svm.airdrop(recipient, lamports);
+	assert.strictEqual(svm.getBalance(recipient), preview);
+	assert.strictEqual(svm.previewAirdrop(recipient, lamports), 2n * lamports);
+});
@@ -0,0 +1,42 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, TransactionMetadata } from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	SystemProgram,
+	TransactionInstruction,
+} from "@solana/web3.js";
+
+test("process instruction", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	const recipient = PublicKey.unique();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const res = svm.processInstruction(
+		SystemProgram.transfer({
+			fromPubkey: payer.publicKey,
+			toPubkey: recipient,
+			lamports: 1_000,
+		}),
+		[payer],
+	);
+	assert.ok(res instanceof TransactionMetadata);
+	assert.strictEqual(svm.getBalance(recipient), 1_000n);
+});
+
+test("process instruction with extra signer", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	const author = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const memo = new TransactionInstruction({
+		programId: new PublicKey("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
+		keys: [{ pubkey: author.publicKey, isSigner: true, isWritable: false }],
+		data: Buffer.from("hello"),
+	});
+	const res = svm.processInstruction(memo, [payer, author]);
+	assert.ok(res instanceof TransactionMetadata);
+	assert.ok(res.logs().some((log) => log.includes('Memo (len 5): "hello"')));
+});
@@ -0,0 +1,13 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+
+test("program kind", () => {
+	const programId = PublicKey.unique();
+	const svm = new LiteSVM();
+	svm.addProgramFromFile(programId, "program_bytes/spl_example_logging.so");
+	assert.strictEqual(svm.programKind(programId), "BpfLoaderV2");
+	assert.strictEqual(svm.programKind(SystemProgram.programId), "Builtin");
+	assert.strictEqual(svm.programKind(PublicKey.unique()), null);
+});
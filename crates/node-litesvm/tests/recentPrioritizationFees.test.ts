@@ -0,0 +1,44 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import {
+	ComputeBudgetProgram,
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+
+test("recent prioritization fees", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const to = PublicKey.unique();
+	for (const [slot, price] of [
+		[1n, 0],
+		[2n, 5],
+		[3n, 10],
+	] as const) {
+		svm.warpToSlot(slot);
+		const tx = new Transaction();
+		tx.recentBlockhash = svm.latestBlockhash();
+		tx.add(
+			ComputeBudgetProgram.setComputeUnitPrice({ microLamports: price }),
+			SystemProgram.transfer({
+				fromPubkey: payer.publicKey,
+				toPubkey: to,
+				lamports: 1_000_000,
+			}),
+		);
+		tx.sign(payer);
+		svm.sendTransaction(tx);
+	}
+	const fees = svm
+		.getRecentPrioritizationFees()
+		.map((fee) => [fee.slot(), fee.prioritizationFee()]);
+	assert.deepStrictEqual(fees, [
+		[2n, 5n],
+		[3n, 10n],
+	]);
+});
@@ -0,0 +1,46 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { readFileSync } from "node:fs";
+import { LiteSVM, TransactionMetadata } from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+
+function run(svm: LiteSVM): [string[], string] {
+	const payer = Keypair.fromSeed(new Uint8Array(32).fill(1));
+	const recipient = Keypair.fromSeed(new Uint8Array(32).fill(2)).publicKey;
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	svm.deployProgram(readFileSync("program_bytes/spl_example_logging.so"));
+	const signatures: string[] = [];
+	for (const lamports of [1_000_000, 2_000_000]) {
+		const tx = new Transaction();
+		tx.recentBlockhash = svm.latestBlockhash();
+		tx.add(
+			SystemProgram.transfer({
+				fromPubkey: payer.publicKey,
+				toPubkey: recipient,
+				lamports,
+			}),
+		);
+		tx.sign(payer);
+		const res = svm.sendTransaction(tx);
+		assert.ok(res instanceof TransactionMetadata);
+		signatures.push(Buffer.from(res.signature()).toString("hex"));
+		svm.warpToSlot(svm.getClock().slot + 10n);
+		svm.expireBlockhash();
+	}
+	return [signatures, svm.dumpStateJson(true)];
+}
+
+test("rng seed", () => {
+	const [signatures, state] = run(new LiteSVM().withRngSeed(42n));
+	const [otherSignatures, otherState] = run(new LiteSVM().withRngSeed(42n));
+	assert.deepStrictEqual(signatures, otherSignatures);
+	assert.strictEqual(state, otherState);
+
+	const [, differentState] = run(new LiteSVM().withRngSeed(43n));
+	assert.notStrictEqual(state, differentState);
+});
@@ -16,3 +16,38 @@ test("set account", () => {
 	const fetched = svm.getAccount(address);
 	assert.deepStrictEqual(fetched.data, new Uint8Array([0, 1]));
 });
+
+test("account modified slot", () => {
+	const svm = new LiteSVM();
+	const address = PublicKey.unique();
+	const toSet: AccountInfoBytes = {
+		executable: false,
+		owner: PublicKey.default,
+		lamports: LAMPORTS_PER_SOL,
+		data: new Uint8Array([0, 1]),
+	};
+	svm.setAccount(address, toSet);
+	assert.strictEqual(svm.getAccountModifiedSlot(address), 0n);
+	svm.warpToSlot(5n);
+	svm.setAccount(address, toSet);
+	assert.strictEqual(svm.getAccountModifiedSlot(address), 5n);
+	assert.strictEqual(svm.getAccountModifiedSlot(PublicKey.unique()), null);
+});
+
+test("set account lamports", () => {
+	const svm = new LiteSVM();
+	const address = PublicKey.unique();
+	const owner = PublicKey.unique();
+	svm.setAccount(address, {
+		executable: false,
+		owner,
+		lamports: LAMPORTS_PER_SOL,
+		data: new Uint8Array([0, 1]),
+	});
+	svm.setAccountLamports(address, 3n * BigInt(LAMPORTS_PER_SOL));
+	const fetched = svm.getAccount(address);
+	assert.strictEqual(fetched.lamports, 3 * LAMPORTS_PER_SOL);
+	assert.deepStrictEqual(fetched.data, new Uint8Array([0, 1]));
+	assert.ok(fetched.owner.equals(owner));
+	assert.throws(() => svm.setAccountLamports(PublicKey.unique(), 1n));
+});
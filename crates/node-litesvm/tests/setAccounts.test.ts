@@ -0,0 +1,49 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { AccountInfoBytes, LiteSVM } from "litesvm";
+import { LAMPORTS_PER_SOL, PublicKey, SYSVAR_CLOCK_PUBKEY } from "@solana/web3.js";
+
+test("set accounts", () => {
+	const svm = new LiteSVM();
+	const owner = PublicKey.unique();
+	const entries = Array.from(
+		{ length: 100 },
+		(_, i) =>
+			[
+				PublicKey.unique(),
+				{
+					lamports: LAMPORTS_PER_SOL,
+					data: new Uint8Array(8).fill(i),
+					owner,
+					executable: false,
+				},
+			] as [PublicKey, AccountInfoBytes],
+	);
+	svm.setAccounts(entries);
+	for (const [address, expected] of entries) {
+		const account = svm.getAccount(address);
+		assert.ok(account !== null);
+		assert.deepStrictEqual(account.data, expected.data);
+		assert.ok(account.owner.equals(owner));
+	}
+	// an invalid entry leaves every account untouched
+	const untouched = PublicKey.unique();
+	assert.throws(() =>
+		svm.setAccounts([
+			[
+				untouched,
+				{ lamports: LAMPORTS_PER_SOL, data: new Uint8Array(), owner, executable: false },
+			],
+			[
+				SYSVAR_CLOCK_PUBKEY,
+				{
+					lamports: LAMPORTS_PER_SOL,
+					data: new Uint8Array(1),
+					owner: new PublicKey("Sysvar1111111111111111111111111111111111111"),
+					executable: false,
+				},
+			],
+		]),
+	);
+	assert.strictEqual(svm.getAccount(untouched), null);
+});
@@ -0,0 +1,37 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import {
+	FailedTransactionMetadata,
+	LiteSVM,
+	TransactionMetadata,
+} from "litesvm";
+import {
+	Ed25519Program,
+	Keypair,
+	LAMPORTS_PER_SOL,
+	Transaction,
+} from "@solana/web3.js";
+
+test("sigverify precompiles", () => {
+	const payer = new Keypair();
+	// a signature that doesn't match the message
+	const ix = Ed25519Program.createInstructionWithPublicKey({
+		publicKey: payer.publicKey.toBytes(),
+		message: new TextEncoder().encode("hello world"),
+		signature: new Uint8Array(64),
+	});
+	for (const enabled of [true, false]) {
+		const svm = new LiteSVM().withSigverifyPrecompiles(enabled);
+		svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+		const tx = new Transaction();
+		tx.recentBlockhash = svm.latestBlockhash();
+		tx.add(ix);
+		tx.sign(payer);
+		const res = svm.sendTransaction(tx);
+		if (enabled) {
+			assert.ok(res instanceof FailedTransactionMetadata);
+		} else {
+			assert.ok(res instanceof TransactionMetadata);
+		}
+	}
+});
@@ -0,0 +1,38 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import {
+	FailedTransactionMetadata,
+	LiteSVM,
+	SimulatedTransactionInfo,
+} from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+
+test("simulate with replaced blockhash", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const toPubkey = PublicKey.unique();
+	const tx = new Transaction().add(
+		SystemProgram.transfer({
+			fromPubkey: payer.publicKey,
+			toPubkey,
+			lamports: LAMPORTS_PER_SOL / 2,
+		}),
+	);
+	tx.recentBlockhash = PublicKey.default.toBase58();
+	tx.sign(payer);
+	assert.ok(
+		svm.simulateTransaction(tx) instanceof FailedTransactionMetadata,
+	);
+	assert.ok(
+		svm.simulateTransactionReplaceBlockhash(tx) instanceof
+			SimulatedTransactionInfo,
+	);
+	assert.strictEqual(svm.getBalance(toPubkey), null);
+});
@@ -0,0 +1,51 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import {
+	FailedTransactionMetadata,
+	LiteSVM,
+	SimulatedTransactionInfo,
+} from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+
+test("simulate transactions", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const recipients: PublicKey[] = [];
+	const transfer = (lamports: number) => {
+		const toPubkey = PublicKey.unique();
+		recipients.push(toPubkey);
+		const tx = new Transaction().add(
+			SystemProgram.transfer({
+				fromPubkey: payer.publicKey,
+				toPubkey,
+				lamports,
+			}),
+		);
+		tx.recentBlockhash = svm.latestBlockhash();
+		tx.sign(payer);
+		return tx;
+	};
+	const results = svm.simulateTransactions([
+		transfer(0.6 * LAMPORTS_PER_SOL),
+		transfer(0.6 * LAMPORTS_PER_SOL),
+		transfer(2 * LAMPORTS_PER_SOL),
+	]);
+	assert.strictEqual(results.length, 3);
+	assert.ok(results[0] instanceof SimulatedTransactionInfo);
+	assert.ok(results[1] instanceof SimulatedTransactionInfo);
+	assert.ok(results[2] instanceof FailedTransactionMetadata);
+	assert.strictEqual(
+		svm.getBalance(payer.publicKey),
+		BigInt(LAMPORTS_PER_SOL),
+	);
+	for (const recipient of recipients) {
+		assert.strictEqual(svm.getBalance(recipient), null);
+	}
+});
@@ -0,0 +1,32 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, SimulatedTransactionInfo } from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+
+test("serializable simulation info", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const tx = new Transaction().add(
+		SystemProgram.transfer({
+			fromPubkey: payer.publicKey,
+			toPubkey: PublicKey.unique(),
+			lamports: LAMPORTS_PER_SOL / 2,
+		}),
+	);
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.sign(payer);
+	const result = svm.simulateTransaction(tx);
+	assert.ok(result instanceof SimulatedTransactionInfo);
+	const info = result.toSerializable();
+	assert.strictEqual(typeof info.unitsConsumed, "number");
+	assert.strictEqual(BigInt(info.unitsConsumed), result.unitsConsumed());
+	assert.ok(Array.isArray(info.logs));
+	assert.deepStrictEqual(info.logs, result.meta().logs());
+});
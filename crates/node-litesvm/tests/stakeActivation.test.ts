@@ -0,0 +1,77 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, TransactionMetadata } from "litesvm";
+import {
+	Authorized,
+	Keypair,
+	LAMPORTS_PER_SOL,
+	StakeProgram,
+	Transaction,
+	VoteInit,
+	VoteProgram,
+} from "@solana/web3.js";
+
+function send(svm: LiteSVM, tx: Transaction, signers: Keypair[]) {
+	tx.recentBlockhash = svm.latestBlockhash();
+	tx.feePayer = signers[0].publicKey;
+	tx.sign(...signers);
+	const res = svm.sendTransaction(tx);
+	assert.ok(res instanceof TransactionMetadata, res.toString());
+}
+
+test("stake activation", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(100 * LAMPORTS_PER_SOL));
+
+	const node = new Keypair();
+	const vote = new Keypair();
+	send(
+		svm,
+		VoteProgram.createAccount({
+			fromPubkey: payer.publicKey,
+			votePubkey: vote.publicKey,
+			voteInit: new VoteInit(node.publicKey, node.publicKey, node.publicKey, 0),
+			lamports: Number(svm.minimumBalanceForRentExemption(3762n)),
+		}),
+		[payer, vote, node],
+	);
+
+	const stake = new Keypair();
+	const stakeAmount = LAMPORTS_PER_SOL;
+	const reserve = Number(svm.minimumBalanceForRentExemption(200n));
+	send(
+		svm,
+		StakeProgram.createAccount({
+			fromPubkey: payer.publicKey,
+			stakePubkey: stake.publicKey,
+			authorized: new Authorized(payer.publicKey, payer.publicKey),
+			lamports: reserve + stakeAmount,
+		}),
+		[payer, stake],
+	);
+	send(
+		svm,
+		StakeProgram.delegate({
+			stakePubkey: stake.publicKey,
+			authorizedPubkey: payer.publicKey,
+			votePubkey: vote.publicKey,
+		}),
+		[payer],
+	);
+	assert.deepStrictEqual(svm.getStakeActivation(stake.publicKey), {
+		state: "activating",
+		active: 0n,
+		inactive: BigInt(stakeAmount),
+	});
+
+	const clock = svm.getClock();
+	clock.epoch += 1n;
+	svm.setClock(clock);
+	assert.deepStrictEqual(svm.getStakeActivation(stake.publicKey), {
+		state: "active",
+		active: BigInt(stakeAmount),
+		inactive: 0n,
+	});
+	assert.strictEqual(svm.getStakeActivation(vote.publicKey), null);
+});
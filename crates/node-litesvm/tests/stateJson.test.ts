@@ -0,0 +1,52 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM } from "litesvm";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+
+test("dump and load state json", () => {
+	// the faucet is kept on load, so both instances need the same one
+	const faucetSeed = new Uint8Array(32).fill(1);
+	const svm = new LiteSVM().withFaucetSeed(faucetSeed);
+	svm.airdrop(PublicKey.unique(), BigInt(LAMPORTS_PER_SOL));
+	svm.setAccount(PublicKey.unique(), {
+		executable: false,
+		owner: PublicKey.unique(),
+		lamports: LAMPORTS_PER_SOL,
+		data: new Uint8Array([1, 2, 3]),
+	});
+	svm.warpToSlot(1000n);
+	const dumped = svm.dumpStateJson(true);
+
+	const restored = new LiteSVM().withFaucetSeed(faucetSeed);
+	restored.loadStateJson(dumped);
+	assert.strictEqual(restored.dumpStateJson(true), dumped);
+	assert.strictEqual(JSON.parse(dumped).clock.slot, "1000");
+});
+
+test("load state json restores the blockhash and drops other accounts", () => {
+	const svm = new LiteSVM();
+	svm.airdrop(PublicKey.unique(), BigInt(LAMPORTS_PER_SOL));
+	svm.expireBlockhash();
+	const dumped = svm.dumpStateJson(false);
+
+	const restored = new LiteSVM();
+	const stale = PublicKey.unique();
+	restored.airdrop(stale, BigInt(LAMPORTS_PER_SOL));
+	restored.loadStateJson(dumped);
+	assert.strictEqual(restored.latestBlockhash(), svm.latestBlockhash());
+	assert.strictEqual(restored.getAccount(stale), null);
+});
+
+test("state json sysvars", () => {
+	const svm = new LiteSVM();
+	const clock = "SysvarC1ock11111111111111111111111111111111";
+	const rent = "SysvarRent111111111111111111111111111111111";
+	const addresses = (includeSysvars: boolean) =>
+		JSON.parse(svm.dumpStateJson(includeSysvars)).accounts.map(
+			(account: { address: string }) => account.address,
+		);
+	assert.ok(addresses(true).includes(clock));
+	assert.ok(addresses(true).includes(rent));
+	assert.ok(!addresses(false).includes(clock));
+	assert.ok(!addresses(false).includes(rent));
+});
@@ -22,3 +22,39 @@ test("sysvar", () => {
 	const clockAfter = svm.getClock();
 	assert.strictEqual(clockAfter.epoch, newClock.epoch);
 });
+
+test("rent fields set independently", () => {
+	const svm = new LiteSVM();
+	const rent = svm.getRent();
+	rent.lamportsPerByteYear = 1000n;
+	svm.setRent(rent);
+	const expected = BigInt(
+		Math.floor((128 + 100) * 1000 * rent.exemptionThreshold),
+	);
+	assert.strictEqual(svm.minimumBalanceForRentExemption(100n), expected);
+	rent.exemptionThreshold = 1;
+	svm.setRent(rent);
+	assert.strictEqual(svm.minimumBalanceForRentExemption(100n), 228000n);
+	assert.strictEqual(svm.getRent().burnPercent, 50);
+});
+
+test("set sysvars in bulk", () => {
+	const svm = new LiteSVM();
+	const clock = new Clock(1000n, 1n, 3n, 4n, 1700000000n);
+	const rent = svm.getRent();
+	rent.lamportsPerByteYear *= 2n;
+	const epochSchedule = svm.getEpochSchedule();
+	epochSchedule.warmup = false;
+	svm.setSysvarsBulk({ clock, rent, epochSchedule });
+	assert.strictEqual(svm.getClock().slot, 1000n);
+	assert.strictEqual(svm.getClock().unixTimestamp, 1700000000n);
+	assert.strictEqual(svm.getRent().lamportsPerByteYear, rent.lamportsPerByteYear);
+	assert.strictEqual(svm.getEpochSchedule().warmup, false);
+	assert.strictEqual(
+		svm.minimumBalanceForRentExemption(100n),
+		rent.minimumBalance(100n),
+	);
+	// omitted sysvars are left unchanged
+	svm.setSysvarsBulk({ rent: Rent.default() });
+	assert.strictEqual(svm.getClock().slot, 1000n);
+});
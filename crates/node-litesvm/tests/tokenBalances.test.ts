@@ -0,0 +1,98 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, TokenBalance, TransactionMetadata } from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+import {
+	MINT_SIZE,
+	TOKEN_PROGRAM_ID,
+	createAssociatedTokenAccountInstruction,
+	createInitializeMint2Instruction,
+	createMintToInstruction,
+	createTransferInstruction,
+	getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+
+test("token balances", () => {
+	const svm = new LiteSVM().withTokenBalanceRecording(true);
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const mint = new Keypair();
+	const receiver = PublicKey.unique();
+	const senderAta = getAssociatedTokenAddressSync(mint.publicKey, payer.publicKey);
+	const receiverAta = getAssociatedTokenAddressSync(mint.publicKey, receiver);
+	const setup = new Transaction();
+	setup.recentBlockhash = svm.latestBlockhash();
+	setup.add(
+		SystemProgram.createAccount({
+			fromPubkey: payer.publicKey,
+			newAccountPubkey: mint.publicKey,
+			lamports: Number(svm.minimumBalanceForRentExemption(BigInt(MINT_SIZE))),
+			space: MINT_SIZE,
+			programId: TOKEN_PROGRAM_ID,
+		}),
+		createInitializeMint2Instruction(mint.publicKey, 6, payer.publicKey, null),
+		createAssociatedTokenAccountInstruction(
+			payer.publicKey,
+			senderAta,
+			payer.publicKey,
+			mint.publicKey,
+		),
+		createAssociatedTokenAccountInstruction(
+			payer.publicKey,
+			receiverAta,
+			receiver,
+			mint.publicKey,
+		),
+		createMintToInstruction(mint.publicKey, senderAta, payer.publicKey, 1_500_000),
+	);
+	setup.sign(payer, mint);
+	assert.ok(svm.sendTransaction(setup) instanceof TransactionMetadata);
+
+	const res = svm.processInstruction(
+		createTransferInstruction(senderAta, receiverAta, payer.publicKey, 500_000),
+		[payer],
+	);
+	assert.ok(res instanceof TransactionMetadata);
+	// the payer comes first, the order of the two token accounts depends on their addresses
+	const summary = (balances: TokenBalance[]) =>
+		balances
+			.map((balance) => {
+				assert.ok([1, 2].includes(balance.accountIndex()));
+				assert.ok(new PublicKey(balance.mint()).equals(mint.publicKey));
+				assert.strictEqual(balance.decimals(), 6);
+				return {
+					owner: new PublicKey(balance.owner()).toBase58(),
+					amount: balance.amount(),
+					uiAmount: balance.uiAmount(),
+				};
+			})
+			.sort((a, b) => a.owner.localeCompare(b.owner));
+	const expected = (balances: [PublicKey, bigint, number][]) =>
+		balances
+			.map(([owner, amount, uiAmount]) => ({
+				owner: owner.toBase58(),
+				amount,
+				uiAmount,
+			}))
+			.sort((a, b) => a.owner.localeCompare(b.owner));
+	assert.deepStrictEqual(
+		summary(res.preTokenBalances()),
+		expected([
+			[payer.publicKey, 1_500_000n, 1.5],
+			[receiver, 0n, 0],
+		]),
+	);
+	assert.deepStrictEqual(
+		summary(res.postTokenBalances()),
+		expected([
+			[payer.publicKey, 1_000_000n, 1],
+			[receiver, 500_000n, 0.5],
+		]),
+	);
+});
@@ -0,0 +1,46 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import {
+	LiteSVM,
+	FailedTransactionMetadata,
+	TransactionMetadata,
+} from "litesvm";
+import {
+	Keypair,
+	LAMPORTS_PER_SOL,
+	PublicKey,
+	SystemProgram,
+	Transaction,
+} from "@solana/web3.js";
+import { TransactionErrorFieldless } from "internal";
+
+test("transaction dedup", () => {
+	for (const dedup of [true, false]) {
+		const svm = new LiteSVM().withTransactionDedup(dedup);
+		const payer = new Keypair();
+		const receiver = PublicKey.unique();
+		svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+		const tx = new Transaction().add(
+			SystemProgram.transfer({
+				fromPubkey: payer.publicKey,
+				toPubkey: receiver,
+				lamports: 100,
+			}),
+		);
+		tx.recentBlockhash = svm.latestBlockhash();
+		tx.sign(payer);
+		assert.ok(svm.sendTransaction(tx) instanceof TransactionMetadata);
+		const second = svm.sendTransaction(tx);
+		if (dedup) {
+			assert.ok(second instanceof FailedTransactionMetadata);
+			assert.strictEqual(
+				second.err(),
+				TransactionErrorFieldless.AlreadyProcessed,
+			);
+			assert.strictEqual(svm.getBalance(receiver), 100n);
+		} else {
+			assert.ok(second instanceof TransactionMetadata);
+			assert.strictEqual(svm.getBalance(receiver), 200n);
+		}
+	}
+});
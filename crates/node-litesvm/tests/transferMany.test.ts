@@ -0,0 +1,19 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { LiteSVM, TransactionMetadata } from "litesvm";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+
+test("transfer many", () => {
+	const svm = new LiteSVM();
+	const payer = new Keypair();
+	svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+	const recipients: [PublicKey, bigint][] = [1n, 2n, 3n, 4n, 5n].map((i) => [
+		PublicKey.unique(),
+		i * 1_000_000n,
+	]);
+	const res = svm.transferMany(payer, recipients);
+	assert.ok(res instanceof TransactionMetadata);
+	for (const [address, lamports] of recipients) {
+		assert.strictEqual(svm.getBalance(address), lamports);
+	}
+});
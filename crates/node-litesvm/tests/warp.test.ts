@@ -11,3 +11,28 @@ test("warp", () => {
 	const slot1 = svm.getClock().slot;
 	assert.strictEqual(slot1, newSlot);
 });
+
+test("warp forward seconds", () => {
+	const svm = new LiteSVM();
+	const before = svm.getClock();
+	svm.warpForwardSeconds(3600n);
+	const after = svm.getClock();
+	assert.strictEqual(after.unixTimestamp - before.unixTimestamp, 3600n);
+	assert.strictEqual(after.slot - before.slot, 9000n);
+});
+
+test("warp derives timestamp from slot duration", () => {
+	const svm = new LiteSVM();
+	const before = svm.getClock();
+	svm.warpToSlot(before.slot + 150n);
+	const after = svm.getClock();
+	assert.strictEqual(after.unixTimestamp - before.unixTimestamp, 60n);
+});
+
+test("create at slot", () => {
+	const svm = LiteSVM.createAtSlot(250_000_000n);
+	const clock = svm.getClock();
+	assert.strictEqual(clock.slot, 250_000_000n);
+	// 14 warmup epochs, then 432,000 slots per epoch from slot 524,256
+	assert.strictEqual(clock.epoch, 591n);
+});